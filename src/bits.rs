@@ -27,11 +27,17 @@ implement `AsMut<BitSlice>`.
 Until type-level integers stabilize, this module only implements `Bits` and
 `BitsMut` on arrays up to length 32; arrays larger than 32 must use the slice
 implementation.
+
+The `pack_bits` and `unpack_bits` functions are also provided here, for moving
+bits into and out of byte buffers without requiring an allocator.
 !*/
 
 use crate::{
 	cursor::Cursor,
-	slice::BitSlice,
+	slice::{
+		BitSlice,
+		Iter,
+	},
 	store::BitStore,
 };
 
@@ -303,3 +309,166 @@ impl_ref_for! { u8, u16, u32 }
 
 #[cfg(target_pointer_width = "64")]
 impl_ref_for! { u64 }
+
+/** Packs a stream of bits into a caller-provided byte buffer.
+
+This requires neither an allocator nor a `BitSlice` handle from the caller, and
+is suitable for building fixed-size frames on the stack in `no_std` contexts.
+Bits are written in the order defined by the `C` cursor, starting at the first
+bit of `out[0]`. Bits of `out` past the last bit written are left unchanged.
+
+# Type Parameters
+
+- `C`: The cursor type used to order bits within each byte.
+- `I`: The source of bits to pack.
+
+# Parameters
+
+- `bits`: A stream of bits to write. It is consumed until either it is exhausted
+  or `out` is full, and no bits are drawn from it after `out` fills.
+- `out`: The buffer into which bits are written.
+
+# Returns
+
+The number of bits written into `out`. This is the lesser of the number of bits
+produced by `bits` and `out.len() * 8`.
+
+# Examples
+
+```rust
+use bitvec::prelude::*;
+use bitvec::bits::pack_bits;
+
+let mut buf = [0u8; 2];
+let bits = [true, false, true, true].iter().cloned();
+assert_eq!(pack_bits::<BigEndian, _>(bits, &mut buf), 4);
+assert_eq!(buf, [0b1011_0000, 0]);
+```
+**/
+pub fn pack_bits<C, I>(bits: I, out: &mut [u8]) -> usize
+where C: Cursor, I: IntoIterator<Item = bool> {
+	let slots = BitSlice::<C, u8>::from_slice_mut(out);
+	let mut count = 0;
+	//  The index range leads the zip so that the source is not polled again
+	//  once the buffer is full.
+	for (idx, bit) in (0 .. slots.len()).zip(bits) {
+		slots.set(idx, bit);
+		count += 1;
+	}
+	count
+}
+
+/** Reads the leading bits of a byte buffer.
+
+This is the inverse of `pack_bits`, and likewise requires no allocator.
+
+# Type Parameters
+
+- `C`: The cursor type used to order bits within each byte.
+
+# Parameters
+
+- `bytes`: The buffer from which bits are read.
+- `count`: The number of bits to read. This is clamped to `bytes.len() * 8`.
+
+# Returns
+
+An iterator over the first `count` bits of `bytes`, in the order defined by the
+`C` cursor.
+
+# Examples
+
+```rust
+use bitvec::prelude::*;
+use bitvec::bits::unpack_bits;
+
+let frame = [0b1011_0000u8, 0xFF];
+let flags = unpack_bits::<BigEndian>(&frame, 4).collect::<Vec<_>>();
+assert_eq!(flags, [true, false, true, true]);
+assert_eq!(unpack_bits::<BigEndian>(&frame, 100).count(), 16);
+```
+**/
+pub fn unpack_bits<C>(bytes: &[u8], count: usize) -> Iter<C, u8>
+where C: Cursor {
+	BitSlice::<C, u8>::from_slice(bytes).take(count).iter()
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::cursor::{
+		BigEndian,
+		LittleEndian,
+	};
+
+	#[test]
+	fn pack() {
+		let src = [
+			true, true, false, false, true, false, true, false,
+			false, true, false, true, true, true, true, false,
+		];
+
+		let mut buf = [0u8; 2];
+		let len = pack_bits::<BigEndian, _>(
+			src.iter().cloned(),
+			&mut buf,
+		);
+		assert_eq!(len, 16);
+		assert_eq!(buf, [0b1100_1010, 0b0101_1110]);
+
+		let len = pack_bits::<LittleEndian, _>(
+			src.iter().cloned(),
+			&mut buf,
+		);
+		assert_eq!(len, 16);
+		assert_eq!(buf, [0b0101_0011, 0b0111_1010]);
+
+		//  Short sources leave the rest of the buffer untouched.
+		let mut buf = [!0u8; 2];
+		let len = pack_bits::<BigEndian, _>(
+			src[.. 3].iter().cloned(),
+			&mut buf,
+		);
+		assert_eq!(len, 3);
+		assert_eq!(buf, [0b1101_1111, !0]);
+
+		//  Long sources are truncated, and not drawn past the end of the buffer.
+		let mut buf = [0u8; 2];
+		let mut iter = src.iter().cloned().chain(src.iter().cloned());
+		assert_eq!(pack_bits::<BigEndian, _>(&mut iter, &mut buf), 16);
+		assert_eq!(buf, [0b1100_1010, 0b0101_1110]);
+		assert_eq!(iter.count(), 16);
+
+		let empty: &mut [u8] = &mut [];
+		assert_eq!(pack_bits::<BigEndian, _>(src.iter().cloned(), empty), 0);
+	}
+
+	#[test]
+	fn unpack() {
+		let byte = [0b1100_1010u8];
+		let be = [true, true, false, false, true, false, true, false];
+		let le = [false, true, false, true, false, false, true, true];
+
+		assert!(unpack_bits::<BigEndian>(&byte, 8).eq(be.iter().cloned()));
+		assert!(unpack_bits::<LittleEndian>(&byte, 8).eq(le.iter().cloned()));
+		assert!(unpack_bits::<BigEndian>(&byte, 3).eq(be[.. 3].iter().cloned()));
+		assert!(
+			unpack_bits::<LittleEndian>(&byte, 5).eq(le[.. 5].iter().cloned())
+		);
+
+		//  Counts are clamped to the buffer.
+		assert_eq!(unpack_bits::<BigEndian>(&byte, 9).count(), 8);
+		assert_eq!(unpack_bits::<BigEndian>(&[], 4).count(), 0);
+		assert_eq!(unpack_bits::<BigEndian>(&byte, 0).count(), 0);
+
+		//  Unpacking inverts packing.
+		let mut buf = [0u8; 2];
+		let src = [0xA5u8, 0x3C];
+		let len = pack_bits::<LittleEndian, _>(
+			unpack_bits::<LittleEndian>(&src, 16),
+			&mut buf,
+		);
+		assert_eq!(len, 16);
+		assert_eq!(buf, src);
+	}
+}
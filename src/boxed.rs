@@ -29,9 +29,13 @@ use core::{
 	cmp,
 	fmt::{
 		self,
+		Binary,
 		Debug,
 		Display,
 		Formatter,
+		LowerHex,
+		Octal,
+		UpperHex,
 	},
 	hash::{
 		Hash,
@@ -359,19 +363,29 @@ where C: Cursor, T: BitStore {
 	/// This function is mainly useful for bit regions that live for the
 	/// remainder of the program’s life. Dropping the returned reference will
 	/// cause a memory leak. If this is not acceptable, the reference should
-	/// first be wrapped with the [`Box::from_raw`] function, producing a
+	/// first be wrapped with the [`BitBox::from_raw`] function, producing a
 	/// `BitBox`. This `BitBox` can then be dropped which will properly
 	/// deallocate the memory.
 	///
 	/// # Parameters
 	///
-	/// - `b`: The `BitBox` to deconstruct.
+	/// - `self`
 	///
 	/// # Returns
 	///
 	/// The slice formerly governed by the `BitBox`, which will never
 	/// deallocate.
 	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let bits: &'static mut BitSlice = bitbox![0; 10].leak();
+	/// bits.set(3, true);
+	/// assert_eq!(bits.count_ones(), 1);
+	/// ```
+	///
 	/// [`BitBox::from_raw`]: #method.from_raw
 	pub fn leak<'a>(self) -> &'a mut BitSlice<C, T> {
 		let out = self.bitptr();
@@ -639,12 +653,7 @@ where C: Cursor, T: BitStore {
 impl<C, T> Debug for BitBox<C, T>
 where C: Cursor, T: BitStore {
 	fn fmt(&self, f: &mut Formatter) -> fmt::Result {
-		f.write_str("BitBox<")?;
-		f.write_str(C::TYPENAME)?;
-		f.write_str(", ")?;
-		f.write_str(T::TYPENAME)?;
-		f.write_str("> ")?;
-		Display::fmt(self.as_bits(), f)
+		self.as_bits().fmt_debug("BitBox", f)
 	}
 }
 
@@ -655,6 +664,34 @@ where C: Cursor, T: BitStore {
 	}
 }
 
+impl<C, T> Binary for BitBox<C, T>
+where C: Cursor, T: BitStore {
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+		Binary::fmt(self.as_bits(), f)
+	}
+}
+
+impl<C, T> Octal for BitBox<C, T>
+where C: Cursor, T: BitStore {
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+		Octal::fmt(self.as_bits(), f)
+	}
+}
+
+impl<C, T> LowerHex for BitBox<C, T>
+where C: Cursor, T: BitStore {
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+		LowerHex::fmt(self.as_bits(), f)
+	}
+}
+
+impl<C, T> UpperHex for BitBox<C, T>
+where C: Cursor, T: BitStore {
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+		UpperHex::fmt(self.as_bits(), f)
+	}
+}
+
 impl<C, T> Hash for BitBox<C, T>
 where C: Cursor, T: BitStore {
 	fn hash<H: Hasher>(&self, hasher: &mut H) {
@@ -910,7 +947,7 @@ where C: Cursor, T: BitStore {
 	type Output = Self;
 
 	fn not(mut self) -> Self::Output {
-		let _ = self.as_bits_mut().not();
+		self.flip_all();
 		self
 	}
 }
@@ -1012,3 +1049,22 @@ where C: Cursor, T: BitStore {
 		self.next_back()
 	}
 }
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::cursor::BigEndian;
+
+	#[test]
+	fn leak() {
+		let bits: &'static mut BitSlice<BigEndian, u8> =
+			bitbox![BigEndian, u8; 1, 0, 1].leak();
+		bits.set(1, true);
+		bits.set(2, false);
+		assert_eq!(bits, &bitvec![BigEndian, u8; 1, 1, 0]);
+
+		//  Reconstitute the box so that the allocation is released.
+		let boxed = unsafe { BitBox::<BigEndian, u8>::from_raw(bits.bitptr()) };
+		assert_eq!(boxed, bitbox![BigEndian, u8; 1, 1, 0]);
+	}
+}
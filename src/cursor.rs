@@ -12,6 +12,40 @@ one position, and each position is mapped by one and only one cursor).
 Contiguity is not required.
 
 `Cursor` is a stateless trait, and implementors should be zero-sized types.
+
+# Runtime Selection
+
+Because a cursor is a type, not a value, there is no `Cursor` implementor that
+holds a bit order chosen at runtime. A single type whose `at` function
+consulted a runtime flag could not keep different collections in different
+orders, and changing the flag while any collection exists would violate the
+purity requirement on `Cursor::at`.
+
+When the ordering is only known at runtime, such as from a file header, branch
+on it once and dispatch into code that is generic over `C: Cursor`. Each arm
+is monomorphized with a static cursor, so the per-bit work carries no branch.
+
+```rust
+use bitvec::prelude::*;
+
+enum Order { Big, Little }
+
+fn ones_positions<C: Cursor>(bytes: &[u8]) -> Vec<usize> {
+  let bits = bytes.bits::<C>();
+  (0 .. bits.len()).filter(|&idx| bits[idx]).collect()
+}
+
+fn decode(order: Order, bytes: &[u8]) -> Vec<usize> {
+  match order {
+    Order::Big => ones_positions::<BigEndian>(bytes),
+    Order::Little => ones_positions::<LittleEndian>(bytes),
+  }
+}
+
+let header = [0b0000_0011u8];
+assert_eq!(decode(Order::Big, &header), [6, 7]);
+assert_eq!(decode(Order::Little, &header), [0, 1]);
+```
 !*/
 
 use crate::{
@@ -19,6 +53,7 @@ use crate::{
 		BitIdx,
 		BitMask,
 		BitPos,
+		IntoBitIdx,
 	},
 	store::BitStore,
 };
@@ -70,6 +105,38 @@ pub struct LittleEndian;
 #[cfg(target_endian = "little")]
 pub type Local = LittleEndian;
 
+/** Traverses an element from `MSbit` to `LSbit`, by numeric significance.
+
+This is another name for `BigEndian`, which describes what the cursor does
+rather than borrowing the vocabulary of byte order: index `0` is the most
+significant bit of the element, and each following index is the next less
+significant bit.
+
+The ordering is by significance within the whole element, not by memory
+address. For a multi-byte element such as `u16`, index `0` is bit 15 of the
+integer value. On a little-endian target, that bit lives in the *second* byte of
+the element in memory, so a `BitSlice<Msb0, u16>` does not visit the bits of its
+memory in the same order as a `BitSlice<Msb0, u8>` over the same bytes does.
+Loading the elements back out with `as_slice` always produces the integer whose
+binary representation, written with the most significant bit first, is the
+bit sequence.
+**/
+pub type Msb0 = BigEndian;
+
+/** Traverses an element from `LSbit` to `MSbit`, by numeric significance.
+
+This is another name for `LittleEndian`, which describes what the cursor does
+rather than borrowing the vocabulary of byte order: index `0` is the least
+significant bit of the element, and each following index is the next more
+significant bit.
+
+As with [`Msb0`], the ordering is by significance within the whole element, and
+does not depend on the byte order of the target.
+
+[`Msb0`]: type.Msb0.html
+**/
+pub type Lsb0 = LittleEndian;
+
 /** A cursor over an element.
 
 # Usage
@@ -186,6 +253,129 @@ pub trait Cursor {
 		);
 		BitMask::new(T::from(1) << *place)
 	}
+
+	/// Counts the clear bits at the front of an element, in semantic order.
+	///
+	/// This is an optional function; a default implementation is provided for
+	/// you.
+	///
+	/// The default implementation tests each semantic index in turn, starting
+	/// from `0`, until it finds a set bit. `Cursor` implementors whose ordering
+	/// corresponds to an electrical direction should override it with the
+	/// matching processor intrinsic.
+	///
+	/// # Parameters
+	///
+	/// - `elt`: An element value.
+	///
+	/// # Returns
+	///
+	/// The number of clear bits in `elt` before the first set bit, counting in
+	/// the order defined by this cursor. This is `T::BITS` when `elt` is zero.
+	///
+	/// # Type Parameters
+	///
+	/// - `T`: The storage type of the element being counted.
+	fn leading_zeros<T>(elt: T) -> usize
+	where T: BitStore {
+		(0 .. T::BITS)
+			.take_while(|&n| elt & *Self::mask::<T>(n.idx()) == T::from(0))
+			.count()
+	}
+
+	/// Counts the clear bits at the back of an element, in semantic order.
+	///
+	/// This is an optional function; a default implementation is provided for
+	/// you.
+	///
+	/// The default implementation tests each semantic index in turn, starting
+	/// from `T::BITS - 1`, until it finds a set bit. `Cursor` implementors
+	/// whose ordering corresponds to an electrical direction should override
+	/// it with the matching processor intrinsic.
+	///
+	/// # Parameters
+	///
+	/// - `elt`: An element value.
+	///
+	/// # Returns
+	///
+	/// The number of clear bits in `elt` after the last set bit, counting in
+	/// the order defined by this cursor. This is `T::BITS` when `elt` is zero.
+	///
+	/// # Type Parameters
+	///
+	/// - `T`: The storage type of the element being counted.
+	fn trailing_zeros<T>(elt: T) -> usize
+	where T: BitStore {
+		(0 .. T::BITS)
+			.rev()
+			.take_while(|&n| elt & *Self::mask::<T>(n.idx()) == T::from(0))
+			.count()
+	}
+
+	/// Checks that the cursor upholds the invariants of `at` and `mask`.
+	///
+	/// This is an optional function; a default implementation is provided for
+	/// you, and implementors should not need to override it.
+	///
+	/// The default implementation walks every semantic index in `0 .. T::BITS`
+	/// and asserts that `at` produces a position in that same domain, that no
+	/// two indices produce the same position, and that `mask` sets exactly the
+	/// bit at the position produced by `at`. Together, these show that the
+	/// cursor is a bijection over the element.
+	///
+	/// The `BitSlice` and `BitVec` constructors call this function when debug
+	/// assertions are enabled, so a faulty `Cursor` implementation is caught
+	/// before it can read or write the wrong bits. Authors of custom cursors
+	/// may also call it directly in their own tests.
+	///
+	/// # Type Parameters
+	///
+	/// - `T`: The storage type over which the cursor is checked.
+	///
+	/// # Panics
+	///
+	/// This function panics if the cursor violates any of the invariants listed
+	/// above.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// Msb0::verify::<u8>();
+	/// Lsb0::verify::<u32>();
+	/// ```
+	fn verify<T>()
+	where T: BitStore {
+		let mut seen = T::from(0);
+		for n in 0 .. T::BITS {
+			let pos = *Self::at::<T>(n.idx());
+			assert!(
+				pos < T::BITS,
+				"Cursor {} maps index {} to position {}, outside the width {}",
+				Self::TYPENAME,
+				n,
+				pos,
+				T::BITS,
+			);
+			let mask = *Self::mask::<T>(n.idx());
+			assert!(
+				mask == T::from(1) << pos,
+				"Cursor {} produces a mask for index {} without position {}",
+				Self::TYPENAME,
+				n,
+				pos,
+			);
+			assert!(
+				seen & mask == T::from(0),
+				"Cursor {} maps more than one index to position {}",
+				Self::TYPENAME,
+				pos,
+			);
+			seen |= mask;
+		}
+	}
 }
 
 impl Cursor for BigEndian {
@@ -209,6 +399,20 @@ impl Cursor for BigEndian {
 		//  a single shift.
 		unsafe { BitMask::new_unchecked((T::from(1) << T::MASK) >> *cursor) }
 	}
+
+	/// `BigEndian` order begins at the `MSbit`.
+	#[inline(always)]
+	fn leading_zeros<T>(elt: T) -> usize
+	where T: BitStore {
+		BitStore::leading_zeros(&elt)
+	}
+
+	/// `BigEndian` order ends at the `LSbit`.
+	#[inline(always)]
+	fn trailing_zeros<T>(elt: T) -> usize
+	where T: BitStore {
+		BitStore::trailing_zeros(&elt)
+	}
 }
 
 impl Cursor for LittleEndian {
@@ -229,6 +433,20 @@ impl Cursor for LittleEndian {
 		//  Set the LSbit, then shift it up.
 		unsafe { BitMask::new_unchecked(T::from(1) << *cursor) }
 	}
+
+	/// `LittleEndian` order begins at the `LSbit`.
+	#[inline(always)]
+	fn leading_zeros<T>(elt: T) -> usize
+	where T: BitStore {
+		BitStore::trailing_zeros(&elt)
+	}
+
+	/// `LittleEndian` order ends at the `MSbit`.
+	#[inline(always)]
+	fn trailing_zeros<T>(elt: T) -> usize
+	where T: BitStore {
+		BitStore::leading_zeros(&elt)
+	}
 }
 
 #[cfg(test)]
@@ -236,6 +454,48 @@ mod tests {
 	use super::*;
 	use crate::indices::IntoBitIdx;
 
+	/// A cursor with an off-by-one error that maps both `0` and `1` to the
+	/// `LSbit`.
+	struct Skewed;
+
+	impl Cursor for Skewed {
+		const TYPENAME: &'static str = "Skewed";
+
+		fn at<T>(cursor: BitIdx<T>) -> BitPos<T>
+		where T: BitStore {
+			BitPos::new(cursor.saturating_sub(1))
+		}
+	}
+
+	#[test]
+	fn verify() {
+		BigEndian::verify::<u8>();
+		BigEndian::verify::<u16>();
+		BigEndian::verify::<u32>();
+		LittleEndian::verify::<u8>();
+		LittleEndian::verify::<u16>();
+		LittleEndian::verify::<u32>();
+
+		#[cfg(target_pointer_width = "64")] {
+		BigEndian::verify::<u64>();
+		LittleEndian::verify::<u64>();
+		}
+	}
+
+	#[test]
+	#[should_panic(expected = "more than one index to position 0")]
+	fn verify_skewed() {
+		Skewed::verify::<u8>();
+	}
+
+	#[cfg(debug_assertions)]
+	#[test]
+	#[should_panic]
+	fn construct_skewed() {
+		let elt = 0u16;
+		crate::slice::BitSlice::<Skewed, u16>::from_element(&elt);
+	}
+
 	#[test]
 	fn be_u8_range() {
 		assert_eq!(BigEndian::at::<u8>(0.idx()), BitPos::new(7));
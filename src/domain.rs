@@ -4,7 +4,9 @@ The domains governed by `BitSlice` and owned-variant handles have different
 representative states depending on the span of governed elements and live bits.
 
 This module provides representations of the domain states for ease of use by
-handle operations.
+handle operations. `BitSlice::domain` exposes the immutable descriptor, so that
+callers may operate on the fully-occupied interior elements directly and handle
+only the partial edges bit by bit.
 !*/
 
 use crate::{
@@ -0,0 +1,575 @@
+/*! Adapters between bit collections and `std::io`.
+
+`BitVec` implements `std::io::Write` directly, appending each written byte as
+eight bits. Reading bits back out as bytes requires tracking how much of the
+source has been consumed, which a `&BitSlice` cannot do on its own, so this
+module provides the `BitReader` adapter for that direction.
+
+Bit-packed encoders also need to emit fields narrower than a byte, and to pad
+the stream out to a byte boundary between them. The `BitWriter` adapter wraps a
+`BitVec` to provide those operations alongside `std::io::Write`.
+
+Both directions interpret bytes through the `BigEndian` or `LittleEndian` order
+of the collection’s `Cursor`: each byte is the sequence of eight bits that a
+`BitSlice<C, u8>` over it would produce.
+!*/
+
+#![cfg(feature = "std")]
+
+use crate::{
+	cursor::Cursor,
+	slice::BitSlice,
+	store::BitStore,
+	vec::BitVec,
+};
+
+use core::cmp;
+
+use std::io::{
+	self,
+	Read,
+	Write,
+};
+
+/** Reads the bits of a `BitSlice` as a stream of bytes.
+
+Each byte produced by the reader is filled with the next eight bits of the
+source slice, in the bit order of the cursor `C`. If the slice length is not a
+multiple of eight, the final byte is padded with zero bits.
+
+The reader can also consume single bits and integer fields directly, which
+makes it the decoding counterpart of [`BitWriter`].
+
+# Type Parameters
+
+- `C`: The `Cursor` type of the source slice, which also governs the order in
+  which bits are packed into each byte.
+- `T`: The storage type of the source slice.
+
+# Examples
+
+```rust
+use bitvec::prelude::*;
+use bitvec::io::BitReader;
+use std::io::Read;
+
+let bits = bitvec![BigEndian, u8; 1, 0, 1, 0, 1, 0, 1, 1, 1, 1];
+let mut reader = BitReader::new(&bits);
+let mut buf = [0u8; 4];
+assert_eq!(reader.read(&mut buf).unwrap(), 2);
+assert_eq!(buf[.. 2], [0xAB, 0xC0]);
+```
+
+[`BitWriter`]: struct.BitWriter.html
+**/
+#[derive(Clone, Debug)]
+pub struct BitReader<'a, C, T>
+where C: Cursor, T: 'a + BitStore {
+	/// The source slice.
+	bits: &'a BitSlice<C, T>,
+	/// The index of the next bit in `bits` to be read.
+	position: usize,
+}
+
+impl<'a, C, T> BitReader<'a, C, T>
+where C: Cursor, T: 'a + BitStore {
+	/// Creates a reader positioned at the front of a bit slice.
+	///
+	/// # Parameters
+	///
+	/// - `bits`: The slice from which bits will be read.
+	///
+	/// # Returns
+	///
+	/// A `BitReader` which has not yet read any bits.
+	pub fn new(bits: &'a BitSlice<C, T>) -> Self {
+		Self { bits, position: 0 }
+	}
+
+	/// Gets the number of bits which the reader has consumed.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	///
+	/// # Returns
+	///
+	/// The index in the source slice of the next bit to be read.
+	pub fn position(&self) -> usize {
+		self.position
+	}
+
+	/// Gets the number of bits which the reader has not yet consumed.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	///
+	/// # Returns
+	///
+	/// The number of bits between the position and the end of the source.
+	pub fn remaining(&self) -> usize {
+		self.bits.len() - self.position
+	}
+
+	/// Reads a single bit.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	///
+	/// # Returns
+	///
+	/// The next bit of the source, or `None` if the source is exhausted.
+	pub fn read_bit(&mut self) -> Option<bool> {
+		let bit = self.bits.get(self.position)?;
+		self.position += 1;
+		Some(bit)
+	}
+
+	/// Reads an integer field, most significant bit first.
+	///
+	/// This has the same behavior as [`BitSlice::load_bits`] at the current
+	/// position, and is the inverse of [`BitWriter::write_bits`].
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	/// - `width`: The number of bits to read. This may be at most 64.
+	///
+	/// # Returns
+	///
+	/// The value of the field, zero-extended to `u64`. If fewer than `width`
+	/// bits remain, this returns `None` and does not consume any bits.
+	///
+	/// # Panics
+	///
+	/// This panics if `width` is greater than 64.
+	///
+	/// [`BitSlice::load_bits`]: ../slice/struct.BitSlice.html#method.load_bits
+	/// [`BitWriter::write_bits`]: struct.BitWriter.html#method.write_bits
+	pub fn read_bits(&mut self, width: usize) -> Option<u64> {
+		assert!(width <= 64, "Fields cannot be wider than 64 bits: {}", width);
+		if width > self.remaining() {
+			return None;
+		}
+		let value = self.bits.load_bits(self.position, width);
+		self.position += width;
+		Some(value)
+	}
+
+	/// Reads an integer field, least significant bit first.
+	///
+	/// This is the inverse of [`BitWriter::write_bits_lsb0`]: the first bit
+	/// read is bit 0 of the produced value.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	/// - `width`: The number of bits to read. This may be at most 64.
+	///
+	/// # Returns
+	///
+	/// The value of the field, zero-extended to `u64`. If fewer than `width`
+	/// bits remain, this returns `None` and does not consume any bits.
+	///
+	/// # Panics
+	///
+	/// This panics if `width` is greater than 64.
+	///
+	/// [`BitWriter::write_bits_lsb0`]: struct.BitWriter.html#method.write_bits_lsb0
+	pub fn read_bits_lsb0(&mut self, width: usize) -> Option<u64> {
+		assert!(width <= 64, "Fields cannot be wider than 64 bits: {}", width);
+		if width > self.remaining() {
+			return None;
+		}
+		let start = self.position;
+		let value = self.bits[start .. start + width]
+			.iter()
+			.enumerate()
+			.fold(0, |accum, (n, bit)| accum | (bit as u64) << n);
+		self.position += width;
+		Some(value)
+	}
+
+	/// Skips bits until the position is a multiple of eight.
+	///
+	/// This does nothing if the reader is already at a byte boundary. The
+	/// reader will not move past the end of the source.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	///
+	/// # Returns
+	///
+	/// The number of bits skipped, which is less than eight.
+	pub fn align_to_byte(&mut self) -> usize {
+		let pad = cmp::min((8 - self.position % 8) % 8, self.remaining());
+		self.position += pad;
+		pad
+	}
+
+	/// Unwraps the reader, returning the source slice.
+	///
+	/// # Parameters
+	///
+	/// - `self`
+	///
+	/// # Returns
+	///
+	/// The full slice from which the reader was created.
+	pub fn into_inner(self) -> &'a BitSlice<C, T> {
+		self.bits
+	}
+}
+
+impl<'a, C, T> Read for BitReader<'a, C, T>
+where C: Cursor, T: 'a + BitStore {
+	/// Packs the unread bits of the source slice into bytes.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	/// - `buf`: The destination for the packed bytes.
+	///
+	/// # Returns
+	///
+	/// The number of bytes written into `buf`. This is zero once the source
+	/// slice has been exhausted.
+	fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+		let rest = &self.bits[self.position ..];
+		let mut count = 0;
+		for (byte, chunk) in buf.iter_mut().zip(rest.chunks(8)) {
+			*byte = 0;
+			let dest = BitSlice::<C, u8>::from_element_mut(byte);
+			for (idx, bit) in chunk.iter().enumerate() {
+				dest.set(idx, bit);
+			}
+			count += 1;
+		}
+		self.position += cmp::min(count * 8, rest.len());
+		Ok(count)
+	}
+}
+
+/** Writes bits and integer fields onto the end of a `BitVec`.
+
+The writer appends to its vector, so the length of the vector is always the
+position of the next bit to be written. Bytes written through `std::io::Write`
+are appended as eight bits each, in the bit order of the cursor `C`, whether or
+not the writer is aligned to a byte boundary.
+
+# Type Parameters
+
+- `C`: The `Cursor` type of the destination vector.
+- `T`: The storage type of the destination vector.
+
+# Examples
+
+```rust
+use bitvec::prelude::*;
+use bitvec::io::BitWriter;
+
+let mut writer = BitWriter::new(BitVec::<BigEndian, u8>::new());
+writer.write_bit(true);
+writer.write_bits(0b01, 2);
+writer.align_to_byte();
+writer.write_bits(0xAB, 8);
+assert_eq!(writer.into_inner().as_slice(), &[0xA0, 0xAB]);
+```
+**/
+#[derive(Clone, Debug)]
+pub struct BitWriter<C, T>
+where C: Cursor, T: BitStore {
+	/// The destination vector.
+	bits: BitVec<C, T>,
+}
+
+impl<C, T> BitWriter<C, T>
+where C: Cursor, T: BitStore {
+	/// Creates a writer which appends to a bit vector.
+	///
+	/// # Parameters
+	///
+	/// - `bits`: The vector to which bits will be written. Its existing
+	///   contents are kept, and new bits are written after them.
+	///
+	/// # Returns
+	///
+	/// A `BitWriter` positioned at the end of `bits`.
+	pub fn new(bits: BitVec<C, T>) -> Self {
+		Self { bits }
+	}
+
+	/// Gets the number of bits in the destination vector.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	///
+	/// # Returns
+	///
+	/// The index in the destination vector of the next bit to be written.
+	pub fn position(&self) -> usize {
+		self.bits.len()
+	}
+
+	/// Appends a single bit.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	/// - `bit`: The bit to append.
+	pub fn write_bit(&mut self, bit: bool) {
+		self.bits.push(bit);
+	}
+
+	/// Appends an integer field, most significant bit first.
+	///
+	/// This has the same behavior as [`BitVec::push_bits`].
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	/// - `value`: The value to append. Bits above `width` are ignored.
+	/// - `width`: The number of bits to append. This may be at most 64.
+	///
+	/// # Panics
+	///
+	/// This panics if `width` is greater than 64.
+	///
+	/// [`BitVec::push_bits`]: ../vec/struct.BitVec.html#method.push_bits
+	pub fn write_bits(&mut self, value: u64, width: usize) {
+		self.bits.push_bits(value, width);
+	}
+
+	/// Appends an integer field, least significant bit first.
+	///
+	/// This has the same behavior as [`BitVec::push_bits_lsb0`].
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	/// - `value`: The value to append. Bits above `width` are ignored.
+	/// - `width`: The number of bits to append. This may be at most 64.
+	///
+	/// # Panics
+	///
+	/// This panics if `width` is greater than 64.
+	///
+	/// [`BitVec::push_bits_lsb0`]: ../vec/struct.BitVec.html#method.push_bits_lsb0
+	pub fn write_bits_lsb0(&mut self, value: u64, width: usize) {
+		self.bits.push_bits_lsb0(value, width);
+	}
+
+	/// Appends zero bits until the position is a multiple of eight.
+	///
+	/// This does nothing if the writer is already at a byte boundary.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	///
+	/// # Returns
+	///
+	/// The number of zero bits appended, which is less than eight.
+	pub fn align_to_byte(&mut self) -> usize {
+		let len = self.bits.len();
+		let pad = (8 - len % 8) % 8;
+		self.bits.resize(len + pad, false);
+		pad
+	}
+
+	/// Borrows the destination vector.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	///
+	/// # Returns
+	///
+	/// All bits written so far, including any the vector held beforehand.
+	pub fn get_ref(&self) -> &BitVec<C, T> {
+		&self.bits
+	}
+
+	/// Unwraps the writer, returning the destination vector.
+	///
+	/// # Parameters
+	///
+	/// - `self`
+	///
+	/// # Returns
+	///
+	/// The vector, including all bits written through the writer.
+	pub fn into_inner(self) -> BitVec<C, T> {
+		self.bits
+	}
+}
+
+/// Creates a writer into a new, empty vector.
+impl<C, T> Default for BitWriter<C, T>
+where C: Cursor, T: BitStore {
+	fn default() -> Self {
+		Self::new(BitVec::new())
+	}
+}
+
+impl<C, T> Write for BitWriter<C, T>
+where C: Cursor, T: BitStore {
+	/// Appends the bits of each byte in `buf`.
+	///
+	/// This defers to the `Write` implementation of `BitVec`.
+	fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+		self.bits.write(buf)
+	}
+
+	fn flush(&mut self) -> io::Result<()> { Ok(()) }
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::{
+		cursor::{
+			BigEndian,
+			LittleEndian,
+		},
+		vec::BitVec,
+	};
+	use std::io::Write;
+
+	#[test]
+	fn write_read() {
+		let mut bv = BitVec::<BigEndian, u8>::new();
+		assert_eq!(bv.write(&[0xAB, 0xCD]).unwrap(), 2);
+		assert_eq!(bv, bitvec![
+			1, 0, 1, 0, 1, 0, 1, 1,
+			1, 1, 0, 0, 1, 1, 0, 1,
+		]);
+
+		let mut reader = BitReader::new(&bv);
+		let mut buf = [0u8; 3];
+		assert_eq!(reader.read(&mut buf).unwrap(), 2);
+		assert_eq!(buf, [0xAB, 0xCD, 0]);
+		assert_eq!(reader.position(), 16);
+		assert_eq!(reader.read(&mut buf).unwrap(), 0);
+
+		let mut bv = BitVec::<LittleEndian, u16>::new();
+		bv.write_all(&[0xAB, 0xCD]).unwrap();
+		assert_eq!(bv, bitvec![
+			1, 1, 0, 1, 0, 1, 0, 1,
+			1, 0, 1, 1, 0, 0, 1, 1,
+		]);
+		let mut out = Vec::new();
+		BitReader::new(&bv).read_to_end(&mut out).unwrap();
+		assert_eq!(out, [0xAB, 0xCD]);
+	}
+
+	#[test]
+	fn read_partial() {
+		let bits = bitvec![BigEndian, u32; 1, 1, 1, 1, 0, 0, 0, 0, 1, 1];
+		let mut reader = BitReader::new(&bits);
+		let mut byte = [0u8];
+		assert_eq!(reader.read(&mut byte).unwrap(), 1);
+		assert_eq!(byte, [0xF0]);
+		assert_eq!(reader.read(&mut byte).unwrap(), 1);
+		assert_eq!(byte, [0xC0]);
+		assert_eq!(reader.position(), 10);
+		assert_eq!(reader.read(&mut byte).unwrap(), 0);
+		assert_eq!(reader.into_inner(), &bits);
+	}
+
+	#[test]
+	fn writer_fields() {
+		let mut writer = BitWriter::<BigEndian, u8>::default();
+		assert_eq!(writer.position(), 0);
+		assert_eq!(writer.align_to_byte(), 0);
+		writer.write_bits(0b101, 3);
+		writer.write_bit(false);
+		writer.write_bits(0x1F, 9);
+		assert_eq!(writer.position(), 13);
+		assert_eq!(writer.get_ref().load_bits(4, 9), 0x1F);
+
+		assert_eq!(writer.align_to_byte(), 3);
+		assert_eq!(writer.position(), 16);
+		assert_eq!(writer.align_to_byte(), 0);
+		writer.write_all(&[0x5A]).unwrap();
+		writer.write_bits(!0, 64);
+
+		let bv = writer.into_inner();
+		assert_eq!(bv.len(), 88);
+		assert_eq!(bv.as_slice()[.. 3], [0xA0, 0xF8, 0x5A]);
+		assert!(bv[24 ..].all());
+
+		let mut writer = BitWriter::new(bitvec![LittleEndian, u16; 1; 5]);
+		writer.align_to_byte();
+		writer.write_bits(0b11, 2);
+		writer.align_to_byte();
+		assert_eq!(writer.into_inner().as_slice(), &[0x031F]);
+
+		let mut writer = BitWriter::new(BitVec::<LittleEndian, u8>::new());
+		writer.write_bits_lsb0(0b011, 3);
+		writer.write_bits_lsb0(0x1A, 5);
+		writer.write_bits_lsb0(0x2_DEAD_BEEF, 34);
+		let bv = writer.into_inner();
+		assert_eq!(bv.len(), 42);
+		assert_eq!(bv.as_slice()[.. 5], [0xD3, 0xEF, 0xBE, 0xAD, 0xDE]);
+	}
+
+	#[test]
+	fn reader_lsb0_fields() {
+		let mut writer = BitWriter::new(BitVec::<LittleEndian, u8>::new());
+		writer.write_bits_lsb0(0b011, 3);
+		writer.write_bits_lsb0(0x1A, 5);
+		writer.write_bits_lsb0(0x2_DEAD_BEEF, 34);
+		let frame = writer.into_inner();
+		let mut reader = BitReader::new(&frame);
+		assert_eq!(reader.read_bits_lsb0(3), Some(0b011));
+		assert_eq!(reader.read_bits_lsb0(5), Some(0x1A));
+		assert_eq!(reader.read_bits_lsb0(35), None);
+		assert_eq!(reader.read_bits_lsb0(34), Some(0x2_DEAD_BEEF));
+		assert_eq!(reader.read_bits_lsb0(1), None);
+
+		//  The two orders mirror each other.
+		let bits = bitvec![BigEndian, u8; 1, 1, 0, 0];
+		assert_eq!(BitReader::new(&bits).read_bits(4), Some(0b1100));
+		assert_eq!(BitReader::new(&bits).read_bits_lsb0(4), Some(0b0011));
+	}
+
+	#[test]
+	fn reader_fields() {
+		let mut writer = BitWriter::new(BitVec::<LittleEndian, u32>::new());
+		writer.write_bits(0b110, 3);
+		writer.write_bit(true);
+		writer.write_bits(0x2_DEAD_BEEF, 34);
+		writer.align_to_byte();
+		writer.write_bits(0x7F, 7);
+		let frame = writer.into_inner();
+
+		let mut reader = BitReader::new(&frame);
+		assert_eq!(reader.remaining(), 47);
+		assert_eq!(reader.align_to_byte(), 0);
+		assert_eq!(reader.read_bits(3), Some(0b110));
+		assert_eq!(reader.read_bit(), Some(true));
+		assert_eq!(reader.read_bits(34), Some(0x2_DEAD_BEEF));
+		assert_eq!(reader.align_to_byte(), 2);
+		assert_eq!(reader.position(), 40);
+
+		assert_eq!(reader.read_bits(8), None);
+		assert_eq!(reader.remaining(), 7);
+		assert_eq!(reader.read_bits(0), Some(0));
+		assert_eq!(reader.read_bits(7), Some(0x7F));
+		assert_eq!(reader.read_bit(), None);
+		assert_eq!(reader.read_bits(1), None);
+		assert_eq!(reader.align_to_byte(), 0);
+		assert_eq!(reader.position(), 47);
+
+		let bits = bitvec![BigEndian, u8; 1, 0, 1];
+		let mut reader = BitReader::new(&bits);
+		reader.read_bit();
+		assert_eq!(reader.align_to_byte(), 2);
+		assert_eq!(reader.remaining(), 0);
+	}
+}
@@ -42,6 +42,9 @@ extern crate alloc;
 #[cfg(feature = "std")]
 extern crate core;
 
+#[cfg(feature = "rayon")]
+extern crate rayon;
+
 #[cfg(feature = "serde")]
 extern crate serde;
 
@@ -56,7 +59,7 @@ mod macros;
 
 pub mod bits;
 pub mod cursor;
-mod domain;
+pub mod domain;
 pub mod indices;
 mod pointer;
 pub mod prelude;
@@ -71,6 +74,12 @@ pub mod boxed;
 #[cfg_attr(all(not(feature = "alloc"), tarpaulin), skip)]
 pub mod vec;
 
+#[cfg(feature = "std")]
+pub mod io;
+
+#[cfg(feature = "rayon")]
+pub mod par;
+
 #[cfg(feature = "serde")]
 mod serdes;
 
@@ -1,7 +1,7 @@
 /*! Utility macros for constructing data structures and implementing bulk types.
 
-The only public macro is `bitvec`; this module also provides convenience macros
-for code generation.
+The public macros are `bitvec`, `bitbox`, and `bitarr`; this module also
+provides convenience macros for code generation.
 !*/
 
 /** Construct a `BitVec` out of a literal array in source code, like `vec!`.
@@ -26,14 +26,9 @@ The bit list syntax `bitvec![expr, expr, expr...]` currently produces an
 `&[bool]` slice of the initial pattern, which is written into the final
 artifact’s static memory and may consume excessive space.
 
-The repetition syntax `bitec![expr; count]` currently zeros its allocated buffer
-before setting the first `count` bits to `expr`. This may result in a
-performance penalty when using `bitvec![1; N]`, as the allocation will be zeroed
-and then a subset will be set high.
-
-This behavior is currently required to maintain compatibility with `serde`
-expectations that dead bits are zero. As the `serdes` module removes those
-expectations, the repetition syntax implementation may speed up.
+The repetition syntax `bitvec![expr; count]` fills its allocated buffer an
+element at a time with `expr`, and then clears the dead bits in the last live
+element, so that they are zero as in every other constructor.
 
 # Examples
 
@@ -119,17 +114,24 @@ macro_rules! bitvec {
 		bv
 	}};
 
-	//  `[$val; $rep]` can just allocate a slab of at least `$rep` bits and then
-	//  use `.set_all` to force them to `$val`. This is much faster than
-	//  collecting from a bitstream.
+	//  `[$val; $rep]` can just allocate a slab of at least `$rep` bits and fill
+	//  it by element. This is much faster than collecting from a bitstream.
+	//  A fill of ones also sets the dead bits in the last element, so those are
+	//  cleared again, which touches only that one element.
 
 	( __bv_impl__ $cursor:path , $bits:ty ; $val:expr ; $rep:expr ) => {{
-		let mut bv = $crate::vec::BitVec::<$cursor, $bits>::with_capacity($rep);
-		bv.set_elements(0);
-		unsafe { bv.set_len($rep); }
+		let rep: usize = $rep;
 		let one = $val != 0;
+		let fill: $bits = if one { !0 } else { 0 };
+		let mut bv = $crate::vec::BitVec::<$cursor, $bits>::with_capacity(rep);
+		bv.set_elements(fill);
 		if one {
-			bv.set_all(one);
+			let width = <$bits as $crate::store::BitStore>::BITS as usize;
+			unsafe { bv.set_len(rep + (width - rep % width) % width); }
+			bv.truncate_zeroing(rep);
+		}
+		else {
+			unsafe { bv.set_len(rep); }
 		}
 		bv
 	}};
@@ -213,6 +215,373 @@ macro_rules! bitbox {
 	};
 }
 
+/** Construct a fixed-size element array out of a literal bit list, without
+allocating.
+
+`bitarr!` is the allocation-free counterpart of [`bitvec!`]. It takes the name
+of one of the built-in cursors (`BigEndian` or `LittleEndian`, or their aliases
+`Msb0` and `Lsb0`), the name of a fundamental store (`u8`, `u16`, `u32`, or
+`u64`), and zero or more bits. Each bit must be a single literal token, and is
+considered to represent `1` if it is any other value than exactly zero.
+
+The macro expands to an array expression `[T; N]`, where `N` is the number of
+elements needed to hold all the bits. The bits are placed in the elements
+according to the cursor, and the unused bits of the last element are zero. The
+expansion is built only from integer literals, shifts, and bitwise operators,
+so it can initialize `const` and `static` items, and needs no allocator. The
+array is viewed as a `BitSlice` through the [`Bits`] and [`BitsMut`] traits.
+
+# Notes
+
+Because the cursor and store are matched by name, custom cursors, paths such as
+`bitvec::cursor::BigEndian`, and the `Local` and `Word` aliases are not
+accepted; use `bitvec!` for those.
+
+Each 64 bits of the list costs one level of macro recursion, so lists of more
+than about three thousand bits need a larger `#![recursion_limit]`.
+
+# Examples
+
+```rust
+use bitvec::prelude::*;
+
+const FLAGS: [u8; 2] = bitarr![BigEndian, u8; 1, 0, 1, 1, 0, 0, 0, 0, 1, 1];
+assert_eq!(FLAGS, [0b1011_0000, 0b1100_0000]);
+
+static MASK: [u16; 1] = bitarr![LittleEndian, u16; 0, 1, 1,];
+let bits = MASK.bits::<LittleEndian>();
+assert_eq!(bits.count_ones(), 2);
+assert!(bits[1] && bits[2]);
+
+let none: [u32; 0] = bitarr![Msb0, u32;];
+assert!(none.bits::<Msb0>().is_empty());
+```
+
+[`Bits`]: bits/trait.Bits.html
+[`BitsMut`]: bits/trait.BitsMut.html
+[`bitvec!`]: macro.bitvec.html
+**/
+#[macro_export]
+macro_rules! bitarr {
+	//  bitarr![ Msb0 , type ; 0 , 1 , … ] and the other aliases
+	( Msb0 , $store:ident ; $( $bit:tt ),* ) => {
+		bitarr![ BigEndian , $store ; $( $bit ),* ]
+	};
+	( Msb0 , $store:ident ; $( $bit:tt , )* ) => {
+		bitarr![ BigEndian , $store ; $( $bit ),* ]
+	};
+	( Lsb0 , $store:ident ; $( $bit:tt ),* ) => {
+		bitarr![ LittleEndian , $store ; $( $bit ),* ]
+	};
+	( Lsb0 , $store:ident ; $( $bit:tt , )* ) => {
+		bitarr![ LittleEndian , $store ; $( $bit ),* ]
+	};
+
+	//  bitarr![ cursor , type ; 0 , 1 , … ]
+	( $cursor:ident , $store:ident ; $( $bit:tt ),* ) => {
+		bitarr![ __ba_bits__ $cursor , $store ; [] ; $( $bit ),* ]
+	};
+	//  bitarr![ cursor , type ; 0 , 1 , … , ]
+	( $cursor:ident , $store:ident ; $( $bit:tt , )* ) => {
+		bitarr![ __ba_bits__ $cursor , $store ; [] ; $( $bit ),* ]
+	};
+
+	//  The bits are first gathered into bytes. Each step takes as many bits as
+	//  it can, as every step is a level of macro recursion. The last byte is
+	//  padded with zeros.
+	(
+		__ba_bits__ $c:ident , $s:ident ; [ $( $byte:expr ),* ] ;
+		$b0:tt , $b1:tt , $b2:tt , $b3:tt ,
+		$b4:tt , $b5:tt , $b6:tt , $b7:tt ,
+		$b8:tt , $b9:tt , $b10:tt , $b11:tt ,
+		$b12:tt , $b13:tt , $b14:tt , $b15:tt ,
+		$b16:tt , $b17:tt , $b18:tt , $b19:tt ,
+		$b20:tt , $b21:tt , $b22:tt , $b23:tt ,
+		$b24:tt , $b25:tt , $b26:tt , $b27:tt ,
+		$b28:tt , $b29:tt , $b30:tt , $b31:tt ,
+		$b32:tt , $b33:tt , $b34:tt , $b35:tt ,
+		$b36:tt , $b37:tt , $b38:tt , $b39:tt ,
+		$b40:tt , $b41:tt , $b42:tt , $b43:tt ,
+		$b44:tt , $b45:tt , $b46:tt , $b47:tt ,
+		$b48:tt , $b49:tt , $b50:tt , $b51:tt ,
+		$b52:tt , $b53:tt , $b54:tt , $b55:tt ,
+		$b56:tt , $b57:tt , $b58:tt , $b59:tt ,
+		$b60:tt , $b61:tt , $b62:tt , $b63:tt
+		$( , $rest:tt )*
+	) => {
+		bitarr![ __ba_bits__ $c , $s ; [ $( $byte , )*
+			bitarr![ __ba_byte__ $c ;
+				$b0 , $b1 , $b2 , $b3 , $b4 , $b5 , $b6 , $b7 ] ,
+			bitarr![ __ba_byte__ $c ;
+				$b8 , $b9 , $b10 , $b11 , $b12 , $b13 , $b14 , $b15 ] ,
+			bitarr![ __ba_byte__ $c ;
+				$b16 , $b17 , $b18 , $b19 , $b20 , $b21 , $b22 , $b23 ] ,
+			bitarr![ __ba_byte__ $c ;
+				$b24 , $b25 , $b26 , $b27 , $b28 , $b29 , $b30 , $b31 ] ,
+			bitarr![ __ba_byte__ $c ;
+				$b32 , $b33 , $b34 , $b35 , $b36 , $b37 , $b38 , $b39 ] ,
+			bitarr![ __ba_byte__ $c ;
+				$b40 , $b41 , $b42 , $b43 , $b44 , $b45 , $b46 , $b47 ] ,
+			bitarr![ __ba_byte__ $c ;
+				$b48 , $b49 , $b50 , $b51 , $b52 , $b53 , $b54 , $b55 ] ,
+			bitarr![ __ba_byte__ $c ;
+				$b56 , $b57 , $b58 , $b59 , $b60 , $b61 , $b62 , $b63 ]
+		] ; $( $rest ),* ]
+	};
+	(
+		__ba_bits__ $c:ident , $s:ident ; [ $( $byte:expr ),* ] ;
+		$b0:tt , $b1:tt , $b2:tt , $b3:tt , $b4:tt , $b5:tt , $b6:tt , $b7:tt
+		$( , $rest:tt )*
+	) => {
+		bitarr![ __ba_bits__ $c , $s ; [ $( $byte , )*
+			bitarr![ __ba_byte__ $c ;
+				$b0 , $b1 , $b2 , $b3 , $b4 , $b5 , $b6 , $b7 ]
+		] ; $( $rest ),* ]
+	};
+	( __ba_bits__ $c:ident , $s:ident ; [ $( $byte:expr ),* ] ; ) => {
+		bitarr![ __ba_elts__ $c , $s ; [] ; $( $byte ),* ]
+	};
+	(
+		__ba_bits__ $c:ident , $s:ident ; [ $( $byte:expr ),* ] ;
+		$( $b:tt ),+
+	) => {
+		bitarr![ __ba_elts__ $c , $s ; [] ; $( $byte , )*
+			bitarr![ __ba_byte__ $c ; $( $b , )+ 0 , 0 , 0 , 0 , 0 , 0 , 0 ]
+		]
+	};
+
+	//  The bytes are then joined into elements, thirty-two bytes at a time
+	//  where possible. The last element is padded with zero bytes.
+	( __ba_elts__ $c:ident , u8 ; [] ; $( $byte:expr ),* ) => {
+		[ $( $byte ),* ]
+	};
+	(
+		__ba_elts__ $c:ident , u16 ; [ $( $elt:expr ),* ] ;
+		$a0:expr , $a1:expr , $a2:expr , $a3:expr ,
+		$a4:expr , $a5:expr , $a6:expr , $a7:expr ,
+		$a8:expr , $a9:expr , $a10:expr , $a11:expr ,
+		$a12:expr , $a13:expr , $a14:expr , $a15:expr ,
+		$a16:expr , $a17:expr , $a18:expr , $a19:expr ,
+		$a20:expr , $a21:expr , $a22:expr , $a23:expr ,
+		$a24:expr , $a25:expr , $a26:expr , $a27:expr ,
+		$a28:expr , $a29:expr , $a30:expr , $a31:expr
+		$( , $rest:expr )*
+	) => {
+		bitarr![ __ba_elts__ $c , u16 ; [ $( $elt , )*
+			bitarr![ __ba_elt__ $c , u16 ;
+				$a0 , $a1 ] ,
+			bitarr![ __ba_elt__ $c , u16 ;
+				$a2 , $a3 ] ,
+			bitarr![ __ba_elt__ $c , u16 ;
+				$a4 , $a5 ] ,
+			bitarr![ __ba_elt__ $c , u16 ;
+				$a6 , $a7 ] ,
+			bitarr![ __ba_elt__ $c , u16 ;
+				$a8 , $a9 ] ,
+			bitarr![ __ba_elt__ $c , u16 ;
+				$a10 , $a11 ] ,
+			bitarr![ __ba_elt__ $c , u16 ;
+				$a12 , $a13 ] ,
+			bitarr![ __ba_elt__ $c , u16 ;
+				$a14 , $a15 ] ,
+			bitarr![ __ba_elt__ $c , u16 ;
+				$a16 , $a17 ] ,
+			bitarr![ __ba_elt__ $c , u16 ;
+				$a18 , $a19 ] ,
+			bitarr![ __ba_elt__ $c , u16 ;
+				$a20 , $a21 ] ,
+			bitarr![ __ba_elt__ $c , u16 ;
+				$a22 , $a23 ] ,
+			bitarr![ __ba_elt__ $c , u16 ;
+				$a24 , $a25 ] ,
+			bitarr![ __ba_elt__ $c , u16 ;
+				$a26 , $a27 ] ,
+			bitarr![ __ba_elt__ $c , u16 ;
+				$a28 , $a29 ] ,
+			bitarr![ __ba_elt__ $c , u16 ;
+				$a30 , $a31 ]
+		] ; $( $rest ),* ]
+	};
+	(
+		__ba_elts__ $c:ident , u32 ; [ $( $elt:expr ),* ] ;
+		$a0:expr , $a1:expr , $a2:expr , $a3:expr ,
+		$a4:expr , $a5:expr , $a6:expr , $a7:expr ,
+		$a8:expr , $a9:expr , $a10:expr , $a11:expr ,
+		$a12:expr , $a13:expr , $a14:expr , $a15:expr ,
+		$a16:expr , $a17:expr , $a18:expr , $a19:expr ,
+		$a20:expr , $a21:expr , $a22:expr , $a23:expr ,
+		$a24:expr , $a25:expr , $a26:expr , $a27:expr ,
+		$a28:expr , $a29:expr , $a30:expr , $a31:expr
+		$( , $rest:expr )*
+	) => {
+		bitarr![ __ba_elts__ $c , u32 ; [ $( $elt , )*
+			bitarr![ __ba_elt__ $c , u32 ;
+				$a0 , $a1 , $a2 , $a3 ] ,
+			bitarr![ __ba_elt__ $c , u32 ;
+				$a4 , $a5 , $a6 , $a7 ] ,
+			bitarr![ __ba_elt__ $c , u32 ;
+				$a8 , $a9 , $a10 , $a11 ] ,
+			bitarr![ __ba_elt__ $c , u32 ;
+				$a12 , $a13 , $a14 , $a15 ] ,
+			bitarr![ __ba_elt__ $c , u32 ;
+				$a16 , $a17 , $a18 , $a19 ] ,
+			bitarr![ __ba_elt__ $c , u32 ;
+				$a20 , $a21 , $a22 , $a23 ] ,
+			bitarr![ __ba_elt__ $c , u32 ;
+				$a24 , $a25 , $a26 , $a27 ] ,
+			bitarr![ __ba_elt__ $c , u32 ;
+				$a28 , $a29 , $a30 , $a31 ]
+		] ; $( $rest ),* ]
+	};
+	(
+		__ba_elts__ $c:ident , u64 ; [ $( $elt:expr ),* ] ;
+		$a0:expr , $a1:expr , $a2:expr , $a3:expr ,
+		$a4:expr , $a5:expr , $a6:expr , $a7:expr ,
+		$a8:expr , $a9:expr , $a10:expr , $a11:expr ,
+		$a12:expr , $a13:expr , $a14:expr , $a15:expr ,
+		$a16:expr , $a17:expr , $a18:expr , $a19:expr ,
+		$a20:expr , $a21:expr , $a22:expr , $a23:expr ,
+		$a24:expr , $a25:expr , $a26:expr , $a27:expr ,
+		$a28:expr , $a29:expr , $a30:expr , $a31:expr
+		$( , $rest:expr )*
+	) => {
+		bitarr![ __ba_elts__ $c , u64 ; [ $( $elt , )*
+			bitarr![ __ba_elt__ $c , u64 ;
+				$a0 , $a1 , $a2 , $a3 , $a4 , $a5 , $a6 , $a7 ] ,
+			bitarr![ __ba_elt__ $c , u64 ;
+				$a8 , $a9 , $a10 , $a11 , $a12 , $a13 , $a14 , $a15 ] ,
+			bitarr![ __ba_elt__ $c , u64 ;
+				$a16 , $a17 , $a18 , $a19 , $a20 , $a21 , $a22 , $a23 ] ,
+			bitarr![ __ba_elt__ $c , u64 ;
+				$a24 , $a25 , $a26 , $a27 , $a28 , $a29 , $a30 , $a31 ]
+		] ; $( $rest ),* ]
+	};
+	(
+		__ba_elts__ $c:ident , u16 ; [ $( $elt:expr ),* ] ;
+		$a0:expr , $a1:expr
+		$( , $rest:expr )*
+	) => {
+		bitarr![ __ba_elts__ $c , u16 ; [ $( $elt , )*
+			bitarr![ __ba_elt__ $c , u16 ;
+				$a0 , $a1 ]
+		] ; $( $rest ),* ]
+	};
+	(
+		__ba_elts__ $c:ident , u32 ; [ $( $elt:expr ),* ] ;
+		$a0:expr , $a1:expr , $a2:expr , $a3:expr
+		$( , $rest:expr )*
+	) => {
+		bitarr![ __ba_elts__ $c , u32 ; [ $( $elt , )*
+			bitarr![ __ba_elt__ $c , u32 ;
+				$a0 , $a1 , $a2 , $a3 ]
+		] ; $( $rest ),* ]
+	};
+	(
+		__ba_elts__ $c:ident , u64 ; [ $( $elt:expr ),* ] ;
+		$a0:expr , $a1:expr , $a2:expr , $a3:expr ,
+		$a4:expr , $a5:expr , $a6:expr , $a7:expr
+		$( , $rest:expr )*
+	) => {
+		bitarr![ __ba_elts__ $c , u64 ; [ $( $elt , )*
+			bitarr![ __ba_elt__ $c , u64 ;
+				$a0 , $a1 , $a2 , $a3 , $a4 , $a5 , $a6 , $a7 ]
+		] ; $( $rest ),* ]
+	};
+	( __ba_elts__ $c:ident , $s:ident ; [ $( $elt:expr ),* ] ; ) => {
+		[ $( $elt ),* ]
+	};
+	(
+		__ba_elts__ $c:ident , $s:ident ; [ $( $elt:expr ),* ] ;
+		$( $a:expr ),+
+	) => {
+		bitarr![ __ba_elts__ $c , $s ; [ $( $elt , )*
+			bitarr![ __ba_elt__ $c , $s ; $( $a , )+ 0 , 0 , 0 , 0 , 0 , 0 , 0 ]
+		] ; ]
+	};
+
+	//  The value of a byte or element, with bits or bytes in cursor order.
+	(
+		__ba_byte__ BigEndian ;
+		$b0:tt , $b1:tt , $b2:tt , $b3:tt , $b4:tt , $b5:tt , $b6:tt , $b7:tt
+		$( , $pad:tt )*
+	) => {
+		bitarr![ __ba_join__ u8 ;
+			$b0 , $b1 , $b2 , $b3 , $b4 , $b5 , $b6 , $b7 ;
+			7 , 6 , 5 , 4 , 3 , 2 , 1 , 0 ]
+	};
+	(
+		__ba_byte__ LittleEndian ;
+		$b0:tt , $b1:tt , $b2:tt , $b3:tt , $b4:tt , $b5:tt , $b6:tt , $b7:tt
+		$( , $pad:tt )*
+	) => {
+		bitarr![ __ba_join__ u8 ;
+			$b0 , $b1 , $b2 , $b3 , $b4 , $b5 , $b6 , $b7 ;
+			0 , 1 , 2 , 3 , 4 , 5 , 6 , 7 ]
+	};
+	(
+		__ba_elt__ BigEndian , u16 ;
+		$a0:expr , $a1:expr
+		$( , $pad:expr )*
+	) => {
+		bitarr![ __ba_wide__ u16 ;
+			$a0 , $a1 ;
+			8 , 0 ]
+	};
+	(
+		__ba_elt__ LittleEndian , u16 ;
+		$a0:expr , $a1:expr
+		$( , $pad:expr )*
+	) => {
+		bitarr![ __ba_wide__ u16 ;
+			$a0 , $a1 ;
+			0 , 8 ]
+	};
+	(
+		__ba_elt__ BigEndian , u32 ;
+		$a0:expr , $a1:expr , $a2:expr , $a3:expr
+		$( , $pad:expr )*
+	) => {
+		bitarr![ __ba_wide__ u32 ;
+			$a0 , $a1 , $a2 , $a3 ;
+			24 , 16 , 8 , 0 ]
+	};
+	(
+		__ba_elt__ LittleEndian , u32 ;
+		$a0:expr , $a1:expr , $a2:expr , $a3:expr
+		$( , $pad:expr )*
+	) => {
+		bitarr![ __ba_wide__ u32 ;
+			$a0 , $a1 , $a2 , $a3 ;
+			0 , 8 , 16 , 24 ]
+	};
+	(
+		__ba_elt__ BigEndian , u64 ;
+		$a0:expr , $a1:expr , $a2:expr , $a3:expr ,
+		$a4:expr , $a5:expr , $a6:expr , $a7:expr
+		$( , $pad:expr )*
+	) => {
+		bitarr![ __ba_wide__ u64 ;
+			$a0 , $a1 , $a2 , $a3 , $a4 , $a5 , $a6 , $a7 ;
+			56 , 48 , 40 , 32 , 24 , 16 , 8 , 0 ]
+	};
+	(
+		__ba_elt__ LittleEndian , u64 ;
+		$a0:expr , $a1:expr , $a2:expr , $a3:expr ,
+		$a4:expr , $a5:expr , $a6:expr , $a7:expr
+		$( , $pad:expr )*
+	) => {
+		bitarr![ __ba_wide__ u64 ;
+			$a0 , $a1 , $a2 , $a3 , $a4 , $a5 , $a6 , $a7 ;
+			0 , 8 , 16 , 24 , 32 , 40 , 48 , 56 ]
+	};
+	( __ba_join__ $t:ty ; $( $b:expr ),* ; $( $shift:expr ),* ) => {
+		0 $( | (($b != 0) as $t) << $shift )*
+	};
+	( __ba_wide__ $t:ty ; $( $a:expr ),* ; $( $shift:expr ),* ) => {
+		0 $( | ($a as $t) << $shift )*
+	};
+}
+
 #[doc(hidden)]
 macro_rules! __bitslice_shift {
 	( $( $t:ty ),+ ) => { $(
@@ -294,15 +663,19 @@ macro_rules! __bitvec_shift {
 	)+ };
 }
 
-#[cfg(all(test, feature = "alloc"))]
+#[cfg(test)]
 mod tests {
 	#[allow(unused_imports)]
-	use crate::cursor::{
-		BigEndian,
-		LittleEndian,
+	use crate::{
+		bits::Bits,
+		cursor::{
+			BigEndian,
+			LittleEndian,
+		},
 	};
 
 	#[test]
+	#[cfg(feature = "alloc")]
 	fn compile_bitvec_macros() {
 		bitvec![0, 1];
 		bitvec![BigEndian; 0, 1];
@@ -330,6 +703,7 @@ mod tests {
 	}
 
 	#[test]
+	#[cfg(feature = "alloc")]
 	fn compile_bitbox_macros() {
 		bitbox![0, 1];
 		bitbox![BigEndian; 0, 1];
@@ -355,4 +729,83 @@ mod tests {
 		bitbox![BigEndian, u64; 0; 70];
 		bitbox![LittleEndian, u64; 1; 70];
 	}
+
+	#[test]
+	#[cfg(feature = "alloc")]
+	fn repetition_storage() {
+		let bv = bitvec![BigEndian, u8; 1; 20];
+		assert_eq!(bv.len(), 20);
+		assert!(bv.all());
+		assert_eq!(bv.as_slice(), &[0xFF, 0xFF, 0xF0]);
+
+		let bv = bitvec![LittleEndian, u16; 1; 20];
+		assert_eq!(bv.as_slice(), &[0xFFFF, 0x000F]);
+
+		let bv = bitvec![BigEndian, u8; 1; 16];
+		assert_eq!(bv.as_slice(), &[0xFF, 0xFF]);
+
+		let bv = bitvec![BigEndian, u8; 0; 20];
+		assert!(bv.not_any());
+		assert_eq!(bv.as_slice(), &[0, 0, 0]);
+
+		assert!(bitvec![BigEndian, u8; 1; 0].is_empty());
+		assert_eq!(bitvec![LittleEndian, u8; 5; 3].as_slice(), &[0b0000_0111]);
+	}
+
+	//  `bitarr!` needs neither `alloc` nor `std`, so these also run in the
+	//  `no_std` build.
+	#[test]
+	fn bitarr_const() {
+		const BE8: [u8; 2] = bitarr![BigEndian, u8; 1, 0, 1, 1, 0, 0, 0, 0, 1];
+		const LE8: [u8; 1] = bitarr![LittleEndian, u8; 1, 0, 1, 1, 0, 0, 0, 0,];
+		const BE16: [u16; 1] = bitarr![BigEndian, u16; 0, 1, 1];
+		const LE16: [u16; 1] = bitarr![LittleEndian, u16; 0, 1, 1,];
+		const BE32: [u32; 2] = bitarr![BigEndian, u32;
+			1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+			0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1,
+			1,
+		];
+		const LE32: [u32; 1] = bitarr![Lsb0, u32; 0, 0, 0, 0, 0, 0, 0, 0, 1];
+		const BE64: [u64; 1] = bitarr![Msb0, u64; 1, 1];
+		const LE64: [u64; 1] = bitarr![LittleEndian, u64; 1, 1];
+		const EMPTY: [u16; 0] = bitarr![BigEndian, u16;];
+
+		assert_eq!(BE8, [0b1011_0000, 0b1000_0000]);
+		assert_eq!(LE8, [0b0000_1101]);
+		assert_eq!(BE16, [0x6000]);
+		assert_eq!(LE16, [0x0006]);
+		assert_eq!(BE32, [0x8000_0001, 0x8000_0000]);
+		assert_eq!(LE32, [0x0000_0100]);
+		assert_eq!(BE64, [0xC000_0000_0000_0000]);
+		assert_eq!(LE64, [3]);
+		assert!(EMPTY.bits::<BigEndian>().is_empty());
+
+		let bits = BE8.bits::<BigEndian>();
+		assert_eq!(bits.count_ones(), 4);
+		assert!(bits[0] && bits[2] && bits[3] && bits[8]);
+		assert!(BE32.bits::<BigEndian>()[31 .. 33].all());
+		assert!(LE32.bits::<LittleEndian>()[8]);
+	}
+
+	#[test]
+	fn bitarr_static() {
+		//  Long lists are taken sixty-four bits at a time.
+		static ONES: [u16; 9] = bitarr![LittleEndian, u16;
+			1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1,
+			1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1,
+			1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1,
+			1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1,
+			1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1,
+			1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1,
+			1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1,
+			1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1,
+			1, 0, 1,
+		];
+
+		assert_eq!(ONES[.. 8], [0xFFFF; 8]);
+		assert_eq!(ONES[8], 0b101);
+		let bits = ONES.bits::<LittleEndian>();
+		assert_eq!(bits.count_ones(), 130);
+		assert!(bits[.. 128].all());
+	}
 }
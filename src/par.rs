@@ -0,0 +1,302 @@
+/*! `rayon`-powered parallel operations
+
+This module extends `BitSlice` with operations that divide their work across the
+`rayon` thread pool. Work is only divided along element boundaries: the fully
+occupied interior elements of a slice are processed in parallel, and the
+partially occupied edge elements, if any, are processed serially.
+
+With the `atomic` feature, which makes `&BitSlice` safe to share across threads,
+`&BitSlice` also implements `IntoParallelIterator`, producing each bit as a
+`bool`, so that `rayon` adapter pipelines can run over a bit slice.
+!*/
+
+#![cfg(feature = "rayon")]
+
+use crate::{
+	cursor::Cursor,
+	domain::BitDomain,
+	slice::{
+		BitSlice,
+		live_mask,
+	},
+	store::{
+		BitAccess,
+		BitStore,
+	},
+};
+
+#[cfg(feature = "atomic")]
+use crate::slice::Iter;
+
+use rayon::prelude::*;
+
+#[cfg(feature = "atomic")]
+use rayon::iter::plumbing::{
+	self,
+	Consumer,
+	Folder,
+	Producer,
+	ProducerCallback,
+	UnindexedConsumer,
+	UnindexedProducer,
+};
+
+impl<C, T> BitSlice<C, T>
+where C: Cursor, T: BitStore {
+	/// Counts how many bits are set high, using the `rayon` thread pool.
+	///
+	/// The interior elements of the slice are counted in parallel. This has
+	/// the same result as [`count_ones`], and is only faster than it for large
+	/// slices.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	///
+	/// # Returns
+	///
+	/// The number of high bits in the slice domain.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let data = [0xFDu8; 1024];
+	/// let bits = &data.bits::<BigEndian>()[3 ..];
+	/// assert_eq!(bits.par_count_ones(), bits.count_ones());
+	/// ```
+	///
+	/// [`count_ones`]: #method.count_ones
+	pub fn par_count_ones(&self) -> usize {
+		let edge = |elt: &T::Nucleus, from: u8, upto: u8| {
+			(BitAccess::load(elt) & live_mask::<C, T>(from, upto)).count_ones()
+		};
+		let body = |body: &[T]| {
+			body.par_iter().map(T::count_ones).sum::<usize>()
+		};
+		match self.domain() {
+			BitDomain::Empty => 0,
+			BitDomain::Minor(head, elt, tail) => edge(elt, *head, *tail),
+			BitDomain::Major(h, head, elts, tail, t) => {
+				edge(head, *h, T::BITS) + body(elts) + edge(tail, 0, *t)
+			},
+			BitDomain::PartialHead(h, head, elts) => {
+				edge(head, *h, T::BITS) + body(elts)
+			},
+			BitDomain::PartialTail(elts, tail, t) => {
+				body(elts) + edge(tail, 0, *t)
+			},
+			BitDomain::Spanning(elts) => body(elts),
+		}
+	}
+}
+
+#[cfg(feature = "atomic")]
+impl<'a, C, T> IntoParallelIterator for &'a BitSlice<C, T>
+where C: Cursor, T: 'a + BitStore {
+	type Iter = ParIter<'a, C, T>;
+	type Item = bool;
+
+	fn into_par_iter(self) -> Self::Iter {
+		ParIter { slice: self }
+	}
+}
+
+/** Parallel iterator over the bits of a `BitSlice`.
+
+When `rayon` is free to choose how to divide the slice, it is only divided at
+element boundaries, so that no element is read by more than one task. Indexed
+adapters, such as `enumerate` and `zip`, may require division at arbitrary bit
+indices, which is also supported.
+
+# Type Parameters
+
+- `C`: The bit-order type of the underlying `BitSlice`.
+- `T`: The storage type of the underlying `BitSlice`.
+
+# Lifetimes
+
+- `'a`: The lifetime of the underlying `BitSlice`.
+
+# Examples
+
+```rust
+use bitvec::prelude::*;
+use rayon::prelude::*;
+
+let bv = bitvec![0, 1, 1, 0, 1];
+assert_eq!(bv.par_iter().filter(|&bit| bit).count(), 3);
+```
+**/
+#[cfg(feature = "atomic")]
+#[derive(Clone, Debug)]
+pub struct ParIter<'a, C, T>
+where C: Cursor, T: 'a + BitStore {
+	/// The slice being iterated.
+	slice: &'a BitSlice<C, T>,
+}
+
+#[cfg(feature = "atomic")]
+impl<'a, C, T> IndexedParallelIterator for ParIter<'a, C, T>
+where C: Cursor, T: 'a + BitStore {
+	fn len(&self) -> usize {
+		self.slice.len()
+	}
+
+	fn drive<D>(self, consumer: D) -> D::Result
+	where D: Consumer<Self::Item> {
+		plumbing::bridge(self, consumer)
+	}
+
+	fn with_producer<CB>(self, callback: CB) -> CB::Output
+	where CB: ProducerCallback<Self::Item> {
+		callback.callback(ParProducer { slice: self.slice })
+	}
+}
+
+#[cfg(feature = "atomic")]
+impl<'a, C, T> ParallelIterator for ParIter<'a, C, T>
+where C: Cursor, T: 'a + BitStore {
+	type Item = bool;
+
+	fn drive_unindexed<D>(self, consumer: D) -> D::Result
+	where D: UnindexedConsumer<Self::Item> {
+		plumbing::bridge_unindexed(ParProducer { slice: self.slice }, consumer)
+	}
+
+	fn opt_len(&self) -> Option<usize> {
+		Some(self.slice.len())
+	}
+}
+
+/// Divides a `BitSlice` into the pieces handed out to `rayon` tasks.
+#[cfg(feature = "atomic")]
+struct ParProducer<'a, C, T>
+where C: Cursor, T: 'a + BitStore {
+	/// The slice remaining to be divided.
+	slice: &'a BitSlice<C, T>,
+}
+
+#[cfg(feature = "atomic")]
+impl<'a, C, T> Producer for ParProducer<'a, C, T>
+where C: Cursor, T: 'a + BitStore {
+	type Item = bool;
+	type IntoIter = Iter<'a, C, T>;
+
+	fn into_iter(self) -> Self::IntoIter {
+		self.slice.iter()
+	}
+
+	fn split_at(self, index: usize) -> (Self, Self) {
+		let (left, right) = self.slice.split_at(index);
+		(Self { slice: left }, Self { slice: right })
+	}
+}
+
+#[cfg(feature = "atomic")]
+impl<'a, C, T> UnindexedProducer for ParProducer<'a, C, T>
+where C: Cursor, T: 'a + BitStore {
+	type Item = bool;
+
+	/// Divides the slice at the element boundary nearest below its midpoint.
+	///
+	/// Slices which do not cross an element boundary are not divided.
+	fn split(self) -> (Self, Option<Self>) {
+		let bits = T::BITS as usize;
+		let head = *self.slice.bitptr().head() as usize;
+		let mid = (head + self.slice.len() / 2) / bits * bits;
+		if mid <= head {
+			return (self, None);
+		}
+		let (left, right) = self.slice.split_at(mid - head);
+		(Self { slice: left }, Some(Self { slice: right }))
+	}
+
+	fn fold_with<F>(self, folder: F) -> F
+	where F: Folder<Self::Item> {
+		folder.consume_iter(self.slice.iter())
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	#[cfg(feature = "atomic")]
+	use super::*;
+
+	use crate::{
+		cursor::{
+			BigEndian,
+			LittleEndian,
+		},
+		vec::BitVec,
+	};
+
+	/// Produces a deterministic stream of pseudo-random bits.
+	fn noise(len: usize) -> impl Iterator<Item = bool> {
+		let mut state = 0x2545_F491_4F6C_DD1Du64;
+		(0 .. len).map(move |_| {
+			state ^= state << 13;
+			state ^= state >> 7;
+			state ^= state << 17;
+			state & 1 != 0
+		})
+	}
+
+	#[test]
+	fn par_count_ones() {
+		let bv = noise(1 << 20).collect::<BitVec<BigEndian, u32>>();
+		let ones = bv.iter().filter(|&bit| bit).count();
+		assert_eq!(bv.par_count_ones(), ones);
+
+		for &(start, end) in &[(0, 0), (3, 20), (5, 1000), (0, 999), (64, 4096)] {
+			let bits = &bv[start .. end];
+			assert_eq!(bits.par_count_ones(), bits.count_ones());
+		}
+
+		let bv = noise(12_345).collect::<BitVec<LittleEndian, u8>>();
+		assert_eq!(bv[7 ..].par_count_ones(), bv[7 ..].count_ones());
+	}
+
+	#[test]
+	#[cfg(feature = "atomic")]
+	fn par_iter() {
+		let bv = noise(100_000).collect::<BitVec<LittleEndian, u16>>();
+		let bits = &bv[5 .. 99_990];
+		let ones = bits.count_ones();
+		let sum = bits.par_iter()
+			.fold(|| 0, |accum, bit| accum + bit as usize)
+			.sum::<usize>();
+		assert_eq!(sum, ones);
+		let zeros = bits.par_iter().filter(|&bit| !bit).count();
+		assert_eq!(zeros, bits.count_zeros());
+
+		let idx = bits.par_iter()
+			.enumerate()
+			.filter(|&(_, bit)| bit)
+			.map(|(idx, _)| idx)
+			.collect::<Vec<_>>();
+		assert_eq!(idx.len(), ones);
+		assert!(idx.iter().all(|&n| bits[n]));
+	}
+
+	#[test]
+	#[cfg(feature = "atomic")]
+	fn par_split() {
+		let data = [0u8; 4];
+		let bits = &BitSlice::<BigEndian, u8>::from_slice(&data)[3 .. 29];
+
+		let (left, right) = ParProducer { slice: bits }.split();
+		let right = right.unwrap();
+		assert_eq!(left.slice.len(), 13);
+		assert_eq!(*right.slice.bitptr().head(), 0);
+
+		let (left, right) = ParProducer { slice: &bits[.. 5] }.split();
+		assert_eq!(left.slice.len(), 5);
+		assert!(right.is_none());
+
+		let (left, right) = ParProducer { slice: &bits[10 ..] }.split();
+		assert_eq!(left.slice.len(), 3);
+		assert_eq!(right.unwrap().slice.len(), 13);
+	}
+}
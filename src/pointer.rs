@@ -348,7 +348,9 @@ where T: BitStore {
 	pub(crate) fn empty() -> Self {
 		Self {
 			_ty: PhantomData,
-			ptr: NonNull::dangling(),
+			//  `NonNull::<u8>::dangling()` is odd, which would be read back as
+			//  a nonzero head index for any wider `T`.
+			ptr: NonNull::<T>::dangling().cast::<u8>(),
 			len: 0,
 		}
 	}
@@ -387,7 +389,7 @@ where T: BitStore {
 		Self {
 			_ty: PhantomData,
 			ptr: NonNull::new(ptr.w() as *mut u8)
-				.unwrap_or_else(NonNull::dangling),
+				.unwrap_or_else(|| NonNull::<T>::dangling().cast::<u8>()),
 			len: 0,
 		}
 	}
@@ -954,7 +956,7 @@ where T: BitStore {
 		let src = unsafe { &*(bs as *const BitSlice<C, T> as *const [()]) };
 		let ptr = Pointer::from(src.as_ptr() as *const u8);
 		let (ptr, len) = match (ptr.w(), src.len()) {
-			(_, 0) => (NonNull::dangling(), 0),
+			(_, 0) => (NonNull::<T>::dangling().cast::<u8>(), 0),
 			(p, _) if p.is_null() => unreachable!("Rust forbids null refs"),
 			(p, l) => (unsafe { NonNull::new_unchecked(p) }, l),
 		};
@@ -1147,6 +1149,17 @@ mod tests {
 		assert_ne!(bp.ptr, NonNull::<u8>::dangling());
 		assert_eq!(bp.len, 0);
 		assert!(bp.is_empty());
+
+		let bp = BitPtr::<u32>::uninhabited(core::ptr::null::<u32>());
+		assert_eq!(*bp.head(), 0);
+	}
+
+	#[test]
+	fn dangling_head() {
+		//  The dangling address of a wide `T` must not leak into the head bits.
+		assert_eq!(*BitPtr::<u16>::empty().head(), 0);
+		assert_eq!(*BitPtr::<u32>::empty().head(), 0);
+		assert_eq!(*BitPtr::<u64>::empty().head(), 0);
 	}
 
 	#[test]
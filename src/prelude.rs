@@ -5,6 +5,7 @@ This collects the general public API into a single spot for inclusion, as
 !*/
 
 pub use crate::{
+	bitarr,
 	bits::{
 		Bits,
 		BitsMut,
@@ -14,6 +15,8 @@ pub use crate::{
 		BigEndian,
 		LittleEndian,
 		Local,
+		Lsb0,
+		Msb0,
 	},
 	slice::BitSlice,
 	store::{
@@ -2,6 +2,19 @@
 
 This module implements the Serde traits for the `bitvec` types, as possible.
 
+The types use one of two representations, chosen by whether the de/serializer
+is human-readable:
+
+- Human-readable formats, such as JSON, receive a string of `'0'` and `'1'`
+  characters, one for each bit in the slice. They may also provide a structure
+  of the slice’s `head` index, its `bits` count, and the `data` elements it
+  spans, which is how earlier versions of the crate serialized to them.
+- Compact formats, such as `bincode`, receive a tuple of the bit count as a
+  `u64`, followed by a tuple of the minimal number of bytes needed to hold the
+  bits. The bits are packed into each byte in the order of the `Cursor`, and the
+  unused bits of the final byte are zeroed. The storage type does not appear in
+  this representation.
+
 Without an allocator, only `BitSlice` exists, and can only implement
 `Serialize`. With an allocator, the `BitBox` and `BitVec` types exist, and are
 able to implement `Deserialize` as well.
@@ -18,6 +31,7 @@ use crate::{
 #[cfg(feature = "alloc")]
 use crate::{
 	boxed::BitBox,
+	indices::BitIdx,
 	pointer::BitPtr,
 	vec::BitVec,
 };
@@ -26,19 +40,21 @@ use crate::{
 use core::{
 	cmp,
 	convert::TryInto,
-	fmt::{
-		self,
-		Formatter,
-	},
 	marker::PhantomData,
-	mem,
+};
+
+use core::fmt::{
+	self,
+	Display,
+	Formatter,
+	Write,
 };
 
 use serde::{
 	Serialize,
 	ser::{
 		Serializer,
-		SerializeStruct,
+		SerializeTuple,
 	},
 };
 
@@ -47,6 +63,7 @@ use serde::{
 	Deserialize,
 	de::{
 		self,
+		DeserializeSeed,
 		Deserializer,
 		MapAccess,
 		SeqAccess,
@@ -70,6 +87,23 @@ where C: Cursor, T: BitStore + Deserialize<'de> {
 	fn new() -> Self {
 		BitBoxVisitor { _cursor: PhantomData, _storage: PhantomData }
 	}
+
+	/// Copies the described region of `data` into a new `BitBox`.
+	///
+	/// The region is clamped to the end of `data`, so a `bits` count that is
+	/// too large for the received elements cannot reach past them.
+	fn assemble<E>(head: u8, bits: usize, data: &[T]) -> Result<BitBox<C, T>, E>
+	where E: de::Error {
+		let head: BitIdx<T> = head.try_into()
+			.map_err(|_| de::Error::invalid_value(
+				Unexpected::Unsigned(head as u64),
+				&"a head index that is less than the bit width of the slice element type",
+			))?;
+		let all = BitSlice::<C, T>::from_slice(data);
+		let start = cmp::min(*head as usize, all.len());
+		let end = cmp::min(start.saturating_add(bits), all.len());
+		Ok(all[start .. end].to_owned().into_boxed_bitslice())
+	}
 }
 
 #[cfg(feature = "alloc")]
@@ -78,7 +112,24 @@ where C: Cursor, T: BitStore + Deserialize<'de> {
 	type Value = BitBox<C, T>;
 
 	fn expecting(&self, fmt: &mut Formatter) -> fmt::Result {
-		fmt.write_str("A BitSet data series")
+		fmt.write_str("A BitSet data series, or a string of 0 and 1 characters")
+	}
+
+	/// Visit a string of `'0'` and `'1'` characters, each of which is one bit.
+	fn visit_str<E>(self, text: &str) -> Result<Self::Value, E>
+	where E: de::Error {
+		let mut out = BitVec::<C, T>::with_capacity(text.len());
+		for ch in text.chars() {
+			match ch {
+				'0' => out.push(false),
+				'1' => out.push(true),
+				_ => return Err(de::Error::invalid_value(
+					Unexpected::Char(ch),
+					&"a 0 or 1 character",
+				)),
+			}
+		}
+		Ok(out.into_boxed_bitslice())
 	}
 
 	/// Visit a sequence of anonymous data elements. These must be in the order
@@ -92,17 +143,7 @@ where C: Cursor, T: BitStore + Deserialize<'de> {
 		let data: Box<[T]> = seq.next_element()?
 			.ok_or_else(|| de::Error::invalid_length(2, &self))?;
 
-		let bitptr = BitPtr::new(
-			data.as_ptr(),
-			head.try_into()
-				.map_err(|_| de::Error::invalid_value(
-					Unexpected::Unsigned(head as u64),
-					&"a head index that is less than the bit width of the slice element type",
-				))?,
-			bits,
-		);
-		mem::forget(data);
-		Ok(unsafe { BitBox::from_raw(bitptr) })
+		Self::assemble(head, bits, &data)
 	}
 
 	/// Visit a map of named data elements. These may be in any order, and must
@@ -133,31 +174,174 @@ where C: Cursor, T: BitStore + Deserialize<'de> {
 		let bits = bits.ok_or_else(|| de::Error::missing_field("bits"))?;
 		let data = data.ok_or_else(|| de::Error::missing_field("data"))?;
 
-		let bitptr = BitPtr::new(
-			data.as_ptr(),
-			head.try_into()
-				.map_err(|_| de::Error::invalid_value(
-					Unexpected::Unsigned(head as u64),
-					&"a head index that is less than the bit width of the slice element type",
-				))?,
-			cmp::min(bits, data.len() * T::BITS as usize),
-		);
-		mem::forget(data);
-		Ok(unsafe { BitBox::from_raw(bitptr) })
+		Self::assemble(head, bits, &data)
 	}
 }
 
+/// A Serde visitor to pull the compact representation out of a serialized
+/// stream.
+#[cfg(feature = "alloc")]
+#[derive(Clone, Copy, Default, Debug)]
+struct CompactVisitor<C, T>
+where C: Cursor, T: BitStore {
+	_cursor: PhantomData<C>,
+	_storage: PhantomData<T>,
+}
+
+#[cfg(feature = "alloc")]
+impl<C, T> CompactVisitor<C, T>
+where C: Cursor, T: BitStore {
+	fn new() -> Self {
+		CompactVisitor { _cursor: PhantomData, _storage: PhantomData }
+	}
+}
+
+#[cfg(feature = "alloc")]
+impl<'de, C, T> Visitor<'de> for CompactVisitor<C, T>
+where C: Cursor, T: BitStore {
+	type Value = BitVec<C, T>;
+
+	fn expecting(&self, fmt: &mut Formatter) -> fmt::Result {
+		fmt.write_str("A bit count followed by packed bytes")
+	}
+
+	/// Visit the bit count, then hand the byte tuple to a `PackedSeed` which
+	/// knows how many bytes to expect.
+	fn visit_seq<V>(self, mut seq: V) -> Result<Self::Value, V::Error>
+	where V: SeqAccess<'de> {
+		let bits: u64 = seq.next_element()?
+			.ok_or_else(|| de::Error::invalid_length(0, &self))?;
+		if bits > BitPtr::<T>::MAX_INDX as u64 {
+			return Err(de::Error::invalid_value(
+				Unexpected::Unsigned(bits),
+				&"a bit count that a BitVec can hold",
+			));
+		}
+		seq.next_element_seed(PackedSeed::new(bits as usize))?
+			.ok_or_else(|| de::Error::invalid_length(1, &self))
+	}
+}
+
+/// The most bits that a `PackedSeed` will reserve before reading any bytes.
+#[cfg(feature = "alloc")]
+const MAX_PREALLOC: usize = 4096 * 8;
+
+/// Reads a known number of bits out of a tuple of packed bytes.
+#[cfg(feature = "alloc")]
+#[derive(Clone, Copy, Default, Debug)]
+struct PackedSeed<C, T>
+where C: Cursor, T: BitStore {
+	/// The number of live bits in the byte tuple.
+	bits: usize,
+	_cursor: PhantomData<C>,
+	_storage: PhantomData<T>,
+}
+
+#[cfg(feature = "alloc")]
+impl<C, T> PackedSeed<C, T>
+where C: Cursor, T: BitStore {
+	fn new(bits: usize) -> Self {
+		PackedSeed { bits, _cursor: PhantomData, _storage: PhantomData }
+	}
+}
+
+#[cfg(feature = "alloc")]
+impl<'de, C, T> DeserializeSeed<'de> for PackedSeed<C, T>
+where C: Cursor, T: BitStore {
+	type Value = BitVec<C, T>;
+
+	fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+	where D: Deserializer<'de> {
+		deserializer.deserialize_tuple(packed_len(self.bits), self)
+	}
+}
+
+#[cfg(feature = "alloc")]
+impl<'de, C, T> Visitor<'de> for PackedSeed<C, T>
+where C: Cursor, T: BitStore {
+	type Value = BitVec<C, T>;
+
+	fn expecting(&self, fmt: &mut Formatter) -> fmt::Result {
+		write!(fmt, "{} bytes of packed bits", packed_len(self.bits))
+	}
+
+	/// Visit each byte in turn, keeping only the live bits of the last.
+	fn visit_seq<V>(self, mut seq: V) -> Result<Self::Value, V::Error>
+	where V: SeqAccess<'de> {
+		//  The bit count is untrusted, so only a bounded amount of memory is
+		//  reserved ahead of the bytes that actually arrive.
+		let mut out = BitVec::with_capacity(cmp::min(self.bits, MAX_PREALLOC));
+		let mut rest = self.bits;
+		for idx in 0 .. packed_len(self.bits) {
+			let byte: u8 = seq.next_element()?
+				.ok_or_else(|| de::Error::invalid_length(idx, &self))?;
+			let live = cmp::min(rest, 8);
+			out.extend(BitSlice::<C, u8>::from_element(&byte)[.. live].iter());
+			rest -= live;
+		}
+		Ok(out)
+	}
+}
+
+/// Serializes the bits of a slice as a tuple of bytes, with no length prefix.
+///
+/// The bits are packed into each byte in the order of the cursor `C`, and the
+/// dead bits of the final byte are zeroed.
+struct Packed<'a, C, T>(&'a BitSlice<C, T>)
+where C: Cursor, T: 'a + BitStore;
+
+impl<'a, C, T> Serialize for Packed<'a, C, T>
+where C: Cursor, T: 'a + BitStore {
+	fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+	where S: Serializer {
+		let mut state = serializer.serialize_tuple(packed_len(self.0.len()))?;
+		for chunk in self.0.chunks(8) {
+			let mut byte = 0u8;
+			{
+				let bits = BitSlice::<C, u8>::from_element_mut(&mut byte);
+				for (idx, bit) in chunk.iter().enumerate() {
+					bits.set(idx, bit);
+				}
+			}
+			state.serialize_element(&byte)?;
+		}
+		state.end()
+	}
+}
+
+/// Displays the bits of a slice as `'0'` and `'1'` characters, with no
+/// separators.
+struct BitString<'a, C, T>(&'a BitSlice<C, T>)
+where C: Cursor, T: 'a + BitStore;
+
+impl<'a, C, T> Display for BitString<'a, C, T>
+where C: Cursor, T: 'a + BitStore {
+	fn fmt(&self, fmt: &mut Formatter) -> fmt::Result {
+		for bit in self.0 {
+			fmt.write_char(if bit { '1' } else { '0' })?;
+		}
+		Ok(())
+	}
+}
+
+/// Counts the bytes needed to hold some number of bits.
+fn packed_len(bits: usize) -> usize {
+	bits / 8 + (bits % 8 != 0) as usize
+}
+
 #[cfg(feature = "alloc")]
 impl<'de, C, T> Deserialize<'de> for BitBox<C, T>
 where C: Cursor, T: 'de + BitStore + Deserialize<'de> {
 	fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
 	where D: Deserializer<'de> {
-		deserializer
-			.deserialize_struct(
-				"BitSet",
-				&["head", "bits", "data"],
-				BitBoxVisitor::new(),
-			)
+		if deserializer.is_human_readable() {
+			deserializer.deserialize_any(BitBoxVisitor::new())
+		}
+		else {
+			deserializer
+				.deserialize_tuple(2, CompactVisitor::new())
+				.map(BitVec::into_boxed_bitslice)
+		}
 	}
 }
 
@@ -174,14 +358,17 @@ impl<C, T> Serialize for BitSlice<C, T>
 where C: Cursor, T: BitStore + Serialize {
 	fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
 	where S: Serializer {
-		let head = self.bitptr().head();
-		let mut state = serializer.serialize_struct("BitSet", 3)?;
+		if serializer.is_human_readable() {
+			serializer.collect_str(&BitString(self))
+		}
+		else {
+			let mut state = serializer.serialize_tuple(2)?;
 
-		state.serialize_field("head", &*head)?;
-		state.serialize_field("bits", &(self.len() as u64))?;
-		state.serialize_field("data", self.bitptr().as_slice())?;
+			state.serialize_element(&(self.len() as u64))?;
+			state.serialize_element(&Packed(self))?;
 
-		state.end()
+			state.end()
+		}
 	}
 }
 
@@ -206,25 +393,22 @@ where C: Cursor, T: BitStore + Serialize {
 #[cfg(test)]
 mod tests {
 	use crate::prelude::*;
+	use serde::Serialize;
 	use serde_test::{
+		Configure,
 		Token,
 		assert_ser_tokens,
 	};
 	#[cfg(feature = "alloc")]
-	use serde_test::assert_de_tokens;
+	use serde_test::{
+		Compact,
+		Readable,
+		assert_de_tokens,
+		assert_de_tokens_error,
+		assert_tokens,
+	};
 
 	macro_rules! bvtok {
-		( s $elts:expr, $head:expr, $bits:expr, $ty:ident $( , $data:expr )* ) => {
-			&[
-				Token::Struct { name: "BitSet", len: 3, },
-				Token::Str("head"), Token::U8( $head ),
-				Token::Str("bits"), Token::U64( $bits ),
-				Token::Str("data"), Token::Seq { len: Some( $elts ) },
-				$( Token:: $ty ( $data ), )*
-				Token::SeqEnd,
-				Token::StructEnd,
-			]
-		};
 		( d $elts:expr, $head:expr, $bits:expr, $ty:ident $( , $data:expr )* ) => {
 			&[
 				Token::Struct { name: "BitSet", len: 3, },
@@ -242,10 +426,13 @@ mod tests {
 	fn empty() {
 		let slice = BitSlice::<BigEndian, u8>::empty();
 
-		assert_ser_tokens(&slice, bvtok![s 0, 0, 0, U8]);
+		assert_ser_tokens(&slice.readable(), &[Token::Str("")]);
 
-		#[cfg(feature = "alloc")]
-		assert_de_tokens(&bitvec![], bvtok![ d 0, 0, 0, U8 ]);
+		#[cfg(feature = "alloc")] {
+		let bv = BitVec::<BigEndian, u8>::new().readable();
+		assert_de_tokens(&bv, bvtok![ d 0, 0, 0, U8 ]);
+		assert_de_tokens(&bv, &[Token::Str("")]);
+		}
 	}
 
 	#[cfg(feature = "alloc")]
@@ -253,20 +440,23 @@ mod tests {
 	fn small() {
 		let bv = bitvec![BigEndian, u8; 1; 5];
 		let bs = &bv[1 ..];
-		assert_ser_tokens(&bs, bvtok![s 1, 1, 4, U8, 0b1111_1000]);
+		assert_ser_tokens(&bs.readable(), &[Token::Str("1111")]);
 
 		let bv = bitvec![LittleEndian, u16; 1; 12];
-		assert_ser_tokens(&bv, bvtok![s 1, 0, 12, U16, 0b00001111_11111111]);
+		assert_ser_tokens(&bv.readable(), &[Token::Str("111111111111")]);
 
 		let bb: BitBox<_, _> = bitbox![LittleEndian, u32; 1; 10];
-		assert_ser_tokens(&bb, bvtok![s 1, 0, 10, U32, 0x00_00_03_FF]);
+		assert_ser_tokens(&bb.readable(), &[Token::Str("1111111111")]);
 	}
 
 	#[cfg(feature = "alloc")]
 	#[test]
 	fn wide() {
 		let bs = [0u8, !0].bits::<BigEndian>();
-		assert_ser_tokens(&(&bs[1 .. 15]), bvtok![s 2, 1, 14, U8, 0, !0]);
+		assert_ser_tokens(
+			&(&bs[1 .. 15]).readable(),
+			&[Token::Str("00000001111111")],
+		);
 	}
 
 	#[cfg(feature = "alloc")]
@@ -274,10 +464,130 @@ mod tests {
 	#[cfg(feature = "alloc")]
 	fn deser() {
 		let bv = bitvec![BigEndian, u8; 0, 1, 1, 0, 1, 0];
+		let bv = bv.readable();
 		assert_de_tokens(&bv, bvtok![d 1, 0, 6, U8, 0b0110_1000]);
 		//  test that the bits outside the bits domain don't matter in deser
 		assert_de_tokens(&bv, bvtok![d 1, 0, 6, U8, 0b0110_1001]);
 		assert_de_tokens(&bv, bvtok![d 1, 0, 6, U8, 0b0110_1010]);
 		assert_de_tokens(&bv, bvtok![d 1, 0, 6, U8, 0b0110_1011]);
 	}
+
+	#[cfg(feature = "alloc")]
+	#[test]
+	fn boxed() {
+		let bb = bitbox![BigEndian, u16; 0, 1, 1, 0, 1];
+		assert_tokens(&bb.clone().readable(), &[Token::Str("01101")]);
+		assert_tokens(&bb.compact(), &[
+			Token::Tuple { len: 2 },
+			Token::U64(5),
+			Token::Tuple { len: 1 },
+			Token::U8(0b0110_1000),
+			Token::TupleEnd,
+			Token::TupleEnd,
+		]);
+
+		//  A bit count that runs past the data is clamped to the data.
+		assert_de_tokens(
+			&bitbox![BigEndian, u8; 1, 1, 0, 1, 0, 0, 0].readable(),
+			bvtok![d 1, 1, 20, U8, 0b0110_1000],
+		);
+	}
+
+	#[test]
+	fn borrowed_field() {
+		#[derive(Serialize)]
+		struct Holder<'a> {
+			bits: &'a BitSlice<BigEndian, u8>,
+		}
+
+		let data = [0b1011_0000u8];
+		let holder = Holder { bits: &data.bits::<BigEndian>()[.. 4] };
+		assert_ser_tokens(&holder.readable(), &[
+			Token::Struct { name: "Holder", len: 1 },
+			Token::Str("bits"),
+			Token::Str("1011"),
+			Token::StructEnd,
+		]);
+		assert_ser_tokens(&holder.compact(), &[
+			Token::Struct { name: "Holder", len: 1 },
+			Token::Str("bits"),
+			Token::Tuple { len: 2 },
+			Token::U64(4),
+			Token::Tuple { len: 1 },
+			Token::U8(0b1011_0000),
+			Token::TupleEnd,
+			Token::TupleEnd,
+			Token::StructEnd,
+		]);
+	}
+
+	#[cfg(feature = "alloc")]
+	#[test]
+	fn string() {
+		let bv = bitvec![1, 0, 1].readable();
+		assert_tokens(&bv, &[Token::Str("101")]);
+		assert_de_tokens(&bv, &[Token::String("101")]);
+
+		assert_de_tokens_error::<Readable<BitVec>>(
+			&[Token::Str("102")],
+			"invalid value: character `2`, expected a 0 or 1 character",
+		);
+	}
+
+	#[cfg(feature = "alloc")]
+	#[test]
+	fn compact() {
+		let bv = bitvec![BigEndian, u8;
+			1, 0, 1, 1, 0, 0, 1, 0,
+			1, 1, 0, 1, 1,
+		];
+		assert_tokens(&bv.compact(), &[
+			Token::Tuple { len: 2 },
+			Token::U64(13),
+			Token::Tuple { len: 2 },
+			Token::U8(0b1011_0010),
+			Token::U8(0b1101_1000),
+			Token::TupleEnd,
+			Token::TupleEnd,
+		]);
+
+		//  The storage type does not matter, and dead bits are zeroed.
+		let bv = bitvec![LittleEndian, u16; 1; 16];
+		assert_ser_tokens(&(&bv[3 ..]).compact(), &[
+			Token::Tuple { len: 2 },
+			Token::U64(13),
+			Token::Tuple { len: 2 },
+			Token::U8(0xFF),
+			Token::U8(0b0001_1111),
+			Token::TupleEnd,
+			Token::TupleEnd,
+		]);
+		//  and are ignored during deserialization.
+		assert_de_tokens(&bitvec![LittleEndian, u16; 1; 13].compact(), &[
+			Token::Tuple { len: 2 },
+			Token::U64(13),
+			Token::Tuple { len: 2 },
+			Token::U8(0xFF),
+			Token::U8(0xFF),
+			Token::TupleEnd,
+			Token::TupleEnd,
+		]);
+	}
+
+	#[cfg(feature = "alloc")]
+	#[test]
+	fn compact_short() {
+		//  A huge declared length fails on the missing bytes, rather than on
+		//  allocating storage for all of them up front.
+		assert_de_tokens_error::<Compact<BitVec<BigEndian, u8>>>(
+			&[
+				Token::Tuple { len: 2 },
+				Token::U64(1 << 40),
+				Token::Tuple { len: 1 },
+				Token::U8(0xFF),
+				Token::TupleEnd,
+			],
+			"invalid length 1, expected 137438953472 bytes of packed bits",
+		);
+	}
 }
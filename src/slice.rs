@@ -32,21 +32,31 @@ use {
 
 use core::{
 	cmp,
+	convert::TryFrom,
 	fmt::{
 		self,
+		Alignment,
+		Binary,
 		Debug,
 		DebugList,
 		Display,
 		Formatter,
+		LowerHex,
+		Octal,
+		UpperHex,
 	},
 	hash::{
 		Hash,
 		Hasher,
 	},
 	marker::PhantomData,
+	mem,
 	ops::{
+		Bound,
 		Deref,
 		DerefMut,
+		Range,
+		RangeBounds,
 	},
 	ptr,
 	str,
@@ -214,6 +224,9 @@ where C: Cursor, T: BitStore {
 	///
 	/// [`Bits::bits`]: ../bits/trait.Bits.html#tymethod.bits
 	pub fn from_element(elt: &T) -> &Self {
+		if cfg!(debug_assertions) {
+			C::verify::<T>();
+		}
 		unsafe {
 			BitPtr::new_unchecked(elt, 0.idx(), T::BITS as usize)
 		}.into_bitslice()
@@ -251,6 +264,9 @@ where C: Cursor, T: BitStore {
 	///
 	/// [`BitsMut::bits_mut`]: ../bits/trait.BitsMut.html#tymethod.bits_mut
 	pub fn from_element_mut(elt: &mut T) -> &mut Self {
+		if cfg!(debug_assertions) {
+			C::verify::<T>();
+		}
 		unsafe {
 			BitPtr::new_unchecked(elt, 0.idx(), T::BITS as usize)
 		}.into_bitslice_mut()
@@ -299,6 +315,9 @@ where C: Cursor, T: BitStore {
 	/// [`BitPtr`]: ../pointer/struct.BitPtr.html
 	/// [`Bits::bits`]: ../bits/trait.Bits.html#tymethod.bits
 	pub fn from_slice(slice: &[T]) -> &Self {
+		if cfg!(debug_assertions) {
+			C::verify::<T>();
+		}
 		let len = slice.len();
 		assert!(
 			len <= BitPtr::<T>::MAX_ELTS,
@@ -659,6 +678,49 @@ where C: Cursor, T: BitStore {
 		}
 	}
 
+	/// Gets a write reference to the bit at the given position.
+	///
+	/// This is the checked counterpart to [`at`]: it produces the same
+	/// [`BitGuard`] proxy, which reads as the current bit value and commits
+	/// any assignment to it back into the slice when it is dropped.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	/// - `index`: The bit index to borrow.
+	///
+	/// # Returns
+	///
+	/// A write guard over the bit at the specified index, if any. If `index` is
+	/// beyond the bounds of `self`, then `None` is produced.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let mut src = 0u8;
+	/// let bits = src.bits_mut::<LittleEndian>();
+	///
+	/// if let Some(mut bit) = bits.get_mut(3) {
+	///   assert!(!*bit);
+	///   *bit = true;
+	/// }
+	/// assert!(bits.get_mut(8).is_none());
+	/// assert_eq!(src, 0b0000_1000);
+	/// ```
+	///
+	/// [`at`]: #method.at
+	/// [`BitGuard`]: struct.BitGuard.html
+	pub fn get_mut(&mut self, index: usize) -> Option<BitGuard<C, T>> {
+		if index >= self.len() {
+			None
+		}
+		else {
+			Some(self.at(index))
+		}
+	}
+
 	/// Looks up a bit at an index, without doing bounds checking.
 	///
 	/// This is generally not recommended; use with caution! For a safe
@@ -737,427 +799,1416 @@ where C: Cursor, T: BitStore {
 		unsafe { self.set_unchecked(index, value) };
 	}
 
-	/// Sets a bit at an index, without doing bounds checking.
+	/// Sets the bit value at the given position, and returns the value it
+	/// replaced.
 	///
-	/// This is generally not recommended; use with caution! For a safe
-	/// alternative, see [`set`].
+	/// This is the single-bit equivalent of `core::mem::replace`, and saves a
+	/// separate call to `get` in read-modify-write patterns.
 	///
 	/// # Parameters
 	///
 	/// - `&mut self`
-	/// - `index`: The bit index to retrieve. This index is *not* checked
-	///   against the length of `self`.
-	///
-	/// # Effects
+	/// - `index`: The bit index to set. It must be in the domain
+	///   `0 .. self.len()`.
+	/// - `value`: The value to be set, `true` for `1` and `false` for `0`.
 	///
-	/// The bit at `index` is set to `value`.
+	/// # Returns
 	///
-	/// # Safety
+	/// The value of the bit at `index` before it was set.
 	///
-	/// This method is **not** safe. It performs raw pointer arithmetic to seek
-	/// from the start of the slice to the requested index, and set the bit
-	/// there. It does not inspect the length of `self`, and it is free to
-	/// perform out-of-bounds memory *write* access.
+	/// # Panics
 	///
-	/// Use this method **only** when you have already performed the bounds
-	/// check, and can guarantee that the call occurs with a safely in-bounds
-	/// index.
+	/// This method panics if `index` is outside the slice domain.
 	///
 	/// # Examples
 	///
-	/// This example uses a bit slice of length 2, and demonstrates
-	/// out-of-bounds access to the last bit in the element.
-	///
 	/// ```rust
 	/// use bitvec::prelude::*;
 	///
-	/// let mut src = 0u8;
-	/// {
-	///  let bits = &mut src.bits_mut::<BigEndian>()[2 .. 4];
-	///  assert_eq!(bits.len(), 2);
-	///  unsafe { bits.set_unchecked(5, true); }
-	/// }
-	/// assert_eq!(src, 1);
+	/// let mut store = 8u8;
+	/// let bits = store.bits_mut::<BigEndian>();
+	/// assert!(bits.replace(4, false));
+	/// assert!(!bits.replace(4, true));
+	/// assert!(bits[4]);
 	/// ```
-	///
-	/// [`set`]: #method.set
-	pub unsafe fn set_unchecked(&mut self, index: usize, value: bool) {
-		let bitptr = self.bitptr();
-		let (elt, bit) = bitptr.head().offset(index as isize);
-		let data_ptr = bitptr.pointer().n();
-		(&*(data_ptr.offset(elt))).set::<C>(bit, value);
+	pub fn replace(&mut self, index: usize, value: bool) -> bool {
+		let len = self.len();
+		assert!(index < len, "Index out of range: {} >= {}", index, len);
+		unsafe {
+			let out = self.get_unchecked(index);
+			self.set_unchecked(index, value);
+			out
+		}
 	}
 
-	/// Produces a write reference to a single bit in the slice.
+	/// Inverts the bit at the given position.
 	///
-	/// The structure returned by this method extends the borrow until it drops,
-	/// which precludes parallel use.
+	/// # Parameters
 	///
-	/// The [`split_at_mut`] method allows splitting the borrows of a slice, and
-	/// will enable safe parallel use of these write references. The `atomic`
-	/// feature guarantees that parallel use does not cause data races when
-	/// modifying the underlying slice.
+	/// - `&mut self`
+	/// - `index`: The bit index to invert. It must be in the domain
+	///   `0 .. self.len()`.
 	///
-	/// # Lifetimes
+	/// # Panics
 	///
-	/// - `'a` Propagates the lifetime of the referent slice to the single-bit
-	///   reference produced.
+	/// This method panics if `index` is outside the slice domain.
 	///
-	/// # Parameters
+	/// # Examples
 	///
-	/// - `&mut self`: The slice handle will be locked until the structure
-	///   produced by this method goes out of scope. See the Usage section.
-	/// - `index`: The index of the bit in `self` selected.
+	/// ```rust
+	/// use bitvec::prelude::*;
 	///
-	/// # Returns
+	/// let mut store = 8u8;
+	/// let bits = store.bits_mut::<BigEndian>();
+	/// bits.toggle(4);
+	/// bits.toggle(7);
+	/// assert_eq!(store, 0x01);
+	/// ```
+	pub fn toggle(&mut self, index: usize) {
+		let len = self.len();
+		assert!(index < len, "Index out of range: {} >= {}", index, len);
+		let bitptr = self.bitptr();
+		let (elt, bit) = bitptr.head().offset(index as isize);
+		let data_ptr = bitptr.pointer().n();
+		unsafe { (&*(data_ptr.offset(elt))).invert_bit::<C>(bit); }
+	}
+
+	/// Inverts every bit in the slice.
 	///
-	/// A write reference to the requested bit. Due to Rust limitations, this is
-	/// not a native reference type, but is a custom structure that holds the
-	/// address of the requested bit and its value. The produced structure
-	/// implements `Deref` and `DerefMut` to its cached bit, and commits the
-	/// cached bit to the parent slice on drop.
+	/// Elements wholly owned by the slice are inverted in one step. Elements
+	/// shared with other slices at the edges are inverted through a mask, so
+	/// that bits outside the slice are never disturbed.
 	///
-	/// # Usage
+	/// This is the in-place counterpart of the `Not` operator on `BitVec` and
+	/// `BitBox`, which consume their operand.
 	///
-	/// You must use the dereference operator on the `.at()` expression in order
-	/// to assign to it. In general, you should prefer immediately using and
-	/// discarding the returned value, rather than binding it to a name and
-	/// letting it live for more than one statement.
+	/// # Parameters
+	///
+	/// - `&mut self`
 	///
 	/// # Examples
 	///
 	/// ```rust
 	/// use bitvec::prelude::*;
 	///
-	/// let mut src = 0u8;
-	/// let bits = src.bits_mut::<BigEndian>();
-	///
-	/// assert!(!bits[0]);
-	/// *bits.at(0) = true;
-	/// //  note the leading dereference.
-	/// assert!(bits[0]);
+	/// let mut src = [0u8, 0xFF];
+	/// src.bits_mut::<BigEndian>()[4 .. 12].flip_all();
+	/// assert_eq!(src, [0x0F, 0x0F]);
 	/// ```
+	pub fn flip_all(&mut self) {
+		match self.bitptr().domain_mut() {
+			BitDomainMut::Empty => {},
+			BitDomainMut::Minor(head, elt, tail) => {
+				elt.invert_bits(live_mask::<C, T>(*head, *tail));
+			},
+			BitDomainMut::Major(h, head, body, tail, t) => {
+				head.invert_bits(live_mask::<C, T>(*h, T::BITS));
+				for elt in body {
+					*elt = !*elt;
+				}
+				tail.invert_bits(live_mask::<C, T>(0, *t));
+			},
+			BitDomainMut::PartialHead(h, head, body) => {
+				head.invert_bits(live_mask::<C, T>(*h, T::BITS));
+				for elt in body {
+					*elt = !*elt;
+				}
+			},
+			BitDomainMut::PartialTail(body, tail, t) => {
+				for elt in body {
+					*elt = !*elt;
+				}
+				tail.invert_bits(live_mask::<C, T>(0, *t));
+			},
+			BitDomainMut::Spanning(body) => {
+				for elt in body {
+					*elt = !*elt;
+				}
+			},
+		}
+	}
+
+	/// `AND`s another slice into this one.
 	///
-	/// This example shows multiple usage by using `split_at_mut`.
-	///
-	/// ```rust
-	/// use bitvec::prelude::*;
+	/// This produces the same result as `*self &= other`, which clears any
+	/// bits of `self` past the end of `other`. When the two slices have the
+	/// same length and begin at the same bit of their first elements, it
+	/// works one element at a time rather than one bit at a time.
 	///
-	/// let mut src = 0u8;
-	/// let bits = src.bits_mut::<BigEndian>();
+	/// # Parameters
 	///
-	/// {
-	///  let (mut a, rest) = bits.split_at_mut(2);
-	///  let (mut b, rest) = rest.split_at_mut(3);
-	///  *a.at(0) = true;
-	///  *b.at(0) = true;
-	///  *rest.at(0) = true;
-	/// }
+	/// - `&mut self`
+	/// - `other`: The slice to `AND` into `self`.
 	///
-	/// assert_eq!(bits.as_slice()[0], 0b1010_0100);
-	/// //                               a b   rest
-	/// ```
+	/// # Examples
 	///
-	/// The above example splits the slice into three (the first, the second,
-	/// and the rest) in order to hold multiple write references into the slice.
+	/// ```rust
+	/// use bitvec::prelude::*;
 	///
-	/// [`split_at_mut`]: #method.split_at_mut
-	pub fn at(&mut self, index: usize) -> BitGuard<C, T> {
-		BitGuard {
-			bit: self[index],
-			slot: &mut self[index ..= index],
+	/// let mut store = [0b0101_0100u8, 0xFF];
+	/// let other = [0b0011_0000u8, 0x0F];
+	/// let lhs = store.bits_mut::<BigEndian>();
+	/// lhs[2 .. 14].and_with(&other.bits::<BigEndian>()[2 .. 14]);
+	/// assert_eq!(store, [0b0101_0000, 0b0000_1111]);
+	/// ```
+	pub fn and_with(&mut self, other: &Self) {
+		if !self.zip_assign(other, |elt, a, b| elt.clear_bits(a & !b)) {
+			*self &= other;
 		}
 	}
 
-	/// Retrieves a read pointer to the start of the underlying data slice.
+	/// `OR`s another slice into this one.
+	///
+	/// This produces the same result as `*self |= other`, which leaves any
+	/// bits of `self` past the end of `other` unchanged. When the two slices
+	/// have the same length and begin at the same bit of their first
+	/// elements, it works one element at a time rather than one bit at a time.
 	///
 	/// # Parameters
 	///
-	/// - `&self`
+	/// - `&mut self`
+	/// - `other`: The slice to `OR` into `self`.
 	///
-	/// # Returns
+	/// # Examples
 	///
-	/// A pointer to the first element, partial or not, in the underlying store.
+	/// ```rust
+	/// use bitvec::prelude::*;
 	///
-	/// If `self` is empty, then the null pointer is returned.
+	/// let mut store = [0b0101_0100u8, 0x00];
+	/// let other = [0b0011_0011u8, 0xFF];
+	/// let lhs = store.bits_mut::<BigEndian>();
+	/// lhs[2 .. 12].or_with(&other.bits::<BigEndian>()[2 .. 12]);
+	/// assert_eq!(store, [0b0111_0111, 0b1111_0000]);
+	/// ```
+	pub fn or_with(&mut self, other: &Self) {
+		if !self.zip_assign(other, |elt, _, b| elt.set_bits(b)) {
+			*self |= other;
+		}
+	}
+
+	/// `XOR`s another slice into this one.
 	///
-	/// # Safety
+	/// This produces the same result as `*self ^= other`, which leaves any
+	/// bits of `self` past the end of `other` unchanged. When the two slices
+	/// have the same length and begin at the same bit of their first
+	/// elements, it works one element at a time rather than one bit at a time.
 	///
-	/// The caller must ensure that the slice outlives the pointer this function
-	/// returns, or else it will dangle and point to garbage.
+	/// # Parameters
 	///
-	/// Modifying the container referenced by this slice may cause its buffer to
-	/// reallocate, which would also make any pointers to it invalid.
+	/// - `&mut self`
+	/// - `other`: The slice to `XOR` into `self`.
 	///
 	/// # Examples
 	///
 	/// ```rust
 	/// use bitvec::prelude::*;
 	///
-	/// let src = [0u8; 4];
-	/// let bits = src.bits::<BigEndian>();
-	/// assert_eq!(src.as_ptr(), bits.as_ptr());
+	/// let mut store = [0b0101_0100u8, 0x0F];
+	/// let other = [0b0011_0011u8, 0xFF];
+	/// let lhs = store.bits_mut::<BigEndian>();
+	/// lhs[2 .. 12].xor_with(&other.bits::<BigEndian>()[2 .. 12]);
+	/// assert_eq!(store, [0b0110_0111, 0b1111_1111]);
 	/// ```
-	pub fn as_ptr(&self) -> *const T {
-		if self.is_empty() {
-			ptr::null()
-		}
-		else {
-			self.bitptr().pointer().r()
+	pub fn xor_with(&mut self, other: &Self) {
+		if !self.zip_assign(other, |elt, _, b| elt.invert_bits(b)) {
+			*self ^= other;
 		}
 	}
 
-	/// Retrieves a write pointer to the start of the underlying data slice.
+	/// Displays the slice as binary digits, with a separator between groups.
+	///
+	/// Groups are counted from the front of the slice, so only the last group
+	/// may be shorter than `group` bits. This is useful for reading long bit
+	/// maps in logs.
 	///
 	/// # Parameters
 	///
-	/// - `&mut self`
+	/// - `&self`
+	/// - `group`: The number of bits in each group.
+	/// - `sep`: The character written between groups.
 	///
 	/// # Returns
 	///
-	/// A pointer to the first element, partial or not, in the underlying store.
-	///
-	/// If `self` is empty, then the null pointer is returned.
-	///
-	/// # Safety
-	///
-	/// The caller must ensure that the slice outlives the pointer this function
-	/// returns, or else it will dangle and point to garbage.
+	/// A value which prints the slice when formatted with `{}`.
 	///
-	/// Modifying the container referenced by this slice may cause its buffer to
-	/// reallocate, which would also make any pointers to it invalid.
+	/// # Panics
 	///
-	/// The pointer produced by this function is not required to uniquely reach
-	/// the referent element; a neighboring `&mut BitSlice` may also produce a
-	/// pointer to it.
+	/// This panics if `group` is zero.
 	///
 	/// # Examples
 	///
 	/// ```rust
+	/// # #[cfg(feature = "alloc")] {
 	/// use bitvec::prelude::*;
 	///
-	/// let mut src = [0u8; 4];
-	/// let src_ptr = src.as_mut_ptr();
-	/// let bits = src.bits_mut::<BigEndian>();
-	/// assert_eq!(src_ptr, bits.as_mut_ptr());
+	/// let bits = [0xB2u8, 0x40].bits::<BigEndian>();
+	/// assert_eq!(
+	///   format!("{}", bits[.. 10].display_grouped(4, '_')),
+	///   "1011_0010_01",
+	/// );
+	/// # }
 	/// ```
-	pub fn as_mut_ptr(&mut self) -> *mut T {
-		if self.is_empty() {
-			ptr::null_mut()
-		}
-		else {
-			self.bitptr().pointer().w()
-		}
+	pub fn display_grouped(
+		&self,
+		group: usize,
+		sep: char,
+	) -> impl Display + '_ {
+		assert_ne!(group, 0, "Group size cannot be zero");
+		Grouped { bits: self, group, sep }
 	}
 
-	/// Swaps two bits in the slice.
+	/// Sets a bit at an index, without doing bounds checking.
+	///
+	/// This is generally not recommended; use with caution! For a safe
+	/// alternative, see [`set`].
 	///
 	/// # Parameters
 	///
 	/// - `&mut self`
-	/// - `a`: The first index to be swapped.
-	/// - `b`: The second index to be swapped.
+	/// - `index`: The bit index to retrieve. This index is *not* checked
+	///   against the length of `self`.
 	///
-	/// # Panics
+	/// # Effects
 	///
-	/// Panics if either `a` or `b` are out of bounds.
+	/// The bit at `index` is set to `value`.
 	///
-	/// # Examples
+	/// # Safety
+	///
+	/// This method is **not** safe. It performs raw pointer arithmetic to seek
+	/// from the start of the slice to the requested index, and set the bit
+	/// there. It does not inspect the length of `self`, and it is free to
+	/// perform out-of-bounds memory *write* access.
+	///
+	/// Use this method **only** when you have already performed the bounds
+	/// check, and can guarantee that the call occurs with a safely in-bounds
+	/// index.
+	///
+	/// # Examples
+	///
+	/// This example uses a bit slice of length 2, and demonstrates
+	/// out-of-bounds access to the last bit in the element.
 	///
 	/// ```rust
 	/// use bitvec::prelude::*;
 	///
-	/// let mut store = 32u8;
-	/// let bits = store.bits_mut::<BigEndian>();
-	/// assert!(!bits[0]);
-	/// assert!(bits[2]);
-	/// bits.swap(0, 2);
-	/// assert!(bits[0]);
-	/// assert!(!bits[2]);
+	/// let mut src = 0u8;
+	/// {
+	///  let bits = &mut src.bits_mut::<BigEndian>()[2 .. 4];
+	///  assert_eq!(bits.len(), 2);
+	///  unsafe { bits.set_unchecked(5, true); }
+	/// }
+	/// assert_eq!(src, 1);
 	/// ```
-	pub fn swap(&mut self, a: usize, b: usize) {
-		assert!(a < self.len(), "Index {} out of bounds: {}", a, self.len());
-		assert!(b < self.len(), "Index {} out of bounds: {}", b, self.len());
-		unsafe {
-			let (bit_a, bit_b) = (self.get_unchecked(a), self.get_unchecked(b));
-			self.set_unchecked(a, bit_b);
-			self.set_unchecked(b, bit_a);
-		}
+	///
+	/// [`set`]: #method.set
+	pub unsafe fn set_unchecked(&mut self, index: usize, value: bool) {
+		let bitptr = self.bitptr();
+		let (elt, bit) = bitptr.head().offset(index as isize);
+		let data_ptr = bitptr.pointer().n();
+		(&*(data_ptr.offset(elt))).set::<C>(bit, value);
 	}
 
-	/// Reverses the order of bits in the slice, in place.
+	/// Reads an integer field out of the slice.
+	///
+	/// The field is the `width` bits starting at `start`. Like the numeric
+	/// arithmetic on `BitVec`, the first bit of the field is its most
+	/// significant bit, and the last bit of the field is its least significant
+	/// bit. The cursor `C` determines where in memory each of those bits live.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	/// - `start`: The index of the first bit in the field.
+	/// - `width`: The number of bits in the field. This may be at most 64.
+	///
+	/// # Returns
+	///
+	/// The value of the field, zero-extended to `u64`.
+	///
+	/// # Panics
+	///
+	/// This panics if `width` is greater than 64, or if the field extends past
+	/// the end of the slice.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let src = [0b0010_1000u8, 0b1100_0000];
+	/// let bits = src.bits::<BigEndian>();
+	/// assert_eq!(bits.load_bits(2, 3), 0b101);
+	/// assert_eq!(bits.load_bits(6, 4), 0b0011);
+	/// ```
+	pub fn load_bits(&self, start: usize, width: usize) -> u64 {
+		self.field(start, width)
+			.iter()
+			.fold(0, |accum, bit| (accum << 1) | bit as u64)
+	}
+
+	/// Writes an integer field into the slice.
+	///
+	/// The field is the `width` bits starting at `start`, and receives the low
+	/// `width` bits of `value`. Like the numeric arithmetic on `BitVec`, the
+	/// first bit of the field is its most significant bit, and the last bit of
+	/// the field is its least significant bit. This is the inverse of
+	/// [`load_bits`].
 	///
 	/// # Parameters
 	///
 	/// - `&mut self`
+	/// - `start`: The index of the first bit in the field.
+	/// - `width`: The number of bits in the field. This may be at most 64.
+	/// - `value`: The value to store. Bits above `width` are ignored.
+	///
+	/// # Panics
+	///
+	/// This panics if `width` is greater than 64, or if the field extends past
+	/// the end of the slice.
 	///
 	/// # Examples
 	///
 	/// ```rust
 	/// use bitvec::prelude::*;
 	///
-	/// let mut src = 0b1010_1010u8;
-	/// {
-	///   let bits = src.bits_mut::<BigEndian>();
-	///   bits[1 .. 7].reverse();
-	/// }
-	/// eprintln!("{:b}", src);
-	/// assert_eq!(src, 0b1101_0100);
+	/// let mut src = [0u8; 2];
+	/// let bits = src.bits_mut::<BigEndian>();
+	/// bits.set_bits(2, 3, 0b101);
+	/// bits.set_bits(6, 4, 0xF3);
+	/// assert_eq!(src, [0b0010_1000, 0b1100_0000]);
 	/// ```
-	pub fn reverse(&mut self) {
-		//  this is better implemented as a recursive algorithm, but Rust
-		//  doesn’t yet flatten recursive tail calls into a loop, so, do it
-		//  manually.
-		let mut cur: &mut Self = self;
-		loop {
-			let len = cur.len();
-			if len < 2 {
-				return;
-			}
-			//  At this point in the loop body, the indices are known to be good
-			//  and can have their bounds checks completely elided.
-			let end = len - 1;
-			unsafe {
-				//  Access the bits
-				let (h, t) = (cur.get_unchecked(0), cur.get_unchecked(end));
-				//  Set the bits
-				cur.set_unchecked(0, t);
-				cur.set_unchecked(end, h);
-				//  Shrink the slice.
-				cur = cur.bitptr().incr_head().decr_tail().into_bitslice_mut();
-			}
+	///
+	/// [`load_bits`]: #method.load_bits
+	pub fn set_bits(&mut self, start: usize, width: usize, value: u64) {
+		let field = self.field_mut(start, width);
+		for idx in 0 .. width {
+			let bit = (value >> (width - 1 - idx)) & 1 != 0;
+			unsafe { field.set_unchecked(idx, bit); }
 		}
 	}
 
-	/// Divides one slice into two at an index.
+	/// Reads the slice as an integer whose elements are in little-endian
+	/// order.
 	///
-	/// The first will contain all indices from `[0, mid)` (excluding the index
-	/// `mid` itself) and the second will contain all indices from `[mid, len)`
-	/// (excluding the index `len` itself).
+	/// The live bits of each element are taken at their positions in that
+	/// element, regardless of the `C` cursor’s bit order, and the first element
+	/// of the slice is the least significant. This matches the layout of a
+	/// little-endian integer field in a byte buffer, such as in a network or
+	/// file format.
 	///
 	/// # Parameters
 	///
 	/// - `&self`
-	/// - `mid`: The index at which to split
 	///
 	/// # Returns
 	///
-	/// - The bits up to but not including `mid`.
-	/// - The bits from mid onwards.
+	/// The integer value of the slice. An empty slice is zero.
+	///
+	/// # Type Parameters
+	///
+	/// - `U`: The integer type to load. The slice may not be longer than it.
 	///
 	/// # Panics
 	///
-	/// Panics if `mid > self.len()`.
+	/// This panics if the slice is longer than `U::BITS`.
 	///
 	/// # Examples
 	///
 	/// ```rust
 	/// use bitvec::prelude::*;
 	///
-	/// let bits = 15u8.bits::<BigEndian>();
+	/// let src = [0xABu8, 0xCD];
+	/// let bits = &src.bits::<BigEndian>()[4 ..];
+	/// assert_eq!(bits.load_le::<u16>(), 0xCDB);
+	/// ```
+	pub fn load_le<U>(&self) -> U
+	where U: BitStore {
+		self.check_width::<U>();
+		let mut shift = 0;
+		let value = self.live_elements().fold(0u64, |accum, (elt, from, upto)| {
+			let part = extract(elt.load(), live_mask::<C, T>(from, upto));
+			let accum = accum | part << shift;
+			shift += (upto - from) as u32;
+			accum
+		});
+		U::from_u64(value)
+	}
+
+	/// Reads the slice as an integer whose elements are in big-endian order.
 	///
-	/// let (l, r) = bits.split_at(0);
-	/// assert!(l.is_empty());
-	/// assert_eq!(r, bits);
+	/// The live bits of each element are taken at their positions in that
+	/// element, regardless of the `C` cursor’s bit order, and the first element
+	/// of the slice is the most significant. This matches the layout of a
+	/// big-endian integer field in a byte buffer, such as in a network or file
+	/// format.
 	///
-	/// let (l, r) = bits.split_at(4);
-	/// assert_eq!(l, &bits[0 .. 4]);
-	/// assert_eq!(r, &bits[4 .. 8]);
+	/// # Parameters
 	///
-	/// let (l, r) = bits.split_at(8);
-	/// assert_eq!(l, bits);
-	/// assert!(r.is_empty());
+	/// - `&self`
+	///
+	/// # Returns
+	///
+	/// The integer value of the slice. An empty slice is zero.
+	///
+	/// # Type Parameters
+	///
+	/// - `U`: The integer type to load. The slice may not be longer than it.
+	///
+	/// # Panics
+	///
+	/// This panics if the slice is longer than `U::BITS`.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let src = [0xABu8, 0xCD];
+	/// let bits = &src.bits::<BigEndian>()[4 ..];
+	/// assert_eq!(bits.load_be::<u16>(), 0xBCD);
 	/// ```
-	pub fn split_at(&self, mid: usize) -> (&Self, &Self) {
-		let len = self.len();
-		assert!(mid <= len, "Index {} out of bounds: {}", mid, len);
-		if mid == len {
-			(&self, Self::empty())
-		}
-		else {
-			(&self[.. mid], &self[mid ..])
+	pub fn load_be<U>(&self) -> U
+	where U: BitStore {
+		self.check_width::<U>();
+		let value = self.live_elements().fold(0u64, |accum, (elt, from, upto)| {
+			let part = extract(elt.load(), live_mask::<C, T>(from, upto));
+			accum.checked_shl((upto - from) as u32).unwrap_or(0) | part
+		});
+		U::from_u64(value)
+	}
+
+	/// Writes an integer into the slice, with its elements in little-endian
+	/// order.
+	///
+	/// This is the inverse of [`load_le`]. The low `self.len()` bits of
+	/// `value` are written, with its least significant bits going into the
+	/// first element of the slice.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	/// - `value`: The integer to store.
+	///
+	/// # Type Parameters
+	///
+	/// - `U`: The integer type to store. The slice may not be longer than it.
+	///
+	/// # Panics
+	///
+	/// This panics if the slice is longer than `U::BITS`.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let mut src = [0u8; 2];
+	/// src.bits_mut::<BigEndian>()[4 ..].store_le(0xCDBu16);
+	/// assert_eq!(src, [0x0B, 0xCD]);
+	/// ```
+	///
+	/// [`load_le`]: #method.load_le
+	pub fn store_le<U>(&mut self, value: U)
+	where U: BitStore {
+		self.check_width::<U>();
+		let mut value: u64 = value.into();
+		for (elt, from, upto) in self.live_elements() {
+			let mask = live_mask::<C, T>(from, upto);
+			elt.clear_bits(mask);
+			elt.set_bits(deposit(value, mask));
+			value = value.checked_shr((upto - from) as u32).unwrap_or(0);
 		}
 	}
 
-	/// Divides one slice into two at an index.
+	/// Writes an integer into the slice, with its elements in big-endian
+	/// order.
 	///
-	/// The first will contain all indices from `[0, mid)` (excluding the index
-	/// `mid` itself) and the second will contain all indices from `[mid, len)`
-	/// (excluding the index `len` itself).
+	/// This is the inverse of [`load_be`]. The low `self.len()` bits of
+	/// `value` are written, with its most significant bits going into the
+	/// first element of the slice.
 	///
 	/// # Parameters
 	///
 	/// - `&mut self`
-	/// - `mid`: The index at which to split
+	/// - `value`: The integer to store.
+	///
+	/// # Type Parameters
+	///
+	/// - `U`: The integer type to store. The slice may not be longer than it.
+	///
+	/// # Panics
+	///
+	/// This panics if the slice is longer than `U::BITS`.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let mut src = [0u8; 2];
+	/// src.bits_mut::<BigEndian>()[4 ..].store_be(0xBCDu16);
+	/// assert_eq!(src, [0x0B, 0xCD]);
+	/// ```
+	///
+	/// [`load_be`]: #method.load_be
+	pub fn store_be<U>(&mut self, value: U)
+	where U: BitStore {
+		self.check_width::<U>();
+		let value: u64 = value.into();
+		let mut rem = self.len() as u32;
+		for (elt, from, upto) in self.live_elements() {
+			let mask = live_mask::<C, T>(from, upto);
+			rem -= (upto - from) as u32;
+			elt.clear_bits(mask);
+			elt.set_bits(deposit(value.checked_shr(rem).unwrap_or(0), mask));
+		}
+	}
+
+	/// Produces a write reference to a single bit in the slice.
+	///
+	/// The structure returned by this method extends the borrow until it drops,
+	/// which precludes parallel use.
+	///
+	/// The [`split_at_mut`] method allows splitting the borrows of a slice, and
+	/// will enable safe parallel use of these write references. The `atomic`
+	/// feature guarantees that parallel use does not cause data races when
+	/// modifying the underlying slice.
+	///
+	/// # Lifetimes
+	///
+	/// - `'a` Propagates the lifetime of the referent slice to the single-bit
+	///   reference produced.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`: The slice handle will be locked until the structure
+	///   produced by this method goes out of scope. See the Usage section.
+	/// - `index`: The index of the bit in `self` selected.
 	///
 	/// # Returns
 	///
-	/// - The bits up to but not including `mid`.
-	/// - The bits from mid onwards.
+	/// A write reference to the requested bit. Due to Rust limitations, this is
+	/// not a native reference type, but is a custom structure that holds the
+	/// address of the requested bit and its value. The produced structure
+	/// implements `Deref` and `DerefMut` to its cached bit, and commits the
+	/// cached bit to the parent slice on drop.
+	///
+	/// # Usage
+	///
+	/// You must use the dereference operator on the `.at()` expression in order
+	/// to assign to it. In general, you should prefer immediately using and
+	/// discarding the returned value, rather than binding it to a name and
+	/// letting it live for more than one statement.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let mut src = 0u8;
+	/// let bits = src.bits_mut::<BigEndian>();
+	///
+	/// assert!(!bits[0]);
+	/// *bits.at(0) = true;
+	/// //  note the leading dereference.
+	/// assert!(bits[0]);
+	/// ```
+	///
+	/// This example shows multiple usage by using `split_at_mut`.
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let mut src = 0u8;
+	/// let bits = src.bits_mut::<BigEndian>();
+	///
+	/// {
+	///  let (mut a, rest) = bits.split_at_mut(2);
+	///  let (mut b, rest) = rest.split_at_mut(3);
+	///  *a.at(0) = true;
+	///  *b.at(0) = true;
+	///  *rest.at(0) = true;
+	/// }
+	///
+	/// assert_eq!(bits.as_slice()[0], 0b1010_0100);
+	/// //                               a b   rest
+	/// ```
+	///
+	/// The above example splits the slice into three (the first, the second,
+	/// and the rest) in order to hold multiple write references into the slice.
+	///
+	/// [`split_at_mut`]: #method.split_at_mut
+	pub fn at(&mut self, index: usize) -> BitGuard<C, T> {
+		BitGuard {
+			bit: self[index],
+			slot: &mut self[index ..= index],
+		}
+	}
+
+	/// Retrieves a read pointer to the start of the underlying data slice.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	///
+	/// # Returns
+	///
+	/// A pointer to the first element, partial or not, in the underlying store.
+	///
+	/// If `self` is empty, then the null pointer is returned.
+	///
+	/// # Safety
+	///
+	/// The caller must ensure that the slice outlives the pointer this function
+	/// returns, or else it will dangle and point to garbage.
+	///
+	/// Modifying the container referenced by this slice may cause its buffer to
+	/// reallocate, which would also make any pointers to it invalid.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let src = [0u8; 4];
+	/// let bits = src.bits::<BigEndian>();
+	/// assert_eq!(src.as_ptr(), bits.as_ptr());
+	/// ```
+	pub fn as_ptr(&self) -> *const T {
+		if self.is_empty() {
+			ptr::null()
+		}
+		else {
+			self.bitptr().pointer().r()
+		}
+	}
+
+	/// Retrieves a write pointer to the start of the underlying data slice.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	///
+	/// # Returns
+	///
+	/// A pointer to the first element, partial or not, in the underlying store.
+	///
+	/// If `self` is empty, then the null pointer is returned.
+	///
+	/// # Safety
+	///
+	/// The caller must ensure that the slice outlives the pointer this function
+	/// returns, or else it will dangle and point to garbage.
+	///
+	/// Modifying the container referenced by this slice may cause its buffer to
+	/// reallocate, which would also make any pointers to it invalid.
+	///
+	/// The pointer produced by this function is not required to uniquely reach
+	/// the referent element; a neighboring `&mut BitSlice` may also produce a
+	/// pointer to it.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let mut src = [0u8; 4];
+	/// let src_ptr = src.as_mut_ptr();
+	/// let bits = src.bits_mut::<BigEndian>();
+	/// assert_eq!(src_ptr, bits.as_mut_ptr());
+	/// ```
+	pub fn as_mut_ptr(&mut self) -> *mut T {
+		if self.is_empty() {
+			ptr::null_mut()
+		}
+		else {
+			self.bitptr().pointer().w()
+		}
+	}
+
+	/// Swaps two bits in the slice.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	/// - `a`: The first index to be swapped.
+	/// - `b`: The second index to be swapped.
+	///
+	/// # Panics
+	///
+	/// Panics if either `a` or `b` are out of bounds.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let mut store = 32u8;
+	/// let bits = store.bits_mut::<BigEndian>();
+	/// assert!(!bits[0]);
+	/// assert!(bits[2]);
+	/// bits.swap(0, 2);
+	/// assert!(bits[0]);
+	/// assert!(!bits[2]);
+	/// ```
+	pub fn swap(&mut self, a: usize, b: usize) {
+		assert!(a < self.len(), "Index {} out of bounds: {}", a, self.len());
+		assert!(b < self.len(), "Index {} out of bounds: {}", b, self.len());
+		unsafe {
+			let (bit_a, bit_b) = (self.get_unchecked(a), self.get_unchecked(b));
+			self.set_unchecked(a, bit_b);
+			self.set_unchecked(b, bit_a);
+		}
+	}
+
+	/// Reverses the order of bits in the slice, in place.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let mut src = 0b1010_1010u8;
+	/// {
+	///   let bits = src.bits_mut::<BigEndian>();
+	///   bits[1 .. 7].reverse();
+	/// }
+	/// eprintln!("{:b}", src);
+	/// assert_eq!(src, 0b1101_0100);
+	/// ```
+	pub fn reverse(&mut self) {
+		//  Slices which fully span their elements can reverse the element order,
+		//  then reverse the bits within each element.
+		if let BitDomainMut::Spanning(body) = self.bitptr().domain_mut() {
+			body.reverse();
+			for elt in body {
+				*elt = reverse_element::<C, T>(*elt);
+			}
+			return;
+		}
+		//  this is better implemented as a recursive algorithm, but Rust
+		//  doesn’t yet flatten recursive tail calls into a loop, so, do it
+		//  manually.
+		let mut cur: &mut Self = self;
+		loop {
+			let len = cur.len();
+			if len < 2 {
+				return;
+			}
+			//  At this point in the loop body, the indices are known to be good
+			//  and can have their bounds checks completely elided.
+			let end = len - 1;
+			unsafe {
+				//  Access the bits
+				let (h, t) = (cur.get_unchecked(0), cur.get_unchecked(end));
+				//  Set the bits
+				cur.set_unchecked(0, t);
+				cur.set_unchecked(end, h);
+				//  Shrink the slice.
+				cur = cur.bitptr().incr_head().decr_tail().into_bitslice_mut();
+			}
+		}
+	}
+
+	/// Divides one slice into two at an index.
+	///
+	/// The first will contain all indices from `[0, mid)` (excluding the index
+	/// `mid` itself) and the second will contain all indices from `[mid, len)`
+	/// (excluding the index `len` itself).
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	/// - `mid`: The index at which to split
+	///
+	/// # Returns
+	///
+	/// - The bits up to but not including `mid`.
+	/// - The bits from mid onwards.
+	///
+	/// # Panics
+	///
+	/// Panics if `mid > self.len()`.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let bits = 15u8.bits::<BigEndian>();
+	///
+	/// let (l, r) = bits.split_at(0);
+	/// assert!(l.is_empty());
+	/// assert_eq!(r, bits);
+	///
+	/// let (l, r) = bits.split_at(4);
+	/// assert_eq!(l, &bits[0 .. 4]);
+	/// assert_eq!(r, &bits[4 .. 8]);
+	///
+	/// let (l, r) = bits.split_at(8);
+	/// assert_eq!(l, bits);
+	/// assert!(r.is_empty());
+	/// ```
+	pub fn split_at(&self, mid: usize) -> (&Self, &Self) {
+		let len = self.len();
+		assert!(mid <= len, "Index {} out of bounds: {}", mid, len);
+		if mid == len {
+			(&self, Self::empty())
+		}
+		else {
+			(&self[.. mid], &self[mid ..])
+		}
+	}
+
+	/// Divides one slice into two at an index.
+	///
+	/// The first will contain all indices from `[0, mid)` (excluding the index
+	/// `mid` itself) and the second will contain all indices from `[mid, len)`
+	/// (excluding the index `len` itself).
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	/// - `mid`: The index at which to split
+	///
+	/// # Returns
+	///
+	/// - The bits up to but not including `mid`.
+	/// - The bits from mid onwards.
+	///
+	/// # Panics
+	///
+	/// Panics if `mid > self.len()`.
+	pub fn split_at_mut(&mut self, mid: usize) -> (&mut Self, &mut Self) {
+		let (head, tail) = self.split_at(mid);
+		(head.bitptr().into_bitslice_mut(), tail.bitptr().into_bitslice_mut())
+	}
+
+	/// Takes a prefix of the slice.
+	///
+	/// Unlike `Iterator::take`, this produces a bit slice rather than an
+	/// iterator adapter, so the result can continue to use slice methods.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	/// - `n`: The maximum number of bits to keep.
+	///
+	/// # Returns
+	///
+	/// The first `n` bits of `self`, or all of `self` if it is shorter than
+	/// `n` bits.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let bits = 0b1100_1010u8.bits::<BigEndian>();
+	/// assert_eq!(bits.take(3), &bits[.. 3]);
+	/// assert_eq!(bits.take(20), bits);
+	/// ```
+	pub fn take(&self, n: usize) -> &Self {
+		self.split_at(cmp::min(n, self.len())).0
+	}
+
+	/// Skips a prefix of the slice.
+	///
+	/// Unlike `Iterator::skip`, this produces a bit slice rather than an
+	/// iterator adapter, so the result can continue to use slice methods.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	/// - `n`: The number of bits to discard from the front.
+	///
+	/// # Returns
+	///
+	/// The bits of `self` after the first `n`, or an empty slice if `self` is
+	/// not longer than `n` bits.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let bits = 0b1100_1010u8.bits::<BigEndian>();
+	/// assert_eq!(bits.skip(3), &bits[3 ..]);
+	/// assert!(bits.skip(20).is_empty());
+	/// ```
+	pub fn skip(&self, n: usize) -> &Self {
+		self.split_at(cmp::min(n, self.len())).1
+	}
+
+	/// Creates a `BitVec` by repeating the slice `n` times.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	/// - `n`: The number of copies of `self` to place in the new vector.
+	///
+	/// # Returns
+	///
+	/// A `BitVec` of length `self.len() * n` containing `self` `n` times in a
+	/// row. The vector allocates once, and copies each repetition in bulk.
+	///
+	/// # Panics
+	///
+	/// Panics if the repeated length would overflow the capacity of a
+	/// `BitVec`.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let bits = 0b10u8.bits::<LittleEndian>();
+	/// assert_eq!(bits[.. 2].repeat(3), bitvec![0, 1, 0, 1, 0, 1]);
+	/// assert!(bits.repeat(0).is_empty());
+	/// ```
+	#[cfg(feature = "alloc")]
+	pub fn repeat(&self, n: usize) -> BitVec<C, T> {
+		let len = self.len().checked_mul(n)
+			.expect("Capacity overflow in BitSlice::repeat");
+		let mut out = BitVec::with_capacity(len);
+		for _ in 0 .. n {
+			out.extend_from_bitslice(self);
+		}
+		out
+	}
+
+	/// Tests if the slice begins with the given prefix.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	/// - `prefix`: Any `BitSlice` against which `self` is tested. This is not
+	///   required to have the same cursor or storage types as `self`.
+	///
+	/// # Returns
+	///
+	/// Whether `self` begins with `prefix`. This is true only if `self` is at
+	/// least as long as `prefix` and their bits are semantically equal.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let bits = 0xA6u8.bits::<BigEndian>();
+	/// assert!(bits.starts_with(&bits[.. 3]));
+	/// assert!(!bits.starts_with(&bits[3 ..]));
+	/// ```
+	pub fn starts_with<D, U>(&self, prefix: &BitSlice<D, U>) -> bool
+	where D: Cursor, U: BitStore {
+		let plen = prefix.len();
+		self.len() >= plen && prefix == self[.. plen]
+	}
+
+	/// Tests if the slice ends with the given suffix.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	/// - `suffix`: Any `BitSlice` against which `self` is tested. This is not
+	///   required to have the same cursor or storage types as `self`.
+	///
+	/// # Returns
+	///
+	/// Whether `self` ends with `suffix`. This is true only if `self` is at
+	/// least as long as `suffix` and their bits are semantically equal.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let bits = 0xA6u8.bits::<BigEndian>();
+	/// assert!(bits.ends_with(&bits[5 ..]));
+	/// assert!(!bits.ends_with(&bits[.. 5]));
+	/// ```
+	pub fn ends_with<D, U>(&self, suffix: &BitSlice<D, U>) -> bool
+	where D: Cursor, U: BitStore {
+		let slen = suffix.len();
+		let len = self.len();
+		len >= slen && suffix == self[len - slen ..]
+	}
+
+	/// Tests if the slice contains the given bit sequence anywhere within it.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	/// - `needle`: Any `BitSlice` for which `self` is searched. This is not
+	///   required to have the same cursor or storage types as `self`.
+	///
+	/// # Returns
+	///
+	/// Whether some run of `needle.len()` consecutive bits in `self` is
+	/// semantically equal to `needle`. An empty `needle` is contained in every
+	/// slice, and a `needle` longer than `self` is contained in none.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let bits = 0b0110_0000u8.bits::<BigEndian>();
+	/// assert!(bits.contains(&bits[1 .. 3]));
+	/// assert!(!bits[.. 4].contains(&bits[.. 5]));
+	/// assert!(bits.contains(BitSlice::<BigEndian, u8>::empty()));
+	/// ```
+	pub fn contains<D, U>(&self, needle: &BitSlice<D, U>) -> bool
+	where D: Cursor, U: BitStore {
+		self.find(needle).is_some()
+	}
+
+	/// Finds the first occurrence of a bit sequence in the slice.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	/// - `needle`: Any `BitSlice` for which `self` is searched. This is not
+	///   required to have the same cursor or storage types as `self`.
+	///
+	/// # Returns
+	///
+	/// The index in `self` at which the first run of bits equal to `needle`
+	/// begins, or `None` if there is no such run. Runs may overlap, so the
+	/// search advances by one bit at a time. An empty `needle` is found at
+	/// index `0`.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let bits = 0b1010_1000u8.bits::<BigEndian>();
+	/// let sync = &bits[.. 3];
+	/// assert_eq!(bits.find(sync), Some(0));
+	/// assert_eq!(bits[1 ..].find(sync), Some(1));
+	/// assert_eq!(bits[5 ..].find(sync), None);
+	/// ```
+	pub fn find<D, U>(&self, needle: &BitSlice<D, U>) -> Option<usize>
+	where D: Cursor, U: BitStore {
+		match needle.len() {
+			0 => Some(0),
+			n if n > self.len() => None,
+			n => self.windows(n).position(|w| w == needle),
+		}
+	}
+
+	/// Finds the last occurrence of a bit sequence in the slice.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	/// - `needle`: Any `BitSlice` for which `self` is searched. This is not
+	///   required to have the same cursor or storage types as `self`.
+	///
+	/// # Returns
+	///
+	/// The index in `self` at which the last run of bits equal to `needle`
+	/// begins, or `None` if there is no such run. Runs may overlap, so the
+	/// search retreats by one bit at a time. An empty `needle` is found at
+	/// index `self.len()`.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let bits = 0b1010_1000u8.bits::<BigEndian>();
+	/// let sync = &bits[.. 3];
+	/// assert_eq!(bits.rfind(sync), Some(2));
+	/// assert_eq!(bits[.. 4].rfind(sync), Some(0));
+	/// ```
+	pub fn rfind<D, U>(&self, needle: &BitSlice<D, U>) -> Option<usize>
+	where D: Cursor, U: BitStore {
+		match needle.len() {
+			0 => Some(self.len()),
+			n if n > self.len() => None,
+			n => self.windows(n).rposition(|w| w == needle),
+		}
+	}
+
+	/// Produces the set union of two slices.
+	///
+	/// Each bit of the output is set if it is set in either `self` or `other`.
+	///
+	/// Unlike the `|` operator, which stops at the end of the shorter
+	/// operand, this treats the missing bits of the shorter operand as zero.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	/// - `other`: Any `BitSlice`. This is not required to have the same cursor
+	///   or storage types as `self`.
+	///
+	/// # Returns
+	///
+	/// A new `BitVec` as long as the longer of `self` and `other`.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let a = bitvec![0, 1, 1];
+	/// let b = bitvec![1, 0, 0, 0, 1];
+	/// assert_eq!(a.union(&b), bitvec![1, 1, 1, 0, 1]);
+	/// ```
+	#[cfg(feature = "alloc")]
+	pub fn union<D, U>(&self, other: &BitSlice<D, U>) -> BitVec<C, T>
+	where D: Cursor, U: BitStore {
+		self.zip_extended(other, |a, b| a | b)
+	}
+
+	/// Produces the set intersection of two slices.
+	///
+	/// Each bit of the output is set if it is set in both `self` and `other`.
+	///
+	/// Unlike the `&` operator, which stops at the end of the shorter
+	/// operand, this treats the missing bits of the shorter operand as zero.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	/// - `other`: Any `BitSlice`. This is not required to have the same cursor
+	///   or storage types as `self`.
+	///
+	/// # Returns
+	///
+	/// A new `BitVec` as long as the longer of `self` and `other`.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let a = bitvec![0, 1, 1];
+	/// let b = bitvec![1, 1, 0, 0, 1];
+	/// assert_eq!(a.intersection(&b), bitvec![0, 1, 0, 0, 0]);
+	/// ```
+	#[cfg(feature = "alloc")]
+	pub fn intersection<D, U>(&self, other: &BitSlice<D, U>) -> BitVec<C, T>
+	where D: Cursor, U: BitStore {
+		self.zip_extended(other, |a, b| a & b)
+	}
+
+	/// Produces the set difference of two slices.
+	///
+	/// Each bit of the output is set if it is set in `self` and clear in `other`.
+	///
+	/// Unlike the `&` operator, which stops at the end of the shorter
+	/// operand, this treats the missing bits of the shorter operand as zero.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	/// - `other`: Any `BitSlice`. This is not required to have the same cursor
+	///   or storage types as `self`.
+	///
+	/// # Returns
+	///
+	/// A new `BitVec` as long as the longer of `self` and `other`.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let a = bitvec![0, 1, 1, 1, 1];
+	/// let b = bitvec![1, 1, 0];
+	/// assert_eq!(a.difference(&b), bitvec![0, 0, 1, 1, 1]);
+	/// ```
+	#[cfg(feature = "alloc")]
+	pub fn difference<D, U>(&self, other: &BitSlice<D, U>) -> BitVec<C, T>
+	where D: Cursor, U: BitStore {
+		self.zip_extended(other, |a, b| a & !b)
+	}
+
+	/// Produces the symmetric set difference of two slices.
+	///
+	/// Each bit of the output is set if it is set in exactly one of `self` and
+	/// `other`.
+	///
+	/// Unlike the `^` operator, which stops at the end of the shorter
+	/// operand, this treats the missing bits of the shorter operand as zero.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	/// - `other`: Any `BitSlice`. This is not required to have the same cursor
+	///   or storage types as `self`.
+	///
+	/// # Returns
+	///
+	/// A new `BitVec` as long as the longer of `self` and `other`.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let a = bitvec![0, 1, 1];
+	/// let b = bitvec![1, 1, 0, 0, 1];
+	/// assert_eq!(a.symmetric_difference(&b), bitvec![1, 0, 1, 0, 1]);
+	/// ```
+	#[cfg(feature = "alloc")]
+	pub fn symmetric_difference<D, U>(
+		&self,
+		other: &BitSlice<D, U>,
+	) -> BitVec<C, T>
+	where D: Cursor, U: BitStore {
+		self.zip_extended(other, |a, b| a ^ b)
+	}
+
+	/// Tests whether every bit set in `self` is also set in `other`.
+	///
+	/// Slices of different lengths are compared as if the shorter one were
+	/// extended with clear bits. When the two slices begin at the same position
+	/// within their elements, they are compared an element at a time, and the
+	/// test stops at the first element which has a bit outside `other`.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	/// - `other`: The slice which may contain `self`.
+	///
+	/// # Returns
+	///
+	/// Whether the set bits of `self` are a subset of the set bits of `other`.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let a = bitvec![0, 1, 0, 1];
+	/// let b = bitvec![1, 1, 0, 1, 1];
+	/// assert!(a.is_subset(&b));
+	/// assert!(!b.is_subset(&a));
+	/// ```
+	pub fn is_subset(&self, other: &Self) -> bool {
+		let len = cmp::min(self.len(), other.len());
+		let (lhs, rhs) = (&self[.. len], &other[.. len]);
+		let common = match lhs.zip_elements(rhs) {
+			Some(mut elts) => elts.all(|(a, b)| a & !b == T::from(0)),
+			None => lhs.iter().zip(rhs).all(|(a, b)| !a | b),
+		};
+		common && self[len ..].not_any()
+	}
+
+	/// Tests whether every bit set in `other` is also set in `self`.
+	///
+	/// This is [`is_subset`] with the operands exchanged.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	/// - `other`: The slice which `self` may contain.
+	///
+	/// # Returns
+	///
+	/// Whether the set bits of `self` are a superset of the set bits of
+	/// `other`.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
 	///
-	/// # Panics
+	/// let a = bitvec![1, 1, 0, 1, 0, 0];
+	/// let b = bitvec![0, 1, 0, 1];
+	/// assert!(a.is_superset(&b));
+	/// assert!(!b.is_superset(&a));
+	/// ```
 	///
-	/// Panics if `mid > self.len()`.
-	pub fn split_at_mut(&mut self, mid: usize) -> (&mut Self, &mut Self) {
-		let (head, tail) = self.split_at(mid);
-		(head.bitptr().into_bitslice_mut(), tail.bitptr().into_bitslice_mut())
+	/// [`is_subset`]: #method.is_subset
+	pub fn is_superset(&self, other: &Self) -> bool {
+		other.is_subset(self)
 	}
 
-	/// Tests if the slice begins with the given prefix.
+	/// Tests whether `self` and `other` have no set bits in common.
+	///
+	/// Only the indices present in both slices can hold a common set bit, so
+	/// the longer slice’s excess bits are not examined. As with
+	/// [`is_subset`], aligned slices are compared an element at a time.
 	///
 	/// # Parameters
 	///
 	/// - `&self`
-	/// - `prefix`: Any `BitSlice` against which `self` is tested. This is not
-	///   required to have the same cursor or storage types as `self`.
+	/// - `other`: Any slice of the same type as `self`.
 	///
 	/// # Returns
 	///
-	/// Whether `self` begins with `prefix`. This is true only if `self` is at
-	/// least as long as `prefix` and their bits are semantically equal.
+	/// Whether no index is set in both `self` and `other`.
 	///
 	/// # Examples
 	///
 	/// ```rust
 	/// use bitvec::prelude::*;
 	///
-	/// let bits = 0xA6u8.bits::<BigEndian>();
-	/// assert!(bits.starts_with(&bits[.. 3]));
-	/// assert!(!bits.starts_with(&bits[3 ..]));
+	/// let a = bitvec![1, 0, 1, 0];
+	/// assert!(a.is_disjoint(&bitvec![0, 1, 0, 1, 1]));
+	/// assert!(!a.is_disjoint(&bitvec![0, 0, 1]));
 	/// ```
-	pub fn starts_with<D, U>(&self, prefix: &BitSlice<D, U>) -> bool
-	where D: Cursor, U: BitStore {
-		let plen = prefix.len();
-		self.len() >= plen && prefix == self[.. plen]
+	///
+	/// [`is_subset`]: #method.is_subset
+	pub fn is_disjoint(&self, other: &Self) -> bool {
+		let len = cmp::min(self.len(), other.len());
+		let (lhs, rhs) = (&self[.. len], &other[.. len]);
+		match lhs.zip_elements(rhs) {
+			Some(mut elts) => elts.all(|(a, b)| a & b == T::from(0)),
+			None => lhs.iter().zip(rhs).all(|(a, b)| !(a & b)),
+		}
 	}
 
-	/// Tests if the slice ends with the given suffix.
+	/// Counts the indices at which two slices of equal length differ.
+	///
+	/// When the two slices begin at the same position within their elements,
+	/// the differing bits of each pair of elements are counted together,
+	/// rather than comparing each bit in turn.
 	///
 	/// # Parameters
 	///
 	/// - `&self`
-	/// - `suffix`: Any `BitSlice` against which `self` is tested. This is not
-	///   required to have the same cursor or storage types as `self`.
+	/// - `other`: A slice of the same type and length as `self`.
 	///
 	/// # Returns
 	///
-	/// Whether `self` ends with `suffix`. This is true only if `self` is at
-	/// least as long as `suffix` and their bits are semantically equal.
+	/// The Hamming distance between `self` and `other`.
+	///
+	/// # Panics
+	///
+	/// This panics if `self` and `other` have different lengths.
 	///
 	/// # Examples
 	///
 	/// ```rust
 	/// use bitvec::prelude::*;
 	///
-	/// let bits = 0xA6u8.bits::<BigEndian>();
-	/// assert!(bits.ends_with(&bits[5 ..]));
-	/// assert!(!bits.ends_with(&bits[.. 5]));
+	/// let a = bitvec![1, 0, 1, 0];
+	/// let b = bitvec![1, 1, 0, 0];
+	/// assert_eq!(a.hamming_distance(&b), 2);
 	/// ```
-	pub fn ends_with<D, U>(&self, suffix: &BitSlice<D, U>) -> bool
-	where D: Cursor, U: BitStore {
-		let slen = suffix.len();
-		let len = self.len();
-		len >= slen && suffix == self[len - slen ..]
+	pub fn hamming_distance(&self, other: &Self) -> usize {
+		assert_eq!(
+			self.len(),
+			other.len(),
+			"Hamming distance requires slices of equal length",
+		);
+		match self.zip_elements(other) {
+			Some(elts) => elts
+				.map(|(a, b)| (a & !b).count_ones() + (!a & b).count_ones())
+				.sum(),
+			None => self.iter().zip(other).filter(|&(a, b)| a != b).count(),
+		}
 	}
 
 	/// Rotates the slice, in place, to the left.
@@ -1166,9 +2217,9 @@ where C: Cursor, T: BitStore {
 	/// of the slice, and the bits from `[by ..]` will be at the front. This
 	/// operates fully in-place.
 	///
-	/// In-place rotation of bits requires this method to take `O(k × n)` time.
-	/// It is impossible to use machine intrinsics to perform galloping rotation
-	/// on bits.
+	/// The rotation is performed by reversing the two segments on either side
+	/// of the rotation point, then reversing the whole slice. This takes
+	/// `O(n)` time and no scratch space.
 	///
 	/// # Parameters
 	///
@@ -1194,16 +2245,11 @@ where C: Cursor, T: BitStore {
 			return;
 		}
 
-		for _ in 0 .. by {
-			let tmp = unsafe { self.get_unchecked(0) };
-			for n in 1 .. len {
-				unsafe {
-					let bit = self.get_unchecked(n);
-					self.set_unchecked(n - 1, bit);
-				}
-			}
-			unsafe { self.set_unchecked(len - 1, tmp); }
-		}
+		//  Reversing each segment, then the whole slice, moves the segments
+		//  past each other while restoring their internal order.
+		self[.. by].reverse();
+		self[by ..].reverse();
+		self.reverse();
 	}
 
 	/// Rotates the slice, in place, to the right.
@@ -1212,9 +2258,9 @@ where C: Cursor, T: BitStore {
 	/// at the front of the slice, and the bits from `[.. self.len() - by]` will
 	/// be at the back. This operates fully in-place.
 	///
-	/// In-place rotation of bits requires this method to take `O(k × n)` time.
-	/// It is impossible to use machine intrinsics to perform galloping rotation
-	/// on bits.
+	/// The rotation is performed by reversing the two segments on either side
+	/// of the rotation point, then reversing the whole slice. This takes
+	/// `O(n)` time and no scratch space.
 	///
 	/// # Parameters
 	///
@@ -1236,22 +2282,64 @@ where C: Cursor, T: BitStore {
 	pub fn rotate_right(&mut self, by: usize) {
 		let len = self.len();
 		assert!(by <= len, "Slices cannot be rotated by more than their length");
-		if by == len {
+		if by == 0 || by == len {
 			return;
 		}
 
-		//  Perform `by` repetitions,
-		for _ in 0 .. by {
-			let tmp = unsafe { self.get_unchecked(len - 1) };
-			//  of `len` steps
-			for n in (0 .. len - 1).rev() {
-				unsafe {
-					let bit = self.get_unchecked(n);
-					self.set_unchecked(n + 1, bit);
-				}
-			}
-			unsafe { self.set_unchecked(0, tmp); }
-		}
+		let mid = len - by;
+		self[.. mid].reverse();
+		self[mid ..].reverse();
+		self.reverse();
+	}
+
+	/// Shifts all bits in the slice towards the front, in place.
+	///
+	/// This behaves like a fixed-width hardware register: the slice keeps its
+	/// length, the first `by` bits are discarded, and the vacated bits at the
+	/// back are cleared. It is the named form of the `<<=` operator.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	/// - `by`: The number of places to shift. If this is at least the length
+	///   of the slice, the whole slice is cleared.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let mut bv = bitvec![1, 1, 0, 0];
+	/// bv.shift_left(1);
+	/// assert_eq!(bv, bitvec![1, 0, 0, 0]);
+	/// ```
+	pub fn shift_left(&mut self, by: usize) {
+		*self <<= by;
+	}
+
+	/// Shifts all bits in the slice towards the back, in place.
+	///
+	/// This behaves like a fixed-width hardware register: the slice keeps its
+	/// length, the last `by` bits are discarded, and the vacated bits at the
+	/// front are cleared. It is the named form of the `>>=` operator.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	/// - `by`: The number of places to shift. If this is at least the length
+	///   of the slice, the whole slice is cleared.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let mut bv = bitvec![1, 1, 0, 0];
+	/// bv.shift_right(1);
+	/// assert_eq!(bv, bitvec![0, 1, 1, 0]);
+	/// ```
+	pub fn shift_right(&mut self, by: usize) {
+		*self >>= by;
 	}
 
 	/// Tests if *all* bits in the slice domain are set (logical `∧`).
@@ -1527,29 +2615,17 @@ where C: Cursor, T: BitStore {
 		match self.bitptr().domain() {
 			BitDomain::Empty => 0,
 			BitDomain::Minor(head, elt, tail) => {
-				(*head .. *tail)
-					.map(|n| elt.get::<C>(n.idx()))
-					.filter(|b| *b)
-					.count()
+				(elt.load() & live_mask::<C, T>(*head, *tail)).count_ones()
 			},
 			BitDomain::Major(h, head, body, tail, t) => {
-				(*h .. T::BITS)
-					.map(|n| head.get::<C>(n.idx()))
-					.filter(|b| *b)
-					.count() +
+				(head.load() & live_mask::<C, T>(*h, T::BITS)).count_ones() +
 				body.iter()
 					.map(T::count_ones)
 					.sum::<usize>() +
-				(0 .. *t)
-					.map(|n| tail.get::<C>(n.idx()))
-					.filter(|b| *b)
-					.count()
+				(tail.load() & live_mask::<C, T>(0, *t)).count_ones()
 			},
 			BitDomain::PartialHead(h, head, body) => {
-				(*h .. T::BITS)
-					.map(|n| head.get::<C>(n.idx()))
-					.filter(|b| *b)
-					.count() +
+				(head.load() & live_mask::<C, T>(*h, T::BITS)).count_ones() +
 				body.iter()
 					.map(T::count_ones)
 					.sum::<usize>()
@@ -1558,10 +2634,7 @@ where C: Cursor, T: BitStore {
 				body.iter()
 					.map(T::count_ones)
 					.sum::<usize>() +
-				(0 .. *t)
-					.map(|n| tail.get::<C>(n.idx()))
-					.filter(|b| *b)
-					.count()
+				(tail.load() & live_mask::<C, T>(0, *t)).count_ones()
 			},
 			BitDomain::Spanning(body) => {
 				body.iter()
@@ -1589,52 +2662,299 @@ where C: Cursor, T: BitStore {
 	/// let bits = [0xFDu8, 0x25].bits::<BigEndian>();
 	/// assert_eq!(bits.count_zeros(), 6);
 	/// ```
-	pub fn count_zeros(&self) -> usize {
-		match self.bitptr().domain() {
-			BitDomain::Empty => 0,
-			BitDomain::Minor(head, elt, tail) => {
-				(*head .. *tail)
-					.map(|n| !elt.get::<C>(n.idx()))
-					.filter(|b| !*b)
-					.count()
-			},
-			BitDomain::Major(h, head, body, tail, t) => {
-				(*h .. T::BITS)
-					.map(|n| head.get::<C>(n.idx()))
-					.filter(|b| !*b)
-					.count() +
-				body.iter()
-					.map(T::count_zeros)
-					.sum::<usize>() +
-				(0 .. *t)
-					.map(|n| tail.get::<C>(n.idx()))
-					.filter(|b| !*b)
-					.count()
-			},
-			BitDomain::PartialHead(h, head, body) => {
-				(*h .. T::BITS)
-					.map(|n| head.get::<C>(n.idx()))
-					.filter(|b| !*b)
-					.count() +
-				body.iter()
-					.map(T::count_zeros)
-					.sum::<usize>()
-			},
-			BitDomain::PartialTail(body, tail, t) => {
-				body.iter()
-					.map(T::count_zeros)
-					.sum::<usize>() +
-				(0 .. *t)
-					.map(|n| tail.get::<C>(n.idx()))
-					.filter(|b| !*b)
-					.count()
-			},
-			BitDomain::Spanning(body) => {
-				body.iter()
-					.map(T::count_zeros)
-					.sum::<usize>()
-			},
+	pub fn count_zeros(&self) -> usize {
+		match self.bitptr().domain() {
+			BitDomain::Empty => 0,
+			BitDomain::Minor(head, elt, tail) => {
+				(!elt.load() & live_mask::<C, T>(*head, *tail)).count_ones()
+			},
+			BitDomain::Major(h, head, body, tail, t) => {
+				(!head.load() & live_mask::<C, T>(*h, T::BITS)).count_ones() +
+				body.iter()
+					.map(T::count_zeros)
+					.sum::<usize>() +
+				(!tail.load() & live_mask::<C, T>(0, *t)).count_ones()
+			},
+			BitDomain::PartialHead(h, head, body) => {
+				(!head.load() & live_mask::<C, T>(*h, T::BITS)).count_ones() +
+				body.iter()
+					.map(T::count_zeros)
+					.sum::<usize>()
+			},
+			BitDomain::PartialTail(body, tail, t) => {
+				body.iter()
+					.map(T::count_zeros)
+					.sum::<usize>() +
+				(!tail.load() & live_mask::<C, T>(0, *t)).count_ones()
+			},
+			BitDomain::Spanning(body) => {
+				body.iter()
+					.map(T::count_zeros)
+					.sum::<usize>()
+			},
+		}
+	}
+
+	/// Counts the clear bits at the front of the slice.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	///
+	/// # Returns
+	///
+	/// The number of bits before the first set bit. This is the length of the
+	/// slice when no bit is set, and `0` when the slice is empty.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let bits = [0u8, 0x10].bits::<BigEndian>();
+	/// assert_eq!(bits.leading_zeros(), 11);
+	/// assert_eq!(bits[12 ..].leading_zeros(), 4);
+	/// ```
+	pub fn leading_zeros(&self) -> usize {
+		self.leading_run(false)
+	}
+
+	/// Counts the set bits at the front of the slice.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	///
+	/// # Returns
+	///
+	/// The number of bits before the first clear bit. This is the length of
+	/// the slice when every bit is set, and `0` when the slice is empty.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let bits = [0xFFu8, 0xF7].bits::<BigEndian>();
+	/// assert_eq!(bits.leading_ones(), 12);
+	/// assert_eq!(bits[13 ..].leading_ones(), 3);
+	/// ```
+	pub fn leading_ones(&self) -> usize {
+		self.leading_run(true)
+	}
+
+	/// Counts the clear bits at the back of the slice.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	///
+	/// # Returns
+	///
+	/// The number of bits after the last set bit. This is the length of the
+	/// slice when no bit is set, and `0` when the slice is empty.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let bits = [0x08u8, 0].bits::<BigEndian>();
+	/// assert_eq!(bits.trailing_zeros(), 11);
+	/// assert_eq!(bits[.. 4].trailing_zeros(), 4);
+	/// ```
+	pub fn trailing_zeros(&self) -> usize {
+		self.trailing_run(false)
+	}
+
+	/// Counts the set bits at the back of the slice.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	///
+	/// # Returns
+	///
+	/// The number of bits after the last clear bit. This is the length of the
+	/// slice when every bit is set, and `0` when the slice is empty.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let bits = [0xEFu8, 0xFF].bits::<BigEndian>();
+	/// assert_eq!(bits.trailing_ones(), 12);
+	/// assert_eq!(bits[.. 3].trailing_ones(), 3);
+	/// ```
+	pub fn trailing_ones(&self) -> usize {
+		self.trailing_run(true)
+	}
+
+	/// Finds the index of the first set bit in the slice.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	///
+	/// # Returns
+	///
+	/// The index of the first set bit, if the slice has any set bits. The
+	/// index counts from the front of the slice, not from its underlying
+	/// memory element.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let bits = [0u8, 0x21].bits::<BigEndian>();
+	/// assert_eq!(bits.first_one(), Some(10));
+	/// assert_eq!(bits[11 ..].first_one(), Some(4));
+	/// assert!(bits[.. 10].first_one().is_none());
+	/// ```
+	pub fn first_one(&self) -> Option<usize> {
+		let idx = self.leading_run(false);
+		if idx < self.len() { Some(idx) } else { None }
+	}
+
+	/// Finds the index of the first clear bit in the slice.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	///
+	/// # Returns
+	///
+	/// The index of the first clear bit, if the slice has any clear bits. The
+	/// index counts from the front of the slice, not from its underlying
+	/// memory element.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let bits = [!0u8, !0x21].bits::<BigEndian>();
+	/// assert_eq!(bits.first_zero(), Some(10));
+	/// assert_eq!(bits[11 ..].first_zero(), Some(4));
+	/// assert!(bits[.. 10].first_zero().is_none());
+	/// ```
+	pub fn first_zero(&self) -> Option<usize> {
+		let idx = self.leading_run(true);
+		if idx < self.len() { Some(idx) } else { None }
+	}
+
+	/// Finds the index of the first set bit at or after an index.
+	///
+	/// Use this to walk the occupied entries of a sparse bitmap: zeros before
+	/// the match are passed over a whole element at a time.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	/// - `from`: The index at which to begin searching.
+	///
+	/// # Returns
+	///
+	/// The index, counted from the front of `self`, of the first set bit in
+	/// `self[from ..]`. This is `None` if there is no such bit, including
+	/// when `from` is at or beyond the end of the slice.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let bits = [0x81u8, 0x10].bits::<BigEndian>();
+	/// assert_eq!(bits.first_set_after(0), Some(0));
+	/// assert_eq!(bits.first_set_after(1), Some(7));
+	/// assert_eq!(bits.first_set_after(8), Some(11));
+	/// assert!(bits.first_set_after(12).is_none());
+	/// ```
+	pub fn first_set_after(&self, from: usize) -> Option<usize> {
+		if from >= self.len() {
+			return None;
+		}
+		self[from ..].first_one().map(|idx| idx + from)
+	}
+
+	/// Finds the index of the first clear bit at or after an index.
+	///
+	/// This is the search step of a bitmap allocator. Occupied slots, which are
+	/// set, are skipped by scanning whole elements rather than testing each
+	/// bit, until a free slot is found.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	/// - `from`: The index at which to begin searching.
+	///
+	/// # Returns
+	///
+	/// The index, counted from the front of `self`, of the first clear bit in
+	/// `self[from ..]`. This is `None` if there is no such bit, including
+	/// when `from` is at or beyond the end of the slice.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let bits = [!0x81u8, !0x10].bits::<BigEndian>();
+	/// assert_eq!(bits.first_clear_after(0), Some(0));
+	/// assert_eq!(bits.first_clear_after(1), Some(7));
+	/// assert_eq!(bits.first_clear_after(8), Some(11));
+	/// assert!(bits.first_clear_after(12).is_none());
+	/// ```
+	pub fn first_clear_after(&self, from: usize) -> Option<usize> {
+		if from >= self.len() {
+			return None;
+		}
+		self[from ..].first_zero().map(|idx| idx + from)
+	}
+
+	/// Finds the partition point of a predicate over the slice.
+	///
+	/// The slice is assumed to be partitioned according to `pred`: every bit
+	/// for which it returns `true` precedes every bit for which it returns
+	/// `false`. The boundary is found by binary search, in `O(log n)` calls to
+	/// `pred`. If the slice is not partitioned, the result is unspecified but
+	/// is always in `0 ..= self.len()`.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	/// - `pred`: The predicate by which the slice is partitioned.
+	///
+	/// # Returns
+	///
+	/// The index of the first bit for which `pred` returns `false`, or
+	/// `self.len()` if there is no such bit.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let bits = 0b0001_1111u8.bits::<BigEndian>();
+	/// assert_eq!(bits.partition_point(|b| !b), 3);
+	/// assert_eq!(bits[.. 3].partition_point(|b| !b), 3);
+	/// assert_eq!(bits[3 ..].partition_point(|b| !b), 0);
+	/// ```
+	pub fn partition_point<F>(&self, mut pred: F) -> usize
+	where F: FnMut(bool) -> bool {
+		let (mut lo, mut hi) = (0, self.len());
+		while lo < hi {
+			let mid = lo + (hi - lo) / 2;
+			if pred(unsafe { self.get_unchecked(mid) }) {
+				lo = mid + 1;
+			}
+			else {
+				hi = mid;
+			}
 		}
+		lo
 	}
 
 	/// Set all bits in the slice to a value.
@@ -1659,28 +2979,28 @@ where C: Cursor, T: BitStore {
 	/// assert_eq!(bits.as_ref(), &[0b1010_0100]);
 	/// ```
 	pub fn set_all(&mut self, value: bool) {
+		//  Partial elements are written through a mask of their live bits, so
+		//  that bits outside the slice are never disturbed.
+		let write = |elt: &T::Nucleus, mask: T| if value {
+			elt.set_bits(mask);
+		}
+		else {
+			elt.clear_bits(mask);
+		};
 		match self.bitptr().domain_mut() {
 			BitDomainMut::Empty => {},
 			BitDomainMut::Minor(head, elt, tail) => {
-				for n in *head .. *tail {
-					elt.set::<C>(n.idx(), value);
-				}
+				write(elt, live_mask::<C, T>(*head, *tail));
 			},
 			BitDomainMut::Major(h, head, body, tail, t) => {
-				for n in *h .. T::BITS {
-					head.set::<C>(n.idx(), value);
-				}
+				write(head, live_mask::<C, T>(*h, T::BITS));
 				for elt in body {
 					*elt = T::bits(value);
 				}
-				for n in 0 .. *t {
-					tail.set::<C>(n.idx(), value);
-				}
+				write(tail, live_mask::<C, T>(0, *t));
 			},
 			BitDomainMut::PartialHead(h, head, body) => {
-				for n in *h .. T::BITS {
-					head.set::<C>(n.idx(), value);
-				}
+				write(head, live_mask::<C, T>(*h, T::BITS));
 				for elt in body {
 					*elt = T::bits(value);
 				}
@@ -1689,9 +3009,7 @@ where C: Cursor, T: BitStore {
 				for elt in body {
 					*elt = T::bits(value);
 				}
-				for n in 0 .. *t {
-					tail.set::<C>(n.idx(), value);
-				}
+				write(tail, live_mask::<C, T>(0, *t));
 			},
 			BitDomainMut::Spanning(body) => {
 				for elt in body {
@@ -1701,6 +3019,184 @@ where C: Cursor, T: BitStore {
 		}
 	}
 
+	/// Fills the slice with a value.
+	///
+	/// This is an alias of [`set_all`], named to match the standard library’s
+	/// slice method.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	/// - `value`: The bit value to which all bits in the slice will be set.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let mut src = [0u8, 0xFF];
+	/// src.bits_mut::<BigEndian>()[6 .. 10].fill(true);
+	/// src.bits_mut::<BigEndian>()[10 .. 12].fill(false);
+	/// assert_eq!(src, [0x03, 0xCF]);
+	/// ```
+	///
+	/// [`set_all`]: #method.set_all
+	pub fn fill(&mut self, value: bool) {
+		self.set_all(value);
+	}
+
+	/// Copies all bits from `src` into `self`.
+	///
+	/// When both slices begin at the same bit in their first element, the
+	/// elements they fully own are copied directly, and only their partial
+	/// edge elements are copied bit by bit. Otherwise, the whole slice is
+	/// copied bit by bit.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	/// - `src`: The slice from which to copy. It must have the same length as
+	///   `self`.
+	///
+	/// # Panics
+	///
+	/// This function panics if the two slices have different lengths.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let src = [0x3Cu8, 0xA5];
+	/// let mut dst = [0u8; 2];
+	/// {
+	///  let from = src.bits::<BigEndian>();
+	///  let to = dst.bits_mut::<BigEndian>();
+	///  to[2 .. 14].copy_from_slice(&from[2 .. 14]);
+	///  to[.. 2].copy_from_slice(&from[14 ..]);
+	/// }
+	/// assert_eq!(dst, [0x7C, 0xA4]);
+	/// ```
+	pub fn copy_from_slice(&mut self, src: &Self) {
+		let len = self.len();
+		assert_eq!(
+			len,
+			src.len(),
+			"Copying between slices requires equal lengths",
+		);
+		if self.bitptr().head() != src.bitptr().head() {
+			for (idx, bit) in src.iter().enumerate() {
+				unsafe { self.set_unchecked(idx, bit); }
+			}
+			return;
+		}
+		//  Equal heads and lengths produce domains of the same shape.
+		let copy_bits = |to: &T::Nucleus, from: &T::Nucleus, bits: Range<u8>| {
+			for n in bits {
+				to.set::<C>(n.idx(), from.get::<C>(n.idx()));
+			}
+		};
+		match (self.bitptr().domain_mut(), src.bitptr().domain()) {
+			(BitDomainMut::Empty, BitDomain::Empty) => {},
+			(
+				BitDomainMut::Minor(h, to, t),
+				BitDomain::Minor(_, from, _),
+			) => copy_bits(to, from, *h .. *t),
+			(
+				BitDomainMut::Major(h, to_head, to_body, to_tail, t),
+				BitDomain::Major(_, from_head, from_body, from_tail, _),
+			) => {
+				copy_bits(to_head, from_head, *h .. T::BITS);
+				to_body.copy_from_slice(from_body);
+				copy_bits(to_tail, from_tail, 0 .. *t);
+			},
+			(
+				BitDomainMut::PartialHead(h, to_head, to_body),
+				BitDomain::PartialHead(_, from_head, from_body),
+			) => {
+				copy_bits(to_head, from_head, *h .. T::BITS);
+				to_body.copy_from_slice(from_body);
+			},
+			(
+				BitDomainMut::PartialTail(to_body, to_tail, t),
+				BitDomain::PartialTail(from_body, from_tail, _),
+			) => {
+				to_body.copy_from_slice(from_body);
+				copy_bits(to_tail, from_tail, 0 .. *t);
+			},
+			(BitDomainMut::Spanning(to), BitDomain::Spanning(from)) => {
+				to.copy_from_slice(from);
+			},
+			_ => unreachable!("Slices of equal head and length have equal domains"),
+		}
+	}
+
+	/// Copies a range of bits to another position in the same slice.
+	///
+	/// The source and destination ranges may overlap; the bits are copied in
+	/// the direction that reads each source bit before it is overwritten. When
+	/// they do not overlap, the copy uses [`copy_from_slice`], and so moves
+	/// whole elements where it can.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	/// - `src`: The range of bits to copy.
+	/// - `dest`: The index at which the copy of `src` begins.
+	///
+	/// # Panics
+	///
+	/// This panics if `src` is not a valid range within the slice, or if the
+	/// destination range `dest .. dest + src.len()` extends past its end.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let mut src = 0b1100_0000u8;
+	/// let bits = src.bits_mut::<BigEndian>();
+	/// bits.copy_within(.. 4, 2);
+	/// assert_eq!(src, 0b1111_0000);
+	/// ```
+	///
+	/// [`copy_from_slice`]: #method.copy_from_slice
+	pub fn copy_within<R>(&mut self, src: R, dest: usize)
+	where R: RangeBounds<usize> {
+		let len = self.len();
+		let Range { start, end } = resolve_range(src, len);
+		let count = end - start;
+		assert!(
+			dest <= len - count,
+			"Destination {} .. {} out of range for slice of length {}",
+			dest,
+			dest.saturating_add(count),
+			len,
+		);
+		if end <= dest {
+			let (from, to) = self.split_at_mut(dest);
+			to[.. count].copy_from_slice(&from[start .. end]);
+		}
+		else if dest + count <= start {
+			let (to, from) = self.split_at_mut(start);
+			to[dest .. dest + count].copy_from_slice(&from[.. count]);
+		}
+		//  Overlapping copies toward the front read ahead of their writes, and
+		//  copies toward the back must run from the back.
+		else if dest < start {
+			for idx in 0 .. count {
+				let bit = self[start + idx];
+				unsafe { self.set_unchecked(dest + idx, bit); }
+			}
+		}
+		else {
+			for idx in (0 .. count).rev() {
+				let bit = self[start + idx];
+				unsafe { self.set_unchecked(dest + idx, bit); }
+			}
+		}
+	}
+
 	/// Provides mutable traversal of the collection.
 	///
 	/// It is impossible to implement `IndexMut` on `BitSlice`, because bits do
@@ -1939,6 +3435,11 @@ where C: Cursor, T: BitStore {
 	/// detriment in practice. If you need to view the underlying elements of a
 	/// `BitSlice`, you likely also do not have a region with partial elements.
 	///
+	/// This is the safe counterpart to `as_total_slice`. Every element in the
+	/// produced slice has all of its bits live in `self`, so the view never
+	/// reaches bits governed by another handle. A slice that fits within the
+	/// interior of one element produces an empty slice.
+	///
 	/// # Parameters
 	///
 	/// - `&self`
@@ -2025,6 +3526,9 @@ where C: Cursor, T: BitStore {
 	/// detriment in practice. If you need to view the underlying elements of a
 	/// `BitSlice`, you likely also do not have a region with partial elements.
 	///
+	/// As with `as_slice`, writes through the produced slice cannot disturb
+	/// bits belonging to other handles.
+	///
 	/// # Examples
 	///
 	/// ```rust
@@ -2077,6 +3581,48 @@ where C: Cursor, T: BitStore {
 		self.bitptr().as_mut_slice()
 	}
 
+	/// Describes how the slice occupies its underlying elements.
+	///
+	/// The domain separates the slice into a partially-occupied head element,
+	/// a body of fully-occupied elements, and a partially-occupied tail
+	/// element. Any of these regions may be absent. The edge elements are
+	/// provided as shared `BitAccess` references, since other handles may be
+	/// writing to their dead bits; the body is an ordinary element slice.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	///
+	/// # Returns
+	///
+	/// A `BitDomain` descriptor of the memory governed by `self`.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	/// use bitvec::domain::BitDomain;
+	/// use bitvec::store::BitAccess;
+	///
+	/// let src = [0x0Fu8, 0xA5, 0xF0];
+	/// let bits = &src.bits::<BigEndian>()[4 .. 20];
+	/// match bits.domain() {
+	///   BitDomain::Major(head, h, body, t, tail) => {
+	///     assert_eq!(*head, 4);
+	///     assert_eq!(BitAccess::load(h), 0x0F);
+	///     assert_eq!(body, &[0xA5]);
+	///     assert_eq!(BitAccess::load(t), 0xF0);
+	///     assert_eq!(*tail, 4);
+	///   },
+	///   _ => unreachable!(),
+	/// }
+	///
+	/// assert!(src.bits::<BigEndian>().domain().is_spanning());
+	/// ```
+	pub fn domain(&self) -> BitDomain<T> {
+		self.bitptr().domain()
+	}
+
 	/// Changes the cursor type of the slice handle.
 	///
 	/// # Parameters
@@ -2107,59 +3653,475 @@ where C: Cursor, T: BitStore {
 		self.bitptr().into_bitslice()
 	}
 
-	/// Changes the cursor type of the slice handle.
+	/// Changes the cursor type of the slice handle.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	///
+	/// # Returns
+	///
+	/// An equivalent slice handle with a new cursor type.
+	///
+	/// # Type Parameters
+	///
+	/// - `D` The new cursor type to use for the handle.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let mut src = 0u8;
+	/// *src.bits_mut::<BigEndian>().at(1) = true;
+	/// assert_eq!(src, 64);
+	/// src.bits_mut::<BigEndian>()
+	///    .change_cursor_mut::<LittleEndian>()
+	///    .set(1, true);
+	/// assert_eq!(src, 66);
+	/// ```
+	pub fn change_cursor_mut<D>(&mut self) -> &mut BitSlice<D, T>
+	where D: Cursor {
+		self.bitptr().into_bitslice_mut()
+	}
+
+	/// Splits the slice into a prefix, a middle of wider storage elements, and
+	/// a suffix.
+	///
+	/// This mirrors [`<[T]>::align_to`]: the middle is the largest run of
+	/// fully-owned elements which is correctly aligned for `U`, reinterpreted
+	/// as `U` elements, and the prefix and suffix are the remaining bits of
+	/// `self` on either side of it. The middle may be empty, in which case the
+	/// prefix is all of `self`.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	///
+	/// # Returns
+	///
+	/// - `.0`: The bits of `self` before the middle region.
+	/// - `.1`: The middle region, viewed with `U` storage elements.
+	/// - `.2`: The bits of `self` after the middle region.
+	///
+	/// # Type Parameters
+	///
+	/// - `U`: The storage type of the middle region.
+	///
+	/// # Safety
+	///
+	/// The middle region views the same memory as `self` through a different
+	/// element type. The cursor `C` orders the bits of a `U` element by their
+	/// position in `U`, not in `T`, so the bit sequence of the middle region is
+	/// only the same as the bits of `self` it covers when `C` and the target’s
+	/// byte order agree, such as `LittleEndian` on a little-endian target.
+	///
+	/// The caller must also not use the middle region alongside any other
+	/// handle to the same memory which has a different element type, as the
+	/// memory would then be accessed by atomic operations of different widths.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let src = [0u8; 20];
+	/// let bits = &src.bits::<LittleEndian>()[3 ..];
+	/// let (head, body, tail) = unsafe { bits.align_to::<u32>() };
+	/// assert_eq!(head.len() + body.len() + tail.len(), bits.len());
+	/// assert_eq!(body.len() % 32, 0);
+	/// ```
+	///
+	/// [`<[T]>::align_to`]: https://doc.rust-lang.org/stable/std/primitive.slice.html#method.align_to
+	pub unsafe fn align_to<U>(&self) -> (&Self, &BitSlice<C, U>, &Self)
+	where U: BitStore {
+		let bitptr = self.bitptr();
+		let head = *bitptr.head() as usize;
+		let bits = T::BITS as usize;
+		//  Only the elements which `self` fully owns can be reinterpreted.
+		let first = if head == 0 { 0 } else { 1 };
+		let last = (head + self.len()) / bits;
+		if first >= last {
+			return (self, BitSlice::empty(), BitSlice::empty());
+		}
+		let body = core::slice::from_raw_parts(
+			bitptr.pointer().r().add(first),
+			last - first,
+		);
+		let (pre, mid, _) = body.align_to::<U>();
+		if mid.is_empty() {
+			return (self, BitSlice::empty(), BitSlice::empty());
+		}
+		let start = (first + pre.len()) * bits - head;
+		let end = start + mid.len() * U::BITS as usize;
+		(&self[.. start], BitSlice::from_slice(mid), &self[end ..])
+	}
+
+	/// Unconditionally copies a bit from one index to another.
+	///
+	/// This is equivalent to `self[to] = self[from]`.
+	///
+	/// # Safety
+	///
+	/// This function performs no bounds checks. It must only be called from
+	/// within a checked context.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	/// - `from`: The index at which a bit will be unconditionally fetched.
+	/// - `to`: The index at which the fetched bit will be unconditionally set.
+	#[inline]
+	pub(crate) unsafe fn copy(&mut self, from: usize, to: usize) {
+		let bit = self.get_unchecked(from);
+		self.set_unchecked(to, bit);
+	}
+
+	/// Accesses the underlying pointer structure.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	///
+	/// # Returns
+	///
+	/// The [`BitPtr`] structure of the slice handle.
+	///
+	/// [`BitPtr`]: ../pointer/struct.BitPtr.html
+	pub(crate) fn bitptr(&self) -> BitPtr<T> {
+		BitPtr::from_bitslice(self)
+	}
+
+	/// Selects the region of an integer field, checking its bounds.
+	///
+	/// # Panics
+	///
+	/// This panics if `width` is greater than 64, or if the field extends past
+	/// the end of the slice.
+	fn field(&self, start: usize, width: usize) -> &Self {
+		assert!(width <= 64, "Fields cannot be wider than 64 bits: {}", width);
+		let len = self.len();
+		assert!(
+			start <= len && width <= len - start,
+			"Field {} .. {} out of range for slice of length {}",
+			start,
+			start.saturating_add(width),
+			len,
+		);
+		&self[start .. start + width]
+	}
+
+	/// Mutable form of `field`.
+	fn field_mut(&mut self, start: usize, width: usize) -> &mut Self {
+		self.field(start, width);
+		&mut self[start .. start + width]
+	}
+
+	/// Combines two slices bit by bit into a new vector, zero-extending the
+	/// shorter of the two to the length of the longer.
+	#[cfg(feature = "alloc")]
+	fn zip_extended<D, U, F>(
+		&self,
+		other: &BitSlice<D, U>,
+		func: F,
+	) -> BitVec<C, T>
+	where D: Cursor, U: BitStore, F: Fn(bool, bool) -> bool {
+		use core::iter;
+		let len = cmp::max(self.len(), other.len());
+		self.iter()
+			.chain(iter::repeat(false))
+			.zip(other.iter().chain(iter::repeat(false)))
+			.take(len)
+			.map(|(a, b)| func(a, b))
+			.collect()
+	}
+
+	/// Pairs up the elements of two slices whose bits occupy the same
+	/// positions in memory.
+	///
+	/// # Returns
+	///
+	/// If the slices have the same length and begin at the same index within
+	/// their first elements, an iterator over each pair of corresponding
+	/// elements, with their dead bits cleared. Otherwise, `None`.
+	fn zip_elements<'a>(
+		&'a self,
+		other: &'a Self,
+	) -> Option<impl Iterator<Item = (T, T)> + 'a> {
+		let aligned = *self.bitptr().head() == *other.bitptr().head();
+		if self.len() != other.len() || !aligned {
+			return None;
+		}
+		Some(self.live_elements().zip(other.live_elements()).map(
+			|((a, from, upto), (b, _, _))| {
+				let mask = if from == 0 && upto == T::BITS {
+					!T::from(0)
+				}
+				else {
+					live_mask::<C, T>(from, upto)
+				};
+				(a.load() & mask, b.load() & mask)
+			},
+		))
+	}
+
+	/// Combines another slice into this one, one element at a time.
 	///
 	/// # Parameters
 	///
 	/// - `&mut self`
+	/// - `other`: A slice of the same length and alignment as `self`.
+	/// - `func`: A function which receives each element of `self`, and the
+	///   live bits of that element and of the matching element of `other`, as
+	///   produced by `zip_elements`. Its writes must be masked by those live
+	///   values, so that it never touches the dead bits of `self`.
 	///
 	/// # Returns
 	///
-	/// An equivalent slice handle with a new cursor type.
-	///
-	/// # Type Parameters
+	/// `false` if the slices differ in length or alignment, in which case
+	/// `self` is unchanged; `true` once `func` has been applied to every
+	/// element.
+	fn zip_assign<F>(&mut self, other: &Self, func: F) -> bool
+	where F: Fn(&T::Nucleus, T, T) {
+		let pairs = match self.zip_elements(other) {
+			Some(pairs) => pairs,
+			None => return false,
+		};
+		for ((elt, _, _), (a, b)) in self.live_elements().zip(pairs) {
+			func(elt, a, b);
+		}
+		true
+	}
+
+	/// Renders the slice for debugging, under the name of a handle type.
 	///
-	/// - `D` The new cursor type to use for the handle.
+	/// # Parameters
 	///
-	/// # Examples
+	/// - `&self`
+	/// - `name`: The name of the handle type being printed, which is followed
+	///   by the cursor and storage type parameters.
+	/// - `f`: The formatter into which `self` is written.
 	///
-	/// ```rust
-	/// use bitvec::prelude::*;
+	/// # Returns
 	///
-	/// let mut src = 0u8;
-	/// *src.bits_mut::<BigEndian>().at(1) = true;
-	/// assert_eq!(src, 64);
-	/// src.bits_mut::<BigEndian>()
-	///    .change_cursor_mut::<LittleEndian>()
-	///    .set(1, true);
-	/// assert_eq!(src, 66);
-	/// ```
-	pub fn change_cursor_mut<D>(&mut self) -> &mut BitSlice<D, T>
-	where D: Cursor {
-		self.bitptr().into_bitslice_mut()
+	/// The result of the formatting operation.
+	pub(crate) fn fmt_debug(
+		&self,
+		name: &str,
+		f: &mut Formatter,
+	) -> fmt::Result {
+		/// Prints the live bits of a slice as its `Display` rendering.
+		struct Bits<'a, C, T>(&'a BitSlice<C, T>)
+		where C: Cursor, T: 'a + BitStore;
+		impl<'a, C, T> Debug for Bits<'a, C, T>
+		where C: Cursor, T: 'a + BitStore {
+			fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+				Display::fmt(self.0, f)
+			}
+		}
+
+		/// Prints each element of a slice, in full, in hexadecimal.
+		struct Store<'a, C, T>(&'a BitSlice<C, T>)
+		where C: Cursor, T: 'a + BitStore;
+		impl<'a, C, T> Debug for Store<'a, C, T>
+		where C: Cursor, T: 'a + BitStore {
+			fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+				struct Hex<T>(T);
+				impl<T> Debug for Hex<T>
+				where T: BitStore {
+					fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+						let width = 2 + T::BITS as usize / 4;
+						write!(f, "{:#0width$x}", self.0, width = width)
+					}
+				}
+				let elts = self.0.live_elements().map(|(e, _, _)| Hex(e.load()));
+				f.debug_list().entries(elts).finish()
+			}
+		}
+
+		f.write_str(name)?;
+		f.write_str("<")?;
+		f.write_str(C::TYPENAME)?;
+		f.write_str(", ")?;
+		f.write_str(T::TYPENAME)?;
+		f.write_str(">")?;
+		f.debug_struct("")
+			.field("len", &self.len())
+			.field("bits", &Bits(self))
+			.field("store", &Store(self))
+			.finish()
 	}
 
-	/// Unconditionally copies a bit from one index to another.
-	///
-	/// This is equivalent to `self[to] = self[from]`.
-	///
-	/// # Safety
-	///
-	/// This function performs no bounds checks. It must only be called from
-	/// within a checked context.
+	/// Asserts that the slice is not wider than an integer type.
+	fn check_width<U>(&self)
+	where U: BitStore {
+		assert!(
+			self.len() <= U::BITS as usize,
+			"Cannot transfer {} bits with a {}-bit integer",
+			self.len(),
+			U::BITS,
+		);
+	}
+
+	/// Produces each element of the slice, along with the range of its live
+	/// bits.
 	///
-	/// # Parameters
+	/// # Returns
 	///
-	/// - `&mut self`
-	/// - `from`: The index at which a bit will be unconditionally fetched.
-	/// - `to`: The index at which the fetched bit will be unconditionally set.
-	#[inline]
-	pub(crate) unsafe fn copy(&mut self, from: usize, to: usize) {
-		let bit = self.get_unchecked(from);
-		self.set_unchecked(to, bit);
+	/// An iterator over the elements of the slice, in memory order. Each item
+	/// is the element’s shared-mutability handle, and the semantic indices of
+	/// the first live bit and one past the last live bit in that element.
+	fn live_elements(&self) -> impl Iterator<Item = (&T::Nucleus, u8, u8)> {
+		let bitptr = self.bitptr();
+		let head = bitptr.head();
+		let (elts, tail) = head.span(self.len());
+		let data = bitptr.pointer().n();
+		(0 .. elts).map(move |idx| {
+			let from = if idx == 0 { *head } else { 0 };
+			let upto = if idx + 1 == elts { *tail } else { T::BITS };
+			(unsafe { &*data.add(idx) }, from, upto)
+		})
 	}
 
-	/// Accesses the underlying pointer structure.
+	/// Counts the bits equal to `bit` at the front of the slice.
+	///
+	/// Elements are inverted when counting `true` bits, so that the run being
+	/// measured is always a run of zeros. Dead bits in the partial elements are
+	/// masked to zero, and the count is clamped to the live region.
+	fn leading_run(&self, bit: bool) -> usize {
+		let flip = |elt: T| if bit { !elt } else { elt };
+		let bits = T::BITS as usize;
+		//  Counts a run through fully-owned elements, stopping inside the first
+		//  element that has a bit which ends the run.
+		let body_run = |body: &[T]| {
+			let mut count = 0;
+			for elt in body {
+				let run = C::leading_zeros(flip(*elt));
+				count += run;
+				if run < bits {
+					return (count, false);
+				}
+			}
+			(count, true)
+		};
+		match self.bitptr().domain() {
+			BitDomain::Empty => 0,
+			BitDomain::Minor(head, elt, tail) => {
+				let elt = flip(elt.load()) & live_mask::<C, T>(*head, *tail);
+				cmp::min(C::leading_zeros(elt), *tail as usize) - *head as usize
+			},
+			BitDomain::Major(h, head, body, tail, t) => {
+				let run = C::leading_zeros(
+					flip(head.load()) & live_mask::<C, T>(*h, T::BITS),
+				) - *h as usize;
+				if run < bits - *h as usize {
+					return run;
+				}
+				let (count, open) = body_run(body);
+				if !open {
+					return run + count;
+				}
+				let tail = flip(tail.load()) & live_mask::<C, T>(0, *t);
+				run + count + cmp::min(C::leading_zeros(tail), *t as usize)
+			},
+			BitDomain::PartialHead(h, head, body) => {
+				let run = C::leading_zeros(
+					flip(head.load()) & live_mask::<C, T>(*h, T::BITS),
+				) - *h as usize;
+				if run < bits - *h as usize {
+					return run;
+				}
+				run + body_run(body).0
+			},
+			BitDomain::PartialTail(body, tail, t) => {
+				let (count, open) = body_run(body);
+				if !open {
+					return count;
+				}
+				let tail = flip(tail.load()) & live_mask::<C, T>(0, *t);
+				count + cmp::min(C::leading_zeros(tail), *t as usize)
+			},
+			BitDomain::Spanning(body) => body_run(body).0,
+		}
+	}
+
+	/// Counts the bits equal to `bit` at the back of the slice.
+	///
+	/// This is the mirror of `leading_run`, and walks the domain from the tail
+	/// towards the head.
+	fn trailing_run(&self, bit: bool) -> usize {
+		let flip = |elt: T| if bit { !elt } else { elt };
+		let bits = T::BITS as usize;
+		let body_run = |body: &[T]| {
+			let mut count = 0;
+			for elt in body.iter().rev() {
+				let run = C::trailing_zeros(flip(*elt));
+				count += run;
+				if run < bits {
+					return (count, false);
+				}
+			}
+			(count, true)
+		};
+		match self.bitptr().domain() {
+			BitDomain::Empty => 0,
+			BitDomain::Minor(head, elt, tail) => {
+				let elt = flip(elt.load()) & live_mask::<C, T>(*head, *tail);
+				cmp::min(C::trailing_zeros(elt), bits - *head as usize)
+					- (bits - *tail as usize)
+			},
+			BitDomain::Major(h, head, body, tail, t) => {
+				let run = C::trailing_zeros(
+					flip(tail.load()) & live_mask::<C, T>(0, *t),
+				) - (bits - *t as usize);
+				if run < *t as usize {
+					return run;
+				}
+				let (count, open) = body_run(body);
+				if !open {
+					return run + count;
+				}
+				let head = flip(head.load()) & live_mask::<C, T>(*h, T::BITS);
+				run + count + cmp::min(C::trailing_zeros(head), bits - *h as usize)
+			},
+			BitDomain::PartialHead(h, head, body) => {
+				let (count, open) = body_run(body);
+				if !open {
+					return count;
+				}
+				let head = flip(head.load()) & live_mask::<C, T>(*h, T::BITS);
+				count + cmp::min(C::trailing_zeros(head), bits - *h as usize)
+			},
+			BitDomain::PartialTail(body, tail, t) => {
+				let run = C::trailing_zeros(
+					flip(tail.load()) & live_mask::<C, T>(0, *t),
+				) - (bits - *t as usize);
+				if run < *t as usize {
+					return run;
+				}
+				run + body_run(body).0
+			},
+			BitDomain::Spanning(body) => body_run(body).0,
+		}
+	}
+}
+
+/** Byte-oriented views.
+
+These are only available on slices which use `u8` as their storage element, and
+are the inverse of viewing a byte slice as bits with [`BitSlice::from_slice`].
+
+[`BitSlice::from_slice`]: #method.from_slice
+**/
+impl<C> BitSlice<C, u8>
+where C: Cursor {
+	/// Views a byte-aligned `BitSlice` as the bytes it governs.
+	///
+	/// Unlike [`as_slice`], which silently drops partially-owned edge
+	/// elements, this requires that the slice govern every bit of each byte it
+	/// touches. A `BitSlice` whose first bit is not at the start of a byte, or
+	/// whose last bit is not at the end of a byte, cannot be represented as a
+	/// byte slice, and is rejected.
 	///
 	/// # Parameters
 	///
@@ -2167,11 +4129,41 @@ where C: Cursor, T: BitStore {
 	///
 	/// # Returns
 	///
-	/// The [`BitPtr`] structure of the slice handle.
+	/// The bytes underlying `self`. This slice has exactly `self.len() / 8`
+	/// elements.
 	///
-	/// [`BitPtr`]: ../pointer/struct.BitPtr.html
-	pub(crate) fn bitptr(&self) -> BitPtr<T> {
-		BitPtr::from_bitslice(self)
+	/// # Panics
+	///
+	/// This panics if `self` has a partial head or tail byte.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let src = [0x3Cu8, 0xA5, 0x0F];
+	/// let bits = BitSlice::<BigEndian, u8>::from_slice(&src);
+	/// assert_eq!(bits.as_bytes(), &src);
+	/// assert_eq!(bits[8 .. 16].as_bytes(), &[0xA5]);
+	/// ```
+	///
+	/// This panics when the slice does not cover whole bytes.
+	///
+	/// ```rust,should_panic
+	/// use bitvec::prelude::*;
+	///
+	/// let src = [0x3Cu8, 0xA5];
+	/// let bits = BitSlice::<BigEndian, u8>::from_slice(&src);
+	/// bits[4 .. 12].as_bytes();
+	/// ```
+	///
+	/// [`as_slice`]: #method.as_slice
+	pub fn as_bytes(&self) -> &[u8] {
+		match self.bitptr().domain() {
+			BitDomain::Empty => &[],
+			BitDomain::Spanning(body) => body,
+			_ => panic!("BitSlice of length {} is not byte-aligned", self.len()),
+		}
 	}
 }
 
@@ -2445,6 +4437,37 @@ where C: Cursor, T: 'a + BitStore {
 	}
 }
 
+macro_rules! impl_try_from {
+	( $( $t:ty ),* ) => { $(
+/** Reads a `BitSlice` as an unsigned integer.
+
+The slice is interpreted with the same significance as `BitSlice::load_bits`:
+its first bit is the most significant bit of the integer, and its last bit is
+the least significant. A slice shorter than the integer is zero-extended; a
+slice longer than the integer cannot be converted.
+**/
+impl<'a, C, T> TryFrom<&'a BitSlice<C, T>> for $t
+where C: Cursor, T: 'a + BitStore {
+	type Error = &'static str;
+
+	fn try_from(src: &'a BitSlice<C, T>) -> Result<Self, Self::Error> {
+		if src.len() > mem::size_of::<$t>() * 8 {
+			Err(concat!(
+				"Attempted to convert a `BitSlice` longer than `",
+				stringify!($t),
+				"`",
+			))
+		}
+		else {
+			Ok(src.load_bits(0, src.len()) as $t)
+		}
+	}
+}
+	)* };
+}
+
+impl_try_from! { u8, u16, u32, u64 }
+
 impl<'a, C, T> Default for &'a BitSlice<C, T>
 where C: Cursor, T: 'a + BitStore {
 	fn default() -> Self {
@@ -2461,14 +4484,15 @@ where C: Cursor, T: 'a + BitStore {
 
 /** Prints the `BitSlice` for debugging.
 
-The output is of the form `BitSlice<C, T> [ELT, *]` where `<C, T>` is the cursor
-and element type, with square brackets on each end of the bits and all the
-elements of the array printed in binary. The printout is always in semantic
-order, and may not reflect the underlying buffer. To see the underlying buffer,
-use `.as_ref()`.
+The output is of the form
+`BitSlice<C, T> { len: LEN, bits: [ELT, *], store: [RAW, *] }`, where `<C, T>`
+is the cursor and element type. `bits` holds the live bits of each element,
+printed in binary in semantic order, and `store` holds the full value of each
+element in memory, printed in hexadecimal. Comparing the two shows where the
+slice begins and ends within its elements.
 
-The alternate character `{:#?}` prints each element on its own line, rather than
-having all elements on the same line.
+The alternate character `{:#?}` prints each field, and each element, on its own
+line.
 **/
 impl<C, T> Debug for BitSlice<C, T>
 where C: Cursor, T: BitStore {
@@ -2483,18 +4507,14 @@ where C: Cursor, T: BitStore {
 	/// let src = [0b0101_0000_1111_0101u16, 0b00000000_0000_0010];
 	/// let bits = &src.bits::<LittleEndian>()[.. 18];
 	/// assert_eq!(
-    ///     "BitSlice<LittleEndian, u16> [1010111100001010, 01]",
-	///     &format!("{:?}", bits),
+	///   &format!("{:?}", bits),
+	///   "BitSlice<LittleEndian, u16> { len: 18, \
+	///    bits: [1010111100001010, 01], store: [0x50f5, 0x0002] }",
 	/// );
 	/// # }
 	/// ```
 	fn fmt(&self, f: &mut Formatter) -> fmt::Result {
-		f.write_str("BitSlice<")?;
-		f.write_str(C::TYPENAME)?;
-		f.write_str(", ")?;
-		f.write_str(T::TYPENAME)?;
-		f.write_str("> ")?;
-		Display::fmt(self, f)
+		self.fmt_debug("BitSlice", f)
 	}
 }
 
@@ -2602,10 +4622,235 @@ where C: Cursor, T: BitStore {
 	}
 }
 
+/** Prints the `BitSlice` as a string of binary digits.
+
+The bits are printed in semantic order, with no separators between elements.
+The alternate flag `{:#b}` prefixes the digits with `0b`. Width, fill, and
+zero-padding flags apply to the whole output, as they do for integers.
+**/
+impl<C, T> Binary for BitSlice<C, T>
+where C: Cursor, T: BitStore {
+	/// Renders the `BitSlice` contents as binary digits.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// # #[cfg(feature = "alloc")] {
+	/// use bitvec::prelude::*;
+	///
+	/// let bits = &0b1011_0010u8.bits::<BigEndian>()[.. 6];
+	/// assert_eq!(format!("{:b}", bits), "101100");
+	/// assert_eq!(format!("{:#b}", bits), "0b101100");
+	/// # }
+	/// ```
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+		fmt_digits(self, f, 1, "0b", b"01")
+	}
+}
+
+/** Prints the `BitSlice` as a string of octal digits.
+
+Each digit is formed from three consecutive bits, starting at the front of the
+slice, with the first of the three as the most significant bit of the digit. If
+the length is not a multiple of three, the last digit is padded with zero bits.
+The alternate flag `{:#o}` prefixes the digits with `0o`. Width, fill, and
+zero-padding flags apply to the whole output, as they do for integers.
+**/
+impl<C, T> Octal for BitSlice<C, T>
+where C: Cursor, T: BitStore {
+	/// Renders the `BitSlice` contents as octal digits.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// # #[cfg(feature = "alloc")] {
+	/// use bitvec::prelude::*;
+	///
+	/// let bits = 0b101_110_01u8.bits::<BigEndian>();
+	/// assert_eq!(format!("{:o}", bits), "562");
+	/// assert_eq!(format!("{:#o}", &bits[.. 6]), "0o56");
+	/// # }
+	/// ```
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+		fmt_digits(self, f, 3, "0o", b"01234567")
+	}
+}
+
+/** Prints the `BitSlice` as a string of lowercase hexadecimal digits.
+
+Each digit is formed from four consecutive bits, starting at the front of the
+slice, with the first of the four as the most significant bit of the digit. If
+the length is not a multiple of four, the last digit is padded with zero bits.
+The alternate flag `{:#x}` prefixes the digits with `0x`. Width, fill, and
+zero-padding flags apply to the whole output, as they do for integers.
+**/
+impl<C, T> LowerHex for BitSlice<C, T>
+where C: Cursor, T: BitStore {
+	/// Renders the `BitSlice` contents as lowercase hexadecimal digits.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// # #[cfg(feature = "alloc")] {
+	/// use bitvec::prelude::*;
+	///
+	/// let bits = 0xB1u8.bits::<BigEndian>();
+	/// assert_eq!(format!("{:x}", bits), "b1");
+	/// assert_eq!(format!("{:#x}", &bits[.. 6]), "0xb0");
+	/// # }
+	/// ```
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+		fmt_digits(self, f, 4, "0x", b"0123456789abcdef")
+	}
+}
+
+/** Prints the `BitSlice` as a string of uppercase hexadecimal digits.
+
+This is the same as the `LowerHex` rendering, with uppercase digits.
+**/
+impl<C, T> UpperHex for BitSlice<C, T>
+where C: Cursor, T: BitStore {
+	/// Renders the `BitSlice` contents as uppercase hexadecimal digits.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// # #[cfg(feature = "alloc")] {
+	/// use bitvec::prelude::*;
+	///
+	/// let bits = 0xB1u8.bits::<LittleEndian>();
+	/// assert_eq!(format!("{:X}", bits), "8D");
+	/// assert_eq!(format!("{:#X}", bits), "0x8D");
+	/// # }
+	/// ```
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+		fmt_digits(self, f, 4, "0x", b"0123456789ABCDEF")
+	}
+}
+
+/** Prints a `BitSlice` as binary digits, with a separator between groups.
+
+This is produced by [`BitSlice::display_grouped`]. The groups are written as
+bare digits, so the formatter flags, such as `{:#}`, are not applied to them.
+
+[`BitSlice::display_grouped`]: struct.BitSlice.html#method.display_grouped
+**/
+struct Grouped<'a, C, T>
+where C: Cursor, T: 'a + BitStore {
+	/// The slice to print.
+	bits: &'a BitSlice<C, T>,
+	/// The number of bits between separators.
+	group: usize,
+	/// The separator written between groups.
+	sep: char,
+}
+
+impl<'a, C, T> Display for Grouped<'a, C, T>
+where C: Cursor, T: 'a + BitStore {
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+		let mut sep = [0u8; 4];
+		let sep: &str = self.sep.encode_utf8(&mut sep);
+		for (n, group) in self.bits.chunks(self.group).enumerate() {
+			if n > 0 {
+				f.write_str(sep)?;
+			}
+			write_digits(group, f, 1, b"01")?;
+		}
+		Ok(())
+	}
+}
+
+/// Writes a bit slice into a formatter as a string of digits.
+///
+/// As with the integer formatters, the width, fill, alignment, and zero-pad
+/// flags of the formatter apply to the whole string, including the prefix.
+/// Zero padding is placed between the prefix and the digits, and other padding
+/// defaults to right alignment.
+///
+/// # Parameters
+///
+/// - `bits`: The slice to print.
+/// - `f`: The formatter into which the digits are written.
+/// - `width`: The number of bits which make up each digit. The first bit of
+///   each group is the most significant bit of its digit, and a short final
+///   group is padded with zero bits.
+/// - `prefix`: Text written before the digits when the alternate flag is set.
+/// - `alphabet`: The digit characters, indexed by value.
+///
+/// # Returns
+///
+/// The result of the formatting operation.
+fn fmt_digits<C, T>(
+	bits: &BitSlice<C, T>,
+	f: &mut Formatter,
+	width: usize,
+	prefix: &str,
+	alphabet: &[u8],
+) -> fmt::Result
+where C: Cursor, T: BitStore {
+	let prefix = if f.alternate() { prefix } else { "" };
+	let digits = (bits.len() + width - 1) / width;
+	let pad = f.width().map_or(0, |w| w.saturating_sub(prefix.len() + digits));
+	if f.sign_aware_zero_pad() {
+		f.write_str(prefix)?;
+		write_fill(f, '0', pad)?;
+		return write_digits(bits, f, width, alphabet);
+	}
+	let (before, after) = match f.align() {
+		Some(Alignment::Left) => (0, pad),
+		Some(Alignment::Center) => (pad / 2, pad - pad / 2),
+		_ => (pad, 0),
+	};
+	let fill = f.fill();
+	write_fill(f, fill, before)?;
+	f.write_str(prefix)?;
+	write_digits(bits, f, width, alphabet)?;
+	write_fill(f, fill, after)
+}
+
+/// Writes the digits of a bit slice, with no prefix or padding.
+///
+/// The parameters have the same meaning as in `fmt_digits`.
+fn write_digits<C, T>(
+	bits: &BitSlice<C, T>,
+	f: &mut Formatter,
+	width: usize,
+	alphabet: &[u8],
+) -> fmt::Result
+where C: Cursor, T: BitStore {
+	//  Digits are staged in a fixed buffer, rather than a dynamic string, so
+	//  that the formatter is usable in `#![no_std]` contexts.
+	let mut buf = [0u8; 64];
+	let mut len = 0;
+	for group in bits.chunks(width) {
+		let val = group.iter().fold(0, |val, bit| val << 1 | bit as usize);
+		buf[len] = alphabet[val << (width - group.len())];
+		len += 1;
+		if len == buf.len() {
+			f.write_str(unsafe { str::from_utf8_unchecked(&buf[..]) })?;
+			len = 0;
+		}
+	}
+	f.write_str(unsafe { str::from_utf8_unchecked(&buf[.. len]) })
+}
+
+/// Writes `count` copies of a fill character.
+fn write_fill(f: &mut Formatter, fill: char, count: usize) -> fmt::Result {
+	let mut buf = [0u8; 4];
+	let fill: &str = fill.encode_utf8(&mut buf);
+	(0 .. count).try_for_each(|_| f.write_str(fill))
+}
+
 /// Writes the contents of the `BitSlice`, in semantic bit order, into a hasher.
 impl<C, T> Hash for BitSlice<C, T>
 where C: Cursor, T: BitStore {
-	/// Writes each bit of the `BitSlice`, as a full `bool`, into the hasher.
+	/// Writes the length of the `BitSlice`, then each bit, as a full `bool`,
+	/// into the hasher.
+	///
+	/// Only the semantic bit sequence is hashed, so slices which compare equal
+	/// hash equally regardless of their cursor or storage types, and dead bits
+	/// in their edge elements are never observed. The length prefix keeps
+	/// adjacent slices in a compound key from colliding with each other.
 	///
 	/// # Parameters
 	///
@@ -2618,6 +4863,7 @@ where C: Cursor, T: BitStore {
 	///   `self`.
 	fn hash<H>(&self, hasher: &mut H)
 	where H: Hasher {
+		hasher.write_usize(self.len());
 		for bit in self {
 			hasher.write_u8(bit as u8);
 		}
@@ -2733,6 +4979,137 @@ where C: Cursor, T: 'a + BitStore {
 unsafe impl<'a, C, T> Send for BitGuard<'a, C, T>
 where C: Cursor, T: 'a + BitStore {}
 
+/// Builds a mask of the live bits in a partially-occupied element.
+///
+/// # Parameters
+///
+/// - `from`: The semantic index of the first live bit.
+/// - `upto`: The semantic index one past the last live bit.
+///
+/// # Returns
+///
+/// An element with all bits in the semantic range `from .. upto` set high, and
+/// all other bits set low.
+pub(crate) fn live_mask<C, T>(from: u8, upto: u8) -> T
+where C: Cursor, T: BitStore {
+	(from .. upto).fold(T::from(0), |mut mask, n| {
+		mask |= *C::mask::<T>(n.idx());
+		mask
+	})
+}
+
+/// Converts a range of any form into a concrete range within a length.
+///
+/// # Parameters
+///
+/// - `range`: Any range over indices.
+/// - `len`: The length of the sequence into which `range` indexes.
+///
+/// # Returns
+///
+/// The half-open range `start .. end` that `range` describes.
+///
+/// # Panics
+///
+/// This panics if the range begins after it ends, or ends after `len`.
+pub(crate) fn resolve_range<R>(range: R, len: usize) -> Range<usize>
+where R: RangeBounds<usize> {
+	let start = match range.start_bound() {
+		Bound::Included(&n) => n,
+		Bound::Excluded(&n) => n.checked_add(1).expect("Range start overflow"),
+		Bound::Unbounded => 0,
+	};
+	let end = match range.end_bound() {
+		Bound::Included(&n) => n.checked_add(1).expect("Range end overflow"),
+		Bound::Excluded(&n) => n,
+		Bound::Unbounded => len,
+	};
+	assert!(start <= end, "Range start {} exceeds its end {}", start, end);
+	assert!(end <= len, "Range end {} exceeds the length {}", end, len);
+	start .. end
+}
+
+/// Gathers the bits of an element selected by a mask into an integer.
+///
+/// The selected bits keep their relative order, and are packed together at the
+/// least significant end of the output.
+fn extract<T>(elt: T, mask: T) -> u64
+where T: BitStore {
+	let (elt, mask): (u64, u64) = (elt.into(), mask.into());
+	if mask == 0 {
+		return 0;
+	}
+	//  The `BigEndian` and `LittleEndian` cursors always produce contiguous
+	//  masks, which only need a shift.
+	let low = mask.trailing_zeros();
+	let run = mask >> low;
+	if run & run.wrapping_add(1) == 0 {
+		return (elt & mask) >> low;
+	}
+	let (mut out, mut shift) = (0, 0);
+	for place in 0 .. T::BITS {
+		if mask >> place & 1 == 1 {
+			out |= (elt >> place & 1) << shift;
+			shift += 1;
+		}
+	}
+	out
+}
+
+/// Scatters the low bits of an integer into the positions selected by a mask.
+///
+/// This is the inverse of `extract`. Bits of the output outside `mask` are
+/// zero.
+fn deposit<T>(value: u64, mask: T) -> T
+where T: BitStore {
+	let mask64: u64 = mask.into();
+	if mask64 == 0 {
+		return T::from(0);
+	}
+	let low = mask64.trailing_zeros();
+	let run = mask64 >> low;
+	if run & run.wrapping_add(1) == 0 {
+		return T::from_u64(value << low) & mask;
+	}
+	let (mut out, mut shift) = (0u64, 0);
+	for place in 0 .. T::BITS {
+		if mask64 >> place & 1 == 1 {
+			out |= (value >> shift & 1) << place;
+			shift += 1;
+		}
+	}
+	T::from_u64(out)
+}
+
+/// Reverses the semantic order of the bits in an element.
+///
+/// # Parameters
+///
+/// - `elt`: The element to reverse.
+///
+/// # Returns
+///
+/// An element whose bit at each semantic index `n` is the bit of `elt` at the
+/// semantic index `T::MASK - n`.
+fn reverse_element<C, T>(elt: T) -> T
+where C: Cursor, T: BitStore {
+	//  Cursors whose positions mirror about the center of the element, such as
+	//  `BigEndian` and `LittleEndian`, reverse semantic order exactly when the
+	//  electrical order is reversed.
+	let mirrored = (0 .. T::BITS).all(|n| {
+		*C::at::<T>(n.idx()) + *C::at::<T>((T::MASK - n).idx()) == T::MASK
+	});
+	if mirrored {
+		return elt.reverse_bits();
+	}
+	(0 .. T::BITS)
+		.filter(|&n| elt.get::<C>(n.idx()))
+		.fold(T::from(0), |mut out, n| {
+			out |= *C::mask::<T>((T::MASK - n).idx());
+			out
+		})
+}
+
 mod iter;
 mod ops;
 
@@ -16,6 +16,11 @@ use crate::{
 
 use core::{
 	cmp,
+	fmt::{
+		self,
+		Debug,
+		Formatter,
+	},
 	iter::FusedIterator,
 	mem,
 };
@@ -66,6 +71,267 @@ where C: Cursor, T: BitStore {
 		}
 	}
 
+	/// Enumerates the indices of all set bits in the slice.
+	///
+	/// The iterator skips over runs of clear bits an element at a time, rather
+	/// than testing each bit in turn.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	///
+	/// # Returns
+	///
+	/// An iterator which yields the index of each set bit in the slice, in
+	/// ascending order from the front and descending order from the back.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let bits = 0b0100_1100u8.bits::<BigEndian>();
+	/// let mut ones = bits.iter_ones();
+	/// assert_eq!(ones.next(), Some(1));
+	/// assert_eq!(ones.next_back(), Some(5));
+	/// assert_eq!(ones.next(), Some(4));
+	/// assert!(ones.next().is_none());
+	/// ```
+	pub fn iter_ones(&self) -> IterOnes<C, T> {
+		IterOnes {
+			inner: self,
+			offset: 0,
+		}
+	}
+
+	/// Enumerates the indices of all clear bits in the slice.
+	///
+	/// The iterator skips over runs of set bits an element at a time, rather
+	/// than testing each bit in turn.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	///
+	/// # Returns
+	///
+	/// An iterator which yields the index of each clear bit in the slice, in
+	/// ascending order from the front and descending order from the back.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let bits = 0b1011_0011u8.bits::<BigEndian>();
+	/// let mut zeros = bits.iter_zeros();
+	/// assert_eq!(zeros.next(), Some(1));
+	/// assert_eq!(zeros.next_back(), Some(5));
+	/// assert_eq!(zeros.next(), Some(4));
+	/// assert!(zeros.next().is_none());
+	/// ```
+	pub fn iter_zeros(&self) -> IterZeros<C, T> {
+		IterZeros {
+			inner: self,
+			offset: 0,
+		}
+	}
+
+	/// Enumerates the maximal runs of equal bits in the slice.
+	///
+	/// This is a run-length encoding of the slice. Each run is measured an
+	/// element at a time, rather than by testing each bit in turn.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	///
+	/// # Returns
+	///
+	/// An iterator which yields, for each run, the value of its bits and the
+	/// number of bits in it. Adjacent runs always have different values, and
+	/// the run lengths sum to `self.len()`.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let bv = bitvec![1, 1, 1, 0, 0, 1];
+	/// let mut runs = bv.runs();
+	/// assert_eq!(runs.next(), Some((true, 3)));
+	/// assert_eq!(runs.next(), Some((false, 2)));
+	/// assert_eq!(runs.next(), Some((true, 1)));
+	/// assert!(runs.next().is_none());
+	/// ```
+	pub fn runs(&self) -> Runs<C, T> {
+		Runs {
+			inner: self,
+		}
+	}
+
+	/// Divides the slice into subslices separated by bits that match a
+	/// predicate.
+	///
+	/// The matched bits are not contained in the subslices. As with the
+	/// standard library’s slice `split`, a matched bit at the front or back of
+	/// the slice produces an empty subslice at that end, and adjacent matched
+	/// bits produce an empty subslice between them.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	/// - `pred`: A function which receives each bit, and returns `true` if it
+	///   is a separator.
+	///
+	/// # Returns
+	///
+	/// An iterator over the subslices between separators.
+	///
+	/// # Type Parameters
+	///
+	/// - `F: FnMut(bool) -> bool`: The separator test.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let bv = bitvec![0, 0, 1, 0, 1, 0, 0];
+	/// let mut split = bv.split(|bit| bit);
+	/// assert_eq!(split.next().unwrap(), &bitvec![0, 0]);
+	/// assert_eq!(split.next().unwrap(), &bitvec![0]);
+	/// assert_eq!(split.next().unwrap(), &bitvec![0, 0]);
+	/// assert!(split.next().is_none());
+	/// ```
+	pub fn split<F>(&self, pred: F) -> Split<C, T, F>
+	where F: FnMut(bool) -> bool {
+		Split {
+			inner: self,
+			pred,
+			done: false,
+		}
+	}
+
+	/// Divides the slice into subslices separated by bits that match a
+	/// predicate, starting from the back of the slice.
+	///
+	/// This produces the same subslices as [`split`], in reverse order.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	/// - `pred`: A function which receives each bit, and returns `true` if it
+	///   is a separator.
+	///
+	/// # Returns
+	///
+	/// An iterator over the subslices between separators, from the back.
+	///
+	/// # Type Parameters
+	///
+	/// - `F: FnMut(bool) -> bool`: The separator test.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let bv = bitvec![0, 1, 0, 0];
+	/// let mut rsplit = bv.rsplit(|bit| bit);
+	/// assert_eq!(rsplit.next().unwrap(), &bitvec![0, 0]);
+	/// assert_eq!(rsplit.next().unwrap(), &bitvec![0]);
+	/// assert!(rsplit.next().is_none());
+	/// ```
+	///
+	/// [`split`]: #method.split
+	pub fn rsplit<F>(&self, pred: F) -> RSplit<C, T, F>
+	where F: FnMut(bool) -> bool {
+		RSplit {
+			inner: self.split(pred),
+		}
+	}
+
+	/// Divides the slice into at most `n` subslices separated by bits that
+	/// match a predicate.
+	///
+	/// Once `n - 1` subslices have been produced, the rest of the slice is
+	/// produced as the last subslice, without being searched for separators.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	/// - `n`: The maximum number of subslices to produce.
+	/// - `pred`: A function which receives each bit, and returns `true` if it
+	///   is a separator.
+	///
+	/// # Returns
+	///
+	/// An iterator over at most `n` subslices.
+	///
+	/// # Type Parameters
+	///
+	/// - `F: FnMut(bool) -> bool`: The separator test.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let bv = bitvec![0, 1, 0, 1, 0];
+	/// let mut splitn = bv.splitn(2, |bit| bit);
+	/// assert_eq!(splitn.next().unwrap(), &bitvec![0]);
+	/// assert_eq!(splitn.next().unwrap(), &bitvec![0, 1, 0]);
+	/// assert!(splitn.next().is_none());
+	/// ```
+	pub fn splitn<F>(&self, n: usize, pred: F) -> SplitN<C, T, F>
+	where F: FnMut(bool) -> bool {
+		SplitN {
+			inner: self.split(pred),
+			count: n,
+		}
+	}
+
+	/// Divides the slice into at most `n` subslices separated by bits that
+	/// match a predicate, starting from the back of the slice.
+	///
+	/// Once `n - 1` subslices have been produced, the rest of the slice is
+	/// produced as the last subslice, without being searched for separators.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	/// - `n`: The maximum number of subslices to produce.
+	/// - `pred`: A function which receives each bit, and returns `true` if it
+	///   is a separator.
+	///
+	/// # Returns
+	///
+	/// An iterator over at most `n` subslices, from the back.
+	///
+	/// # Type Parameters
+	///
+	/// - `F: FnMut(bool) -> bool`: The separator test.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let bv = bitvec![0, 1, 0, 1, 0];
+	/// let mut rsplitn = bv.rsplitn(2, |bit| bit);
+	/// assert_eq!(rsplitn.next().unwrap(), &bitvec![0]);
+	/// assert_eq!(rsplitn.next().unwrap(), &bitvec![0, 1, 0]);
+	/// assert!(rsplitn.next().is_none());
+	/// ```
+	pub fn rsplitn<F>(&self, n: usize, pred: F) -> RSplitN<C, T, F>
+	where F: FnMut(bool) -> bool {
+		RSplitN {
+			inner: self.split(pred),
+			count: n,
+		}
+	}
+
 	/// Produces a sliding iterator over consecutive windows in the slice. Each
 	/// windows has the width `size`. The windows overlap. If the slice is
 	/// shorter than `size`, the produced iterator is empty.
@@ -1050,6 +1316,146 @@ where C: Cursor, T: 'a + BitStore {
 	}
 }
 
+/** State keeper for iteration over the indices of set bits in a `BitSlice`.
+
+# Type Parameters
+
+- `C`: The bit-order type of the underlying `BitSlice`.
+- `T`: The storage type of the underlying `BitSlice`.
+
+# Lifetimes
+
+- `'a`: The lifetime of the underlying `BitSlice`.
+**/
+#[derive(Clone, Debug)]
+pub struct IterOnes<'a, C, T>
+where C: Cursor, T: 'a + BitStore {
+	/// The remaining `BitSlice` to be searched.
+	inner: &'a BitSlice<C, T>,
+	/// The index in the original slice of the front of `inner`.
+	offset: usize,
+}
+
+impl<'a, C, T> DoubleEndedIterator for IterOnes<'a, C, T>
+where C: Cursor, T: 'a + BitStore {
+	fn next_back(&mut self) -> Option<Self::Item> {
+		let len = self.inner.len();
+		let run = self.inner.trailing_zeros();
+		if run == len {
+			self.inner = BitSlice::empty();
+			return None;
+		}
+		let idx = len - run - 1;
+		self.inner = &self.inner[.. idx];
+		Some(self.offset + idx)
+	}
+}
+
+impl<'a, C, T> FusedIterator for IterOnes<'a, C, T>
+where C: Cursor, T: 'a + BitStore {}
+
+impl<'a, C, T> Iterator for IterOnes<'a, C, T>
+where C: Cursor, T: 'a + BitStore {
+	type Item = usize;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		match self.inner.first_one() {
+			Some(idx) => {
+				self.inner = &self.inner[idx + 1 ..];
+				let out = self.offset + idx;
+				self.offset = out + 1;
+				Some(out)
+			},
+			None => {
+				self.inner = BitSlice::empty();
+				None
+			},
+		}
+	}
+
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		(0, Some(self.inner.len()))
+	}
+
+	fn count(self) -> usize {
+		self.inner.count_ones()
+	}
+
+	fn last(mut self) -> Option<Self::Item> {
+		self.next_back()
+	}
+}
+
+/** State keeper for iteration over the indices of clear bits in a `BitSlice`.
+
+# Type Parameters
+
+- `C`: The bit-order type of the underlying `BitSlice`.
+- `T`: The storage type of the underlying `BitSlice`.
+
+# Lifetimes
+
+- `'a`: The lifetime of the underlying `BitSlice`.
+**/
+#[derive(Clone, Debug)]
+pub struct IterZeros<'a, C, T>
+where C: Cursor, T: 'a + BitStore {
+	/// The remaining `BitSlice` to be searched.
+	inner: &'a BitSlice<C, T>,
+	/// The index in the original slice of the front of `inner`.
+	offset: usize,
+}
+
+impl<'a, C, T> DoubleEndedIterator for IterZeros<'a, C, T>
+where C: Cursor, T: 'a + BitStore {
+	fn next_back(&mut self) -> Option<Self::Item> {
+		let len = self.inner.len();
+		let run = self.inner.trailing_ones();
+		if run == len {
+			self.inner = BitSlice::empty();
+			return None;
+		}
+		let idx = len - run - 1;
+		self.inner = &self.inner[.. idx];
+		Some(self.offset + idx)
+	}
+}
+
+impl<'a, C, T> FusedIterator for IterZeros<'a, C, T>
+where C: Cursor, T: 'a + BitStore {}
+
+impl<'a, C, T> Iterator for IterZeros<'a, C, T>
+where C: Cursor, T: 'a + BitStore {
+	type Item = usize;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		match self.inner.first_zero() {
+			Some(idx) => {
+				self.inner = &self.inner[idx + 1 ..];
+				let out = self.offset + idx;
+				self.offset = out + 1;
+				Some(out)
+			},
+			None => {
+				self.inner = BitSlice::empty();
+				None
+			},
+		}
+	}
+
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		(0, Some(self.inner.len()))
+	}
+
+	fn count(self) -> usize {
+		self.inner.count_zeros()
+	}
+
+	fn last(mut self) -> Option<Self::Item> {
+		self.next_back()
+	}
+}
+
 /** State keeper for reverse chunked iteration over a `BitSlice`.
 
 # Type Parameters
@@ -1446,6 +1852,334 @@ where C: Cursor, T: 'a + BitStore {
 	}
 }
 
+/** State keeper for iteration over the subslices of a `BitSlice` separated by
+bits that match a predicate, from the back.
+
+# Type Parameters
+
+- `C`: The bit-order type of the underlying `BitSlice`.
+- `T`: The storage type of the underlying `BitSlice`.
+- `F`: The predicate which selects separator bits.
+
+# Lifetimes
+
+- `'a`: The lifetime of the underlying `BitSlice`.
+**/
+#[derive(Clone)]
+pub struct RSplit<'a, C, T, F>
+where C: Cursor, T: 'a + BitStore, F: FnMut(bool) -> bool {
+	/// The forward splitter, driven from its back.
+	inner: Split<'a, C, T, F>,
+}
+
+impl<'a, C, T, F> Debug for RSplit<'a, C, T, F>
+where C: Cursor, T: 'a + BitStore, F: FnMut(bool) -> bool {
+	fn fmt(&self, fmt: &mut Formatter) -> fmt::Result {
+		fmt.debug_struct("RSplit")
+			.field("inner", &self.inner)
+			.finish()
+	}
+}
+
+impl<'a, C, T, F> DoubleEndedIterator for RSplit<'a, C, T, F>
+where C: Cursor, T: 'a + BitStore, F: FnMut(bool) -> bool {
+	fn next_back(&mut self) -> Option<Self::Item> {
+		self.inner.next()
+	}
+}
+
+impl<'a, C, T, F> FusedIterator for RSplit<'a, C, T, F>
+where C: Cursor, T: 'a + BitStore, F: FnMut(bool) -> bool {}
+
+impl<'a, C, T, F> Iterator for RSplit<'a, C, T, F>
+where C: Cursor, T: 'a + BitStore, F: FnMut(bool) -> bool {
+	type Item = &'a BitSlice<C, T>;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		self.inner.next_back()
+	}
+
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		self.inner.size_hint()
+	}
+}
+
+/** State keeper for iteration over at most `n` subslices of a `BitSlice`
+separated by bits that match a predicate, from the back.
+
+# Type Parameters
+
+- `C`: The bit-order type of the underlying `BitSlice`.
+- `T`: The storage type of the underlying `BitSlice`.
+- `F`: The predicate which selects separator bits.
+
+# Lifetimes
+
+- `'a`: The lifetime of the underlying `BitSlice`.
+**/
+#[derive(Clone)]
+pub struct RSplitN<'a, C, T, F>
+where C: Cursor, T: 'a + BitStore, F: FnMut(bool) -> bool {
+	/// The forward splitter, driven from its back.
+	inner: Split<'a, C, T, F>,
+	/// The number of subslices remaining to be produced.
+	count: usize,
+}
+
+impl<'a, C, T, F> Debug for RSplitN<'a, C, T, F>
+where C: Cursor, T: 'a + BitStore, F: FnMut(bool) -> bool {
+	fn fmt(&self, fmt: &mut Formatter) -> fmt::Result {
+		fmt.debug_struct("RSplitN")
+			.field("inner", &self.inner)
+			.field("count", &self.count)
+			.finish()
+	}
+}
+
+impl<'a, C, T, F> FusedIterator for RSplitN<'a, C, T, F>
+where C: Cursor, T: 'a + BitStore, F: FnMut(bool) -> bool {}
+
+impl<'a, C, T, F> Iterator for RSplitN<'a, C, T, F>
+where C: Cursor, T: 'a + BitStore, F: FnMut(bool) -> bool {
+	type Item = &'a BitSlice<C, T>;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		match self.count {
+			0 => None,
+			1 => {
+				self.count = 0;
+				self.inner.finish()
+			},
+			_ => {
+				self.count -= 1;
+				self.inner.next_back()
+			},
+		}
+	}
+
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		let (lo, hi) = self.inner.size_hint();
+		(cmp::min(lo, self.count), hi.map(|hi| cmp::min(hi, self.count)))
+	}
+}
+
+/** State keeper for iteration over the runs of equal bits in a `BitSlice`.
+
+# Type Parameters
+
+- `C`: The bit-order type of the underlying `BitSlice`.
+- `T`: The storage type of the underlying `BitSlice`.
+
+# Lifetimes
+
+- `'a`: The lifetime of the underlying `BitSlice`.
+**/
+#[derive(Clone, Debug)]
+pub struct Runs<'a, C, T>
+where C: Cursor, T: 'a + BitStore {
+	/// The remaining `BitSlice` to be measured.
+	inner: &'a BitSlice<C, T>,
+}
+
+impl<'a, C, T> DoubleEndedIterator for Runs<'a, C, T>
+where C: Cursor, T: 'a + BitStore {
+	fn next_back(&mut self) -> Option<Self::Item> {
+		let bit = self.inner.last()?;
+		let run = if bit {
+			self.inner.trailing_ones()
+		}
+		else {
+			self.inner.trailing_zeros()
+		};
+		self.inner = &self.inner[.. self.inner.len() - run];
+		Some((bit, run))
+	}
+}
+
+impl<'a, C, T> FusedIterator for Runs<'a, C, T>
+where C: Cursor, T: 'a + BitStore {}
+
+impl<'a, C, T> Iterator for Runs<'a, C, T>
+where C: Cursor, T: 'a + BitStore {
+	type Item = (bool, usize);
+
+	fn next(&mut self) -> Option<Self::Item> {
+		let bit = self.inner.first()?;
+		let run = if bit {
+			self.inner.leading_ones()
+		}
+		else {
+			self.inner.leading_zeros()
+		};
+		self.inner = &self.inner[run ..];
+		Some((bit, run))
+	}
+
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		let len = self.inner.len();
+		(cmp::min(len, 1), Some(len))
+	}
+
+	fn last(mut self) -> Option<Self::Item> {
+		self.next_back()
+	}
+}
+
+/** State keeper for iteration over the subslices of a `BitSlice` separated by
+bits that match a predicate.
+
+# Type Parameters
+
+- `C`: The bit-order type of the underlying `BitSlice`.
+- `T`: The storage type of the underlying `BitSlice`.
+- `F`: The predicate which selects separator bits.
+
+# Lifetimes
+
+- `'a`: The lifetime of the underlying `BitSlice`.
+**/
+#[derive(Clone)]
+pub struct Split<'a, C, T, F>
+where C: Cursor, T: 'a + BitStore, F: FnMut(bool) -> bool {
+	/// The `BitSlice` not yet split.
+	inner: &'a BitSlice<C, T>,
+	/// The separator predicate.
+	pred: F,
+	/// Whether the last subslice has been produced.
+	done: bool,
+}
+
+impl<'a, C, T, F> Split<'a, C, T, F>
+where C: Cursor, T: 'a + BitStore, F: FnMut(bool) -> bool {
+	/// Produces the remaining slice as the last subslice.
+	fn finish(&mut self) -> Option<&'a BitSlice<C, T>> {
+		if self.done {
+			return None;
+		}
+		self.done = true;
+		Some(self.inner)
+	}
+}
+
+impl<'a, C, T, F> Debug for Split<'a, C, T, F>
+where C: Cursor, T: 'a + BitStore, F: FnMut(bool) -> bool {
+	fn fmt(&self, fmt: &mut Formatter) -> fmt::Result {
+		fmt.debug_struct("Split")
+			.field("inner", &self.inner)
+			.field("done", &self.done)
+			.finish()
+	}
+}
+
+impl<'a, C, T, F> DoubleEndedIterator for Split<'a, C, T, F>
+where C: Cursor, T: 'a + BitStore, F: FnMut(bool) -> bool {
+	fn next_back(&mut self) -> Option<Self::Item> {
+		if self.done {
+			return None;
+		}
+		let pred = &mut self.pred;
+		match self.inner.iter().rposition(|bit| pred(bit)) {
+			Some(idx) => {
+				let out = &self.inner[idx + 1 ..];
+				self.inner = &self.inner[.. idx];
+				Some(out)
+			},
+			None => self.finish(),
+		}
+	}
+}
+
+impl<'a, C, T, F> FusedIterator for Split<'a, C, T, F>
+where C: Cursor, T: 'a + BitStore, F: FnMut(bool) -> bool {}
+
+impl<'a, C, T, F> Iterator for Split<'a, C, T, F>
+where C: Cursor, T: 'a + BitStore, F: FnMut(bool) -> bool {
+	type Item = &'a BitSlice<C, T>;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		if self.done {
+			return None;
+		}
+		let pred = &mut self.pred;
+		match self.inner.iter().position(|bit| pred(bit)) {
+			Some(idx) => {
+				let out = &self.inner[.. idx];
+				self.inner = &self.inner[idx + 1 ..];
+				Some(out)
+			},
+			None => self.finish(),
+		}
+	}
+
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		if self.done {
+			(0, Some(0))
+		}
+		else {
+			(1, Some(self.inner.len() + 1))
+		}
+	}
+}
+
+/** State keeper for iteration over at most `n` subslices of a `BitSlice`
+separated by bits that match a predicate.
+
+# Type Parameters
+
+- `C`: The bit-order type of the underlying `BitSlice`.
+- `T`: The storage type of the underlying `BitSlice`.
+- `F`: The predicate which selects separator bits.
+
+# Lifetimes
+
+- `'a`: The lifetime of the underlying `BitSlice`.
+**/
+#[derive(Clone)]
+pub struct SplitN<'a, C, T, F>
+where C: Cursor, T: 'a + BitStore, F: FnMut(bool) -> bool {
+	/// The unlimited splitter.
+	inner: Split<'a, C, T, F>,
+	/// The number of subslices remaining to be produced.
+	count: usize,
+}
+
+impl<'a, C, T, F> Debug for SplitN<'a, C, T, F>
+where C: Cursor, T: 'a + BitStore, F: FnMut(bool) -> bool {
+	fn fmt(&self, fmt: &mut Formatter) -> fmt::Result {
+		fmt.debug_struct("SplitN")
+			.field("inner", &self.inner)
+			.field("count", &self.count)
+			.finish()
+	}
+}
+
+impl<'a, C, T, F> FusedIterator for SplitN<'a, C, T, F>
+where C: Cursor, T: 'a + BitStore, F: FnMut(bool) -> bool {}
+
+impl<'a, C, T, F> Iterator for SplitN<'a, C, T, F>
+where C: Cursor, T: 'a + BitStore, F: FnMut(bool) -> bool {
+	type Item = &'a BitSlice<C, T>;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		match self.count {
+			0 => None,
+			1 => {
+				self.count = 0;
+				self.inner.finish()
+			},
+			_ => {
+				self.count -= 1;
+				self.inner.next()
+			},
+		}
+	}
+
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		let (lo, hi) = self.inner.size_hint();
+		(cmp::min(lo, self.count), hi.map(|hi| cmp::min(hi, self.count)))
+	}
+}
+
 /** State keeper for sliding-window iteration over a `BitSlice`.
 
 # Type Parameters
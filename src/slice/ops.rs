@@ -9,13 +9,8 @@ use super::BitSlice;
 use crate::{
 	bits::BitsMut,
 	cursor::Cursor,
-	domain::BitDomainMut,
-	indices::IntoBitIdx,
 	pointer::BitPtr,
-	store::{
-		BitAccess,
-		BitStore,
-	},
+	store::BitStore,
 };
 
 use core::{
@@ -537,46 +532,7 @@ where C: Cursor, T: 'a + BitStore {
 	/// assert_eq!(&src, &[0x3F, 0xFC]);
 	/// ```
 	fn not(self) -> Self::Output {
-		match self.bitptr().domain_mut() {
-			BitDomainMut::Empty => {},
-			BitDomainMut::Minor(head, elt, tail) => {
-				for n in *head .. *tail {
-					elt.invert_bit::<C>(n.idx());
-				}
-			},
-			BitDomainMut::Major(h, head, body, tail, t) => {
-				for n in *h .. T::BITS {
-					head.invert_bit::<C>(n.idx());
-				}
-				for elt in body {
-					*elt = !*elt;
-				}
-				for n in 0 .. *t {
-					tail.invert_bit::<C>(n.idx());
-				}
-			},
-			BitDomainMut::PartialHead(h, head, body) => {
-				for n in *h .. T::BITS {
-					head.invert_bit::<C>(n.idx());
-				}
-				for elt in body {
-					*elt = !*elt;
-				}
-			},
-			BitDomainMut::PartialTail(body, tail, t) => {
-				for elt in body {
-					*elt = !*elt;
-				}
-				for n in 0 .. *t {
-					tail.invert_bit::<C>(n.idx());
-				}
-			},
-			BitDomainMut::Spanning(body) => {
-				for elt in body {
-					*elt = !*elt;
-				}
-			},
-		}
+		self.flip_all();
 		self
 	}
 }
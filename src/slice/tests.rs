@@ -218,6 +218,16 @@ fn split_first() {
 	assert!(head);
 	assert!(rest[6]);
 	assert_eq!(rest.len(), 7);
+
+	#[cfg(feature = "alloc")] {
+	let bv = bitvec![1, 0, 1];
+	let (head, rest) = bv.split_first().unwrap();
+	assert!(head);
+	assert_eq!(rest, &bitvec![0, 1]);
+	let (tail, rest) = bv.split_last().unwrap();
+	assert!(tail);
+	assert_eq!(rest, &bitvec![1, 0]);
+	}
 }
 
 #[test]
@@ -254,7 +264,7 @@ fn split_last() {
 
 #[test]
 fn split_last_mut() {
-	assert!(BitSlice::<Local, u8>::empty_mut().split_first_mut().is_none());
+	assert!(BitSlice::<Local, u8>::empty_mut().split_last_mut().is_none());
 
 	let mut elt = 0x0001u16;
 	let bs = BitSlice::<BigEndian, _>::from_element_mut(&mut elt);
@@ -332,6 +342,20 @@ fn swap_reverse() {
 	bs.reverse();
 	assert_eq!(elt, 0b0001_0111);
 
+	#[cfg(feature = "alloc")] {
+	let mut bv = bitvec![1, 0, 0, 0, 0];
+	bv.swap(0, 4);
+	assert_eq!(bv, bitvec![0, 0, 0, 0, 1]);
+	bv.swap(4, 4);
+	assert_eq!(bv, bitvec![0, 0, 0, 0, 1]);
+	}
+
+	#[cfg(feature = "std")]
+	assert!(std::panic::catch_unwind(|| {
+		let mut elt = 0u8;
+		BitSlice::<BigEndian, _>::from_element_mut(&mut elt).swap(0, 8);
+	}).is_err());
+
 	//  check that `reverse` correctly handles odd-length slices
 	let mut elt = 0b101_0_010_1u8;
 	let bs = BitSlice::<BigEndian, _>::from_element_mut(&mut elt);
@@ -359,6 +383,25 @@ fn windows() {
 	assert_eq!(two, &bs[1 ..]);
 
 	assert!(windows.next().is_none());
+
+	assert_eq!(bs.windows(9).len(), 0);
+	assert!(bs.windows(9).next().is_none());
+
+	#[cfg(feature = "alloc")] {
+	let bv = bitvec![1, 0, 1, 1];
+	let mut windows = bv.windows(2);
+	assert_eq!(windows.len(), 3);
+	assert_eq!(windows.next().unwrap(), &bitvec![1, 0]);
+	assert_eq!(windows.next().unwrap(), &bitvec![0, 1]);
+	assert_eq!(windows.next().unwrap(), &bitvec![1, 1]);
+	assert!(windows.next().is_none());
+	}
+
+	#[cfg(feature = "std")]
+	assert!(std::panic::catch_unwind(|| {
+		let elt = 0u8;
+		BitSlice::<BigEndian, _>::from_element(&elt).windows(0);
+	}).is_err());
 }
 
 #[test]
@@ -411,4 +454,1156 @@ fn chunks() {
 	chunks.next().unwrap().set(2, true);
 	chunks.next().unwrap().set(2, true);
 	assert!(chunks.next().is_none());
+
+	//  short final chunk
+
+	#[cfg(feature = "alloc")] {
+	let bv = bitvec![1, 0, 1, 1, 0, 1, 1];
+	let mut chunks = bv.chunks(3);
+	assert_eq!(chunks.len(), 3);
+	assert_eq!(chunks.next().unwrap(), &bitvec![1, 0, 1]);
+	assert_eq!(chunks.next().unwrap(), &bitvec![1, 0, 1]);
+	assert_eq!(chunks.next().unwrap(), &bitvec![1]);
+	assert!(chunks.next().is_none());
+
+	let mut bv = bv;
+	let mut chunks = bv.chunks_mut(3);
+	assert_eq!(chunks.len(), 3);
+	chunks.next_back().unwrap().set(0, false);
+	chunks.next().unwrap().set(0, false);
+	assert_eq!(chunks.len(), 1);
+	assert_eq!(bv, bitvec![0, 0, 1, 1, 0, 1, 0]);
+	}
+
+	#[cfg(feature = "std")]
+	assert!(std::panic::catch_unwind(|| {
+		let elt = 0u8;
+		BitSlice::<BigEndian, _>::from_element(&elt).chunks(0);
+	}).is_err());
+}
+
+#[test]
+fn count_ones_zeros() {
+	#[cfg(feature = "alloc")] {
+	let bv = bitvec![1, 0, 1, 1, 0, 0, 1];
+	assert_eq!(bv.count_ones(), 4);
+	assert_eq!(bv.count_zeros(), 3);
+	}
+
+	let src = [0xA5u8, 0xFF, 0x00, 0x3C];
+	let bs = BitSlice::<BigEndian, _>::from_slice(&src);
+	for from in 0 .. bs.len() {
+		for upto in from .. bs.len() {
+			let part = &bs[from .. upto];
+			let ones = part.iter().filter(|b| *b).count();
+			assert_eq!(part.count_ones(), ones);
+			assert_eq!(part.count_zeros(), part.len() - ones);
+		}
+	}
+
+	let src = [0x0F0F_A5A5u32, !0, 0x8000_0001];
+	let bs = BitSlice::<LittleEndian, _>::from_slice(&src);
+	for from in (0 .. bs.len()).step_by(5) {
+		for upto in (from .. bs.len()).step_by(3) {
+			let part = &bs[from .. upto];
+			let ones = part.iter().filter(|b| *b).count();
+			assert_eq!(part.count_ones(), ones);
+			assert_eq!(part.count_zeros(), part.len() - ones);
+		}
+	}
+}
+
+#[test]
+fn leading_trailing() {
+	assert_eq!(BitSlice::<Local, u8>::empty().leading_zeros(), 0);
+	assert_eq!(BitSlice::<Local, u8>::empty().trailing_ones(), 0);
+
+	let src = [0u16; 3];
+	let bs = BitSlice::<BigEndian, _>::from_slice(&src);
+	assert_eq!(bs.leading_zeros(), 48);
+	assert_eq!(bs[3 .. 37].trailing_zeros(), 34);
+	assert_eq!(bs[3 .. 37].leading_ones(), 0);
+
+	let src = [0xFFu8, 0xC0, 0x00, 0x01, 0x80];
+	let be = BitSlice::<BigEndian, _>::from_slice(&src);
+	let le = BitSlice::<LittleEndian, _>::from_slice(&src);
+	for from in 0 .. be.len() {
+		for upto in from .. be.len() {
+			let part = &be[from .. upto];
+			assert_eq!(
+				part.leading_zeros(),
+				part.iter().take_while(|b| !*b).count(),
+			);
+			assert_eq!(
+				part.leading_ones(),
+				part.iter().take_while(|b| *b).count(),
+			);
+			assert_eq!(
+				part.trailing_zeros(),
+				part.iter().rev().take_while(|b| !*b).count(),
+			);
+			assert_eq!(
+				part.trailing_ones(),
+				part.iter().rev().take_while(|b| *b).count(),
+			);
+
+			let part = &le[from .. upto];
+			assert_eq!(
+				part.leading_zeros(),
+				part.iter().take_while(|b| !*b).count(),
+			);
+			assert_eq!(
+				part.trailing_ones(),
+				part.iter().rev().take_while(|b| *b).count(),
+			);
+		}
+	}
+}
+
+#[test]
+fn first_one_zero() {
+	assert!(BitSlice::<Local, u8>::empty().first_one().is_none());
+	assert!(BitSlice::<Local, u8>::empty().first_zero().is_none());
+
+	//  the only set bit is in the partial tail element
+	let src = [0u32, 0, 0x0000_4000];
+	let bs = BitSlice::<BigEndian, _>::from_slice(&src);
+	assert_eq!(bs[5 .. 90].first_one(), Some(76));
+	assert!(bs[5 .. 81].first_one().is_none());
+	assert_eq!(bs[5 .. 90].first_zero(), Some(0));
+
+	let bs = BitSlice::<LittleEndian, _>::from_slice(&src);
+	assert_eq!(bs[5 .. 90].first_one(), Some(73));
+
+	let src = [!0u8, !0, 0xF7];
+	let bs = BitSlice::<BigEndian, _>::from_slice(&src);
+	assert_eq!(bs[3 ..].first_zero(), Some(17));
+	assert_eq!(bs[3 .. 21].first_zero(), Some(17));
+	assert!(bs[3 .. 20].first_zero().is_none());
+}
+
+#[test]
+fn iter_ones_zeros() {
+	#[cfg(feature = "alloc")] {
+	let bv = bitvec![0, 1, 0, 0, 1, 1];
+	let mut ones = bv.iter_ones();
+	assert_eq!(ones.next(), Some(1));
+	assert_eq!(ones.next(), Some(4));
+	assert_eq!(ones.next(), Some(5));
+	assert!(ones.next().is_none());
+	let mut ones = bv.iter_ones().rev();
+	assert_eq!(ones.next(), Some(5));
+	assert_eq!(ones.next(), Some(4));
+	assert_eq!(ones.next(), Some(1));
+	assert!(ones.next().is_none());
+	}
+
+	let src = [0x8000_0000u32, 0, 0x0001_0001];
+	let bs = BitSlice::<BigEndian, _>::from_slice(&src);
+	let mut ones = bs[1 ..].iter_ones();
+	assert_eq!(ones.next(), Some(78));
+	assert_eq!(ones.next_back(), Some(94));
+	assert!(ones.next().is_none());
+	assert!(ones.next_back().is_none());
+	assert_eq!(bs.iter_ones().count(), 3);
+	assert_eq!(bs.iter_ones().last(), Some(95));
+
+	let mut zeros = bs[.. 35].iter_zeros();
+	assert_eq!(zeros.next(), Some(1));
+	assert_eq!(zeros.nth(32), Some(34));
+	assert!(zeros.next().is_none());
+	assert_eq!(bs.iter_zeros().count(), 93);
+	assert_eq!(bs.iter_zeros().rev().nth(1), Some(93));
+}
+
+#[test]
+fn rotate() {
+	#[cfg(feature = "alloc")] {
+	let mut bv = bitvec![1, 1, 0, 0, 0];
+	bv[..].rotate_left(2);
+	assert_eq!(bv, bitvec![0, 0, 0, 1, 1]);
+	bv[..].rotate_right(2);
+	assert_eq!(bv, bitvec![1, 1, 0, 0, 0]);
+	bv[..].rotate_left(5);
+	assert_eq!(bv, bitvec![1, 1, 0, 0, 0]);
+	bv[..].rotate_right(5);
+	assert_eq!(bv, bitvec![1, 1, 0, 0, 0]);
+	}
+
+	let mut src = [0x8001u16, 0x0F00];
+	let bs = BitSlice::<BigEndian, _>::from_slice_mut(&mut src);
+	bs[3 .. 29].rotate_left(13);
+	assert_eq!(src, [0x81E0, 0x0008]);
+
+	let bs = BitSlice::<BigEndian, _>::from_slice_mut(&mut src);
+	bs[3 .. 29].rotate_right(13);
+	assert_eq!(src, [0x8001, 0x0F00]);
+}
+
+#[test]
+fn shift() {
+	#[cfg(feature = "alloc")] {
+	let mut bv = bitvec![1, 1, 0, 0];
+	bv.shift_left(1);
+	assert_eq!(bv, bitvec![1, 0, 0, 0]);
+	assert_eq!(bv.len(), 4);
+
+	let mut bv = bitvec![1, 1, 0, 0];
+	bv.shift_right(1);
+	assert_eq!(bv, bitvec![0, 1, 1, 0]);
+	bv.shift_right(4);
+	assert!(bv.not_any());
+	assert_eq!(bv.len(), 4);
+	}
+
+	//  Bits outside the shifted slice are untouched.
+	let mut src = [0xFFFFu16, 0x0000];
+	BitSlice::<BigEndian, _>::from_slice_mut(&mut src)[4 .. 20].shift_left(4);
+	assert_eq!(src, [0xFFF0, 0x0000]);
+
+	let mut src = [0xFFFFu16, 0x0000];
+	BitSlice::<BigEndian, _>::from_slice_mut(&mut src)[4 .. 20].shift_right(4);
+	assert_eq!(src, [0xF0FF, 0xF000]);
+}
+
+#[test]
+fn bit_fields() {
+	let mut src = [0u8; 2];
+	let bits = BitSlice::<BigEndian, _>::from_slice_mut(&mut src);
+	bits.set_bits(2, 3, 0b101);
+	assert_eq!(bits.load_bits(2, 3), 0b101);
+	assert_eq!(bits.load_bits(0, 8), 0b0010_1000);
+
+	//  Fields may cross element boundaries.
+	bits.set_bits(6, 6, 0b11_0110);
+	assert_eq!(bits.load_bits(6, 6), 0b11_0110);
+	assert_eq!(src, [0b0010_1011, 0b0110_0000]);
+
+	let mut src = [0u32; 3];
+	let bits = BitSlice::<LittleEndian, _>::from_slice_mut(&mut src);
+	bits.set_bits(20, 64, 0x0123_4567_89AB_CDEF);
+	assert_eq!(bits.load_bits(20, 64), 0x0123_4567_89AB_CDEF);
+	assert_eq!(bits.load_bits(20, 0), 0);
+	assert_eq!(bits.count_ones(), 0x0123_4567_89AB_CDEFu64.count_ones() as usize);
+
+	#[cfg(feature = "std")] {
+
+	assert!(std::panic::catch_unwind(|| {
+		BitSlice::<BigEndian, _>::from_element(&0u8).load_bits(4, 5);
+	}).is_err());
+	assert!(std::panic::catch_unwind(|| {
+		let mut src = [0u32; 4];
+		BitSlice::<BigEndian, _>::from_slice_mut(&mut src).set_bits(0, 65, 0);
+	}).is_err());
+
+	}
+}
+
+#[test]
+fn load_store() {
+	let src = [0xABu8, 0xCD];
+	let bits = &BitSlice::<BigEndian, _>::from_slice(&src)[4 ..];
+	assert_eq!(bits.len(), 12);
+	assert_eq!(bits.load_le::<u16>(), 0xCDB);
+	assert_eq!(bits.load_be::<u16>(), 0xBCD);
+	let bits = &BitSlice::<LittleEndian, _>::from_slice(&src)[4 ..];
+	assert_eq!(bits.load_le::<u16>(), 0xCDA);
+	assert_eq!(bits.load_be::<u16>(), 0xACD);
+	assert_eq!(bits[.. 4].load_le::<u8>(), 0xA);
+	assert_eq!(bits[.. 0].load_be::<u32>(), 0);
+
+	let src = [0x1234u16, 0x5678];
+	let bits = &BitSlice::<BigEndian, _>::from_slice(&src)[8 .. 24];
+	assert_eq!(bits.load_le::<u16>(), 0x5634);
+	assert_eq!(bits.load_be::<u32>(), 0x3456);
+
+	let mut dst = [0u8; 3];
+	let bits = &mut BitSlice::<BigEndian, _>::from_slice_mut(&mut dst)[4 .. 16];
+	bits.store_le(0xCDBu16);
+	assert_eq!(bits.load_le::<u16>(), 0xCDB);
+	bits.store_be(0xFBCDu16);
+	assert_eq!(bits.load_be::<u16>(), 0xBCD);
+	assert_eq!(dst, [0x0B, 0xCD, 0x00]);
+
+	let mut dst = [!0u32; 3];
+	let bits = &mut BitSlice::<LittleEndian, _>::from_slice_mut(&mut dst)[20 .. 84];
+	let value = 0x0123_4567_89AB_CDEFu64;
+	bits[.. 32].store_le(value as u32);
+	assert_eq!(bits[.. 32].load_le::<u32>() as u64, value & 0xFFFF_FFFF);
+	assert!(bits[32 ..].all());
+
+	#[cfg(target_pointer_width = "64")] {
+
+	bits.store_le(value);
+	assert_eq!(bits.load_le::<u64>(), value);
+	bits.store_be(value);
+	assert_eq!(bits.load_be::<u64>(), value);
+	assert_ne!(bits.load_le::<u64>(), value);
+	assert_eq!(dst[0] & 0xF_FFFF, 0xF_FFFF);
+	assert_eq!(dst[2] >> 20, 0xFFF);
+
+	}
+
+	#[cfg(feature = "std")] {
+
+	assert!(std::panic::catch_unwind(|| {
+		BitSlice::<BigEndian, _>::from_slice(&[0u8; 2]).load_le::<u8>();
+	}).is_err());
+
+	}
+}
+
+#[test]
+fn align_to() {
+	let mut src = [0u8; 40];
+	for (idx, byte) in src.iter_mut().enumerate() {
+		*byte = (idx as u8).wrapping_mul(0x9D) ^ 0x5A;
+	}
+	let bits = &BitSlice::<LittleEndian, u8>::from_slice(&src)[5 .. 300];
+	let (head, body, tail) = unsafe { bits.align_to::<u32>() };
+	assert_eq!(head.len() + body.len() + tail.len(), bits.len());
+	assert_eq!(body.len() % 32, 0);
+	assert!(body.len() >= 32 * 7);
+	assert_eq!(body.as_ptr() as usize % core::mem::align_of::<u32>(), 0);
+	assert!(head.len() < 8 + 32);
+	assert!(tail.len() < 8 + 32);
+	assert_eq!(bits[.. head.len()], *head);
+	assert_eq!(bits[bits.len() - tail.len() ..], *tail);
+
+	//  Little-endian bit order over little-endian memory is the same sequence
+	//  at every element width.
+	#[cfg(target_endian = "little")] {
+
+	assert!(
+		head.iter()
+			.chain(body.iter())
+			.chain(tail.iter())
+			.eq(bits.iter())
+	);
+
+	}
+
+	//  Slices that cannot hold a whole `u32` keep everything in the prefix.
+	let short = &bits[.. 30];
+	let (head, body, tail) = unsafe { short.align_to::<u32>() };
+	assert_eq!(head, short);
+	assert!(body.is_empty());
+	assert!(tail.is_empty());
+}
+
+#[test]
+fn get_mut() {
+	let mut data = [0u8, !0];
+	let bits = data.bits_mut::<BigEndian>();
+
+	*bits.get_mut(1).unwrap() = true;
+	*bits.get_mut(14).unwrap() = false;
+	{
+		let bit = bits.get_mut(15).unwrap();
+		assert!(*bit);
+	}
+	assert!(bits.get_mut(16).is_none());
+	assert!(bits[10 ..].get_mut(6).is_none());
+	assert_eq!(data, [0b0100_0000, 0b1111_1101]);
+
+	let mut data = 0u16;
+	*data.bits_mut::<LittleEndian>()[4 ..].get_mut(9).unwrap() = true;
+	assert_eq!(data, 1 << 13);
+}
+
+#[test]
+fn replace() {
+	#[cfg(feature = "alloc")] {
+	let mut bv = bitvec![0, 0, 1, 0];
+	assert!(bv.replace(2, false));
+	assert!(!bv[2]);
+	assert_eq!(bv, bitvec![0; 4]);
+	}
+
+	let mut data = 0x0Fu8;
+	let bits = data.bits_mut::<LittleEndian>();
+	assert!(!bits[2 ..].replace(5, true));
+	assert!(bits.replace(0, true));
+	assert!(bits[7]);
+	assert_eq!(data, 0x8F);
+}
+
+#[test]
+#[should_panic]
+fn replace_oob() {
+	let mut data = 0u8;
+	data.bits_mut::<BigEndian>()[.. 4].replace(4, true);
+}
+
+#[test]
+fn toggle() {
+	let mut data = [0u16; 2];
+	let bits = data.bits_mut::<LittleEndian>();
+	bits.toggle(3);
+	bits[16 ..].toggle(1);
+	assert_eq!(data, [0x0008, 0x0002]);
+	data.bits_mut::<LittleEndian>().toggle(3);
+	assert_eq!(data, [0, 0x0002]);
+}
+
+#[test]
+#[should_panic]
+fn toggle_oob() {
+	let mut data = 0u8;
+	data.bits_mut::<BigEndian>()[2 ..].toggle(6);
+}
+
+#[test]
+fn flip_all() {
+	//  The flipped region shares its edge elements with its neighbors.
+	let mut data = [0xA5u8, 0x00, 0xFF, 0x3C];
+	data.bits_mut::<BigEndian>()[3 .. 29].flip_all();
+	assert_eq!(data, [0xBA, 0xFF, 0x00, 0xC4]);
+
+	//  A region within a single element.
+	let mut data = 0x0Fu16;
+	data.bits_mut::<LittleEndian>()[2 .. 6].flip_all();
+	assert_eq!(data, 0x33);
+
+	//  Flipping matches the bit-by-bit toggle.
+	let mut a = [0x5Au8, 0x81, 0x7E];
+	let mut b = a;
+	a.bits_mut::<LittleEndian>()[5 .. 20].flip_all();
+	let bits = &mut b.bits_mut::<LittleEndian>()[5 .. 20];
+	for idx in 0 .. bits.len() {
+		bits.toggle(idx);
+	}
+	assert_eq!(a, b);
+
+	let mut data = [0x0Fu8; 2];
+	data.bits_mut::<BigEndian>().flip_all();
+	assert_eq!(data, [0xF0; 2]);
+	data.bits_mut::<BigEndian>()[8 .. 8].flip_all();
+	assert_eq!(data, [0xF0; 2]);
+}
+
+#[test]
+fn flip_all_siblings() {
+	//  Both halves share the element at bits 8 .. 16.
+	let mut data = [0x00u8, 0x0F, 0xFF];
+	let (front, back) = data.bits_mut::<BigEndian>().split_at_mut(12);
+	back.flip_all();
+	assert!(front.not_any());
+	assert!(back.not_any());
+	front.flip_all();
+	assert_eq!(data, [0xFF, 0xF0, 0x00]);
+}
+
+#[test]
+fn subsequences() {
+	let data = 0b0110_0000u8;
+	let bits = &BitSlice::<BigEndian, _>::from_element(&data)[.. 4];
+	let ones = 0b11u8;
+	let needle = &BitSlice::<LittleEndian, _>::from_element(&ones)[.. 2];
+
+	assert!(bits.contains(needle));
+	assert!(!bits[2 ..].contains(needle));
+	assert!(!bits[.. 2].contains(&bits[.. 3]));
+	assert!(bits.contains(BitSlice::<BigEndian, u8>::empty()));
+	assert!(BitSlice::<BigEndian, u8>::empty().contains(
+		BitSlice::<LittleEndian, u16>::empty()
+	));
+
+	assert!(bits.starts_with(&bits[.. 2]));
+	assert!(!bits.starts_with(needle));
+	assert!(bits[1 ..].starts_with(needle));
+	assert!(bits.ends_with(&bits[2 ..]));
+	assert!(!bits.ends_with(needle));
+	assert!(bits[.. 3].ends_with(needle));
+	assert!(!bits[.. 1].starts_with(needle));
+	assert!(!bits[.. 1].ends_with(needle));
+}
+
+#[test]
+fn find() {
+	let data = 0b1010_1000u8;
+	let bits = BitSlice::<BigEndian, _>::from_element(&data);
+	let sync = 0b101u8;
+	let needle = &BitSlice::<LittleEndian, _>::from_element(&sync)[.. 3];
+
+	//  Overlapping matches at 0 and 2.
+	assert_eq!(bits[.. 5].find(needle), Some(0));
+	assert_eq!(bits[.. 5].rfind(needle), Some(2));
+	assert_eq!(bits[1 ..].find(needle), Some(1));
+	assert_eq!(bits[3 ..].find(needle), None);
+	assert_eq!(bits[3 ..].rfind(needle), None);
+	assert_eq!(bits[.. 2].find(needle), None);
+
+	let empty = BitSlice::<BigEndian, u8>::empty();
+	assert_eq!(bits.find(empty), Some(0));
+	assert_eq!(bits.rfind(empty), Some(8));
+	assert_eq!(empty.find(empty), Some(0));
+}
+
+#[test]
+fn set_predicates() {
+	let data = [0b0101_0000u8, 0b1111_0000, 0b0000_1111];
+	let bits = BitSlice::<BigEndian, _>::from_slice(&data);
+	let a = &bits[.. 4];
+	let b = &bits[8 .. 14];
+
+	//  Unaligned slices of different lengths.
+	assert!(a.is_subset(b));
+	assert!(!b.is_subset(a));
+	assert!(b.is_superset(a));
+	assert!(!a.is_superset(b));
+	assert!(a.is_subset(a));
+	assert!(!a.is_disjoint(b));
+	assert!(a.is_disjoint(&bits[16 ..]));
+
+	//  Aligned slices, spanning elements, with clear excess bits.
+	let other = [0b0111_0000u8, 0b1111_1000, 0b0000_1111, 0];
+	let wide = BitSlice::<BigEndian, _>::from_slice(&other);
+	assert!(bits[2 ..].is_subset(&wide[2 ..]));
+	assert!(!wide[2 ..].is_subset(&bits[2 ..]));
+	assert!(bits.is_subset(wide));
+	assert!(wide[.. 24].is_superset(bits));
+	assert!(!bits.is_disjoint(wide));
+	assert!(bits[.. 8].is_disjoint(&wide[16 .. 24]));
+	assert!(bits[3 .. 8].is_disjoint(&wide[14 .. 19]));
+	assert!(!bits[3 .. 12].is_disjoint(&wide[12 .. 21]));
+
+	//  Excess set bits in the longer slice defeat the subset test.
+	assert!(!bits.is_subset(&wide[.. 20]));
+	assert!(bits[.. 20].is_subset(wide));
+
+	let empty = BitSlice::<BigEndian, u8>::empty();
+	assert!(empty.is_subset(bits));
+	assert!(bits.is_superset(empty));
+	assert!(empty.is_disjoint(bits));
+}
+
+#[test]
+fn hamming_distance() {
+	let a = 0b1010_0000u8;
+	let b = 0b1100_0000u8;
+	let a = &BitSlice::<BigEndian, _>::from_element(&a)[.. 4];
+	let b = &BitSlice::<BigEndian, _>::from_element(&b)[.. 4];
+	assert_eq!(a.hamming_distance(b), 2);
+	assert_eq!(a.hamming_distance(a), 0);
+
+	//  Aligned slices spanning several elements, with differing dead bits.
+	let x = [0xFFu16, 0x0F0F, 0x1234, 0xFFFF];
+	let y = [0x00u16, 0x0F0F, 0x4321, 0x0000];
+	let x = BitSlice::<LittleEndian, _>::from_slice(&x);
+	let y = BitSlice::<LittleEndian, _>::from_slice(&y);
+	//  0x1234 ^ 0x4321 == 0x5115, which has six bits set.
+	assert_eq!(x[4 .. 60].hamming_distance(&y[4 .. 60]), 4 + 6 + 12);
+	assert_eq!(x.hamming_distance(y), 8 + 6 + 16);
+
+	//  Unaligned slices fall back to comparing bits.
+	let z = [0x0Fu16, 0x0F0F, 0x1234];
+	let z = BitSlice::<LittleEndian, _>::from_slice(&z);
+	assert_eq!(x[4 .. 24].hamming_distance(&z[8 .. 28]), 16);
+	assert_eq!(
+		BitSlice::<BigEndian, u8>::empty()
+			.hamming_distance(BitSlice::empty()),
+		0,
+	);
+}
+
+#[test]
+#[should_panic]
+fn hamming_distance_lengths() {
+	let data = 0u8;
+	let bits = BitSlice::<BigEndian, _>::from_element(&data);
+	bits[.. 3].hamming_distance(&bits[.. 4]);
+}
+
+#[test]
+fn reverse() {
+	#[cfg(feature = "alloc")] {
+	let mut bv = bitvec![1, 0, 0, 1, 1, 0];
+	bv.reverse();
+	assert_eq!(bv, bitvec![0, 1, 1, 0, 0, 1]);
+	}
+
+	let mut src = [0x8421u16, 0x00FF, 0xC000];
+	BitSlice::<BigEndian, _>::from_slice_mut(&mut src).reverse();
+	assert_eq!(src, [0x0003, 0xFF00, 0x8421]);
+
+	BitSlice::<LittleEndian, _>::from_slice_mut(&mut src).reverse();
+	assert_eq!(src, [0x8421u16, 0x00FF, 0xC000]);
+
+	//  partial slices take the bit-by-bit path
+	BitSlice::<BigEndian, _>::from_slice_mut(&mut src)[4 .. 36].reverse();
+	assert_eq!(src, [0x83FF, 0x0084, 0x2000]);
+}
+
+#[test]
+fn split_at() {
+	#[cfg(feature = "alloc")] {
+	let bv = bitvec![1, 0, 1, 1, 0];
+	let (l, r) = bv.split_at(2);
+	assert_eq!(l, &bitvec![1, 0]);
+	assert_eq!(r, &bitvec![1, 1, 0]);
+	}
+
+	//  Both halves share the middle element, and write to it independently.
+	let mut src = [0u8; 2];
+	{
+		let bs = BitSlice::<BigEndian, _>::from_slice_mut(&mut src);
+		let (l, r) = bs.split_at_mut(5);
+		assert_eq!(l.len(), 5);
+		assert_eq!(r.len(), 11);
+		l.set_all(true);
+		r.set(0, true);
+		r.set(10, true);
+		*l.at(0) = false;
+	}
+	assert_eq!(src, [0b0111_1100, 0b0000_0001]);
+
+	#[cfg(feature = "std")]
+	assert!(std::panic::catch_unwind(|| {
+		let elt = 0u8;
+		BitSlice::<BigEndian, _>::from_element(&elt).split_at(9);
+	}).is_err());
+}
+
+#[test]
+fn rchunks() {
+	let elt = 0b101_101_10u8;
+	let bs = BitSlice::<BigEndian, _>::from_element(&elt);
+
+	let mut rchunks = bs.rchunks(3);
+	assert_eq!(rchunks.len(), 3);
+	assert_eq!(rchunks.next().unwrap(), &bs[5 .. 8]);
+	assert_eq!(rchunks.next().unwrap(), &bs[2 .. 5]);
+	assert_eq!(rchunks.next().unwrap(), &bs[0 .. 2]);
+	assert!(rchunks.next().is_none());
+
+	#[cfg(feature = "alloc")] {
+	let mut bv = bitvec![1, 0, 1, 1, 0, 1, 1];
+	//  the short chunk is at the front of the slice, and so is produced last
+	//  from the front of the iterator and first from the back.
+	let mut rchunks = bv.rchunks(3).rev();
+	assert_eq!(rchunks.next().unwrap(), &bitvec![1]);
+	assert_eq!(rchunks.next().unwrap(), &bitvec![0, 1, 1]);
+	assert_eq!(rchunks.next().unwrap(), &bitvec![0, 1, 1]);
+	assert!(rchunks.next().is_none());
+
+	let mut rchunks = bv.rchunks_mut(3);
+	assert_eq!(rchunks.len(), 3);
+	rchunks.next().unwrap().set(0, true);
+	rchunks.next_back().unwrap().set(0, false);
+	assert_eq!(rchunks.len(), 1);
+	assert_eq!(bv, bitvec![0, 0, 1, 1, 1, 1, 1]);
+	}
+
+	#[cfg(feature = "std")]
+	assert!(std::panic::catch_unwind(|| {
+		let elt = 0u8;
+		BitSlice::<BigEndian, _>::from_element(&elt).rchunks(0);
+	}).is_err());
+}
+
+#[test]
+fn copy_from_slice() {
+	let src = [0xA5C3u16, 0x0FF0, 0x1234];
+	let from = BitSlice::<BigEndian, _>::from_slice(&src);
+
+	//  aligned copies, of every domain shape
+	for &(start, end) in &[(0, 48), (3, 11), (3, 40), (3, 32), (16, 40)] {
+		let mut dst = [0u16; 3];
+		let to = BitSlice::<BigEndian, _>::from_slice_mut(&mut dst);
+		to[start .. end].copy_from_slice(&from[start .. end]);
+		assert_eq!(&to[start .. end], &from[start .. end]);
+		assert!(to[.. start].not_any());
+		assert!(to[end ..].not_any());
+	}
+
+	//  misaligned copy across element boundaries
+	let mut dst = [!0u16; 3];
+	let to = BitSlice::<BigEndian, _>::from_slice_mut(&mut dst);
+	to[5 .. 40].copy_from_slice(&from[9 .. 44]);
+	assert_eq!(&to[5 .. 40], &from[9 .. 44]);
+	assert!(to[.. 5].all());
+	assert!(to[40 ..].all());
+
+	#[cfg(feature = "std")]
+	assert!(std::panic::catch_unwind(|| {
+		let mut a = 0u8;
+		let b = 0u8;
+		BitSlice::<BigEndian, _>::from_element_mut(&mut a)[1 ..]
+			.copy_from_slice(BitSlice::from_element(&b));
+	}).is_err());
+}
+
+#[test]
+fn set_all_fill() {
+	let mut src = [0xA5u8, 0x5A, 0xC3, 0x3C];
+	BitSlice::<BigEndian, _>::from_slice_mut(&mut src)[3 .. 29].fill(true);
+	assert_eq!(src, [0xBF, 0xFF, 0xFF, 0xFC]);
+	BitSlice::<BigEndian, _>::from_slice_mut(&mut src)[3 .. 29].fill(false);
+	assert_eq!(src, [0xA0, 0x00, 0x00, 0x04]);
+
+	let mut src = [0xA5A5u16];
+	BitSlice::<LittleEndian, _>::from_slice_mut(&mut src)[2 .. 13].set_all(true);
+	assert_eq!(src, [0xBFFD]);
+	BitSlice::<LittleEndian, _>::from_slice_mut(&mut src)[2 .. 13].set_all(false);
+	assert_eq!(src, [0xA001]);
+}
+
+#[test]
+fn eq_cross_type() {
+	//  The sequence `1101_0010_0000_0000_1000_0000_0000_0001` in every layout
+	let be8 = [0xD2u8, 0x00, 0x80, 0x01];
+	let le8 = [0x4Bu8, 0x00, 0x01, 0x80];
+	let be16 = [0xD200u16, 0x8001];
+	let le16 = [0x004Bu16, 0x8001];
+	let be32 = [0xD200_8001u32];
+	let le32 = [0x8001_004Bu32];
+
+	let bits = BitSlice::<BigEndian, _>::from_slice(&be8);
+	assert_eq!(bits, BitSlice::<LittleEndian, _>::from_slice(&le8));
+	assert_eq!(bits, BitSlice::<BigEndian, _>::from_slice(&be16));
+	assert_eq!(bits, BitSlice::<LittleEndian, _>::from_slice(&le16));
+	assert_eq!(bits, BitSlice::<BigEndian, _>::from_slice(&be32));
+	assert_eq!(bits, BitSlice::<LittleEndian, _>::from_slice(&le32));
+
+	#[cfg(target_pointer_width = "64")] {
+
+	let be64 = [0xD200_8001_0000_0000u64];
+	let le64 = [0x0000_0000_8001_004Bu64];
+	let wide = BitSlice::<BigEndian, _>::from_slice(&be64);
+	assert_eq!(bits, &wide[.. 32]);
+	assert_eq!(bits, &BitSlice::<LittleEndian, _>::from_slice(&le64)[.. 32]);
+	assert_ne!(bits, wide);
+
+	}
+
+	assert_ne!(bits, BitSlice::<LittleEndian, _>::from_slice(&be8));
+	assert_ne!(&bits[1 ..], BitSlice::<BigEndian, _>::from_slice(&be16));
+}
+
+#[test]
+fn as_bytes() {
+	let src = [0x69u8, 0x3C, 0xA5];
+	let bits = BitSlice::<BigEndian, u8>::from_slice(&src);
+	assert_eq!(bits.as_bytes(), &src);
+	assert_eq!(bits[8 ..].as_bytes(), &src[1 ..]);
+	assert_eq!(bits[8 .. 16].as_bytes(), &src[1 .. 2]);
+	assert!(bits[8 .. 8].as_bytes().is_empty());
+
+	let bits = BitSlice::<LittleEndian, u8>::from_slice(&src);
+	assert_eq!(
+		BitSlice::<LittleEndian, u8>::from_slice(bits.as_bytes()),
+		bits,
+	);
+
+	#[cfg(feature = "std")] {
+
+	assert!(std::panic::catch_unwind(|| {
+		BitSlice::<BigEndian, u8>::from_slice(&src)[1 ..].as_bytes();
+	}).is_err());
+	assert!(std::panic::catch_unwind(|| {
+		BitSlice::<BigEndian, u8>::from_slice(&src)[.. 20].as_bytes();
+	}).is_err());
+
+	}
+}
+
+#[test]
+fn take_skip() {
+	let data = 0b1100_1010u8;
+	let bits = BitSlice::<BigEndian, _>::from_element(&data);
+
+	assert_eq!(bits.take(3), &bits[.. 3]);
+	assert_eq!(bits.skip(3), &bits[3 ..]);
+	assert!(bits.take(0).is_empty());
+	assert_eq!(bits.skip(0), bits);
+
+	//  Counts past the end clamp to the slice boundaries.
+	assert_eq!(bits.take(8), bits);
+	assert_eq!(bits.take(9), bits);
+	assert!(bits.skip(8).is_empty());
+	assert!(bits.skip(usize::max_value()).is_empty());
+
+	//  The results are slices, and can be taken and skipped again.
+	assert_eq!(bits.skip(2).take(4), &bits[2 .. 6]);
+	assert_eq!(bits.skip(2).take(4).count_ones(), 1);
+	assert!(BitSlice::<BigEndian, u8>::empty().skip(1).is_empty());
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn partition_point() {
+	let bv = bitvec![0, 0, 0, 1, 1, 1];
+	assert_eq!(bv.partition_point(|b| !b), 3);
+	assert_eq!(bv.partition_point(|_| true), 6);
+	assert_eq!(bv.partition_point(|_| false), 0);
+
+	let bv = bitvec![1, 1, 0, 0, 0, 0, 0];
+	assert_eq!(bv.partition_point(|b| b), 2);
+	assert_eq!(bv[2 ..].partition_point(|b| b), 0);
+
+	//  The search examines only a logarithmic number of bits.
+	let bv = bitvec![BigEndian, u32; 0; 1000];
+	let mut calls = 0;
+	assert_eq!(bv.partition_point(|b| { calls += 1; !b }), 1000);
+	assert!(calls <= 10);
+	assert_eq!(BitSlice::<BigEndian, u8>::empty().partition_point(|b| b), 0);
+}
+
+#[test]
+fn first_after() {
+	//  An allocator bitmap with a block of 13 occupied slots.
+	let mut map = [0u16; 3];
+	let bits = BitSlice::<LittleEndian, _>::from_slice_mut(&mut map);
+	bits[3 .. 16].set_all(true);
+	bits.set(40, true);
+
+	assert_eq!(bits.first_clear_after(3), Some(16));
+	assert_eq!(bits.first_clear_after(17), Some(17));
+	assert_eq!(bits.first_set_after(0), Some(3));
+	assert_eq!(bits.first_set_after(16), Some(40));
+	assert_eq!(bits.first_set_after(40), Some(40));
+
+	//  Nothing remains past the last set bit, or past the end.
+	assert!(bits.first_set_after(41).is_none());
+	assert!(bits.first_set_after(48).is_none());
+	assert!(bits.first_clear_after(100).is_none());
+	assert!(bits[3 .. 16].first_clear_after(0).is_none());
+}
+
+#[test]
+fn bulk_boolean() {
+	let a = [0x5Au8, 0xC3, 0x96, 0x0F];
+	let b = [0x33u8, 0xF0, 0x69, 0xAA];
+
+	//  Aligned spans of equal length take the element-wise path, and all
+	//  other spans take the bit-wise path. Both must match the operators.
+	let spans = [(0, 32, 0, 32), (3, 29, 3, 29), (5, 13, 5, 13), (3, 29, 1, 27)];
+	for &(a1, a2, b1, b2) in &spans {
+		let rhs = &BitSlice::<LittleEndian, _>::from_slice(&b)[b1 .. b2];
+
+		let (mut fast, mut slow) = (a, a);
+		BitSlice::<LittleEndian, _>::from_slice_mut(&mut fast)[a1 .. a2]
+			.and_with(rhs);
+		BitSlice::<LittleEndian, _>::from_slice_mut(&mut slow)[a1 .. a2] &= rhs;
+		assert_eq!(fast, slow);
+
+		let (mut fast, mut slow) = (a, a);
+		BitSlice::<LittleEndian, _>::from_slice_mut(&mut fast)[a1 .. a2]
+			.or_with(rhs);
+		BitSlice::<LittleEndian, _>::from_slice_mut(&mut slow)[a1 .. a2] |= rhs;
+		assert_eq!(fast, slow);
+
+		let (mut fast, mut slow) = (a, a);
+		BitSlice::<LittleEndian, _>::from_slice_mut(&mut fast)[a1 .. a2]
+			.xor_with(rhs);
+		BitSlice::<LittleEndian, _>::from_slice_mut(&mut slow)[a1 .. a2] ^= rhs;
+		assert_eq!(fast, slow);
+	}
+
+	//  Shorter right-hand sides follow the operators' treatment of the rest.
+	let mut data = [0xFFu8; 2];
+	let bits = BitSlice::<BigEndian, _>::from_slice_mut(&mut data);
+	bits.and_with(&BitSlice::<BigEndian, _>::from_slice(&b)[.. 4]);
+	assert_eq!(data, [0x30, 0x00]);
+	let mut data = [0x00u8, 0x5A];
+	let bits = BitSlice::<BigEndian, _>::from_slice_mut(&mut data);
+	bits.or_with(&BitSlice::<BigEndian, _>::from_slice(&b)[.. 4]);
+	assert_eq!(bits.as_slice(), &[0x30, 0x5A]);
+	bits.xor_with(&BitSlice::<BigEndian, _>::from_slice(&b)[.. 3]);
+	assert_eq!(data, [0x10, 0x5A]);
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn chunks_exact_remainder() {
+	let bv = bitvec![1, 0, 1, 1, 0, 1, 1];
+	let mut chunks = bv.chunks_exact(3);
+	assert_eq!(chunks.len(), 2);
+	assert_eq!(chunks.remainder(), &bitvec![1]);
+	assert_eq!(chunks.next(), Some(&bitvec![1, 0, 1][..]));
+	assert_eq!(chunks.next(), Some(&bitvec![1, 0, 1][..]));
+	assert!(chunks.next().is_none());
+	assert_eq!(chunks.remainder(), &bitvec![1]);
+
+	//  Exact multiples leave no remainder, and short slices yield no chunks.
+	assert!(bv[.. 6].chunks_exact(3).remainder().is_empty());
+	let mut chunks = bv[.. 2].chunks_exact(3);
+	assert!(chunks.next().is_none());
+	assert_eq!(chunks.remainder(), &bitvec![1, 0]);
+}
+
+#[test]
+#[should_panic(expected = "Chunk size cannot be zero")]
+fn chunks_exact_zero() {
+	BitSlice::<BigEndian, u8>::empty().chunks_exact(0);
+}
+
+#[test]
+fn domain() {
+	use crate::domain::BitDomain;
+
+	let data = [0x3Cu8, 0xA5, 0x5A, 0xC3];
+	let full = BitSlice::<BigEndian, _>::from_slice(&data);
+
+	for from in 0 .. full.len() {
+		for upto in from .. full.len() + 1 {
+			let bits = &full[from .. upto];
+			let mut rebuilt = [false; 32];
+			let mut len = 0;
+			{
+				let mut push = |elt: u8, from: u8, upto: u8| {
+					let elt = BitSlice::<BigEndian, u8>::from_element(&elt);
+					for bit in &elt[from as usize .. upto as usize] {
+						rebuilt[len] = bit;
+						len += 1;
+					}
+				};
+				match bits.domain() {
+					BitDomain::Empty => {},
+					BitDomain::Minor(h, elt, t) => {
+						push(BitAccess::load(elt), *h, *t);
+					},
+					BitDomain::Major(h, head, body, tail, t) => {
+						push(BitAccess::load(head), *h, 8);
+						body.iter().for_each(|&e| push(e, 0, 8));
+						push(BitAccess::load(tail), 0, *t);
+					},
+					BitDomain::PartialHead(h, head, body) => {
+						push(BitAccess::load(head), *h, 8);
+						body.iter().for_each(|&e| push(e, 0, 8));
+					},
+					BitDomain::PartialTail(body, tail, t) => {
+						body.iter().for_each(|&e| push(e, 0, 8));
+						push(BitAccess::load(tail), 0, *t);
+					},
+					BitDomain::Spanning(body) => {
+						body.iter().for_each(|&e| push(e, 0, 8));
+					},
+				}
+			}
+			assert_eq!(len, bits.len());
+			assert!(rebuilt[.. len].iter().cloned().eq(bits.iter()));
+		}
+	}
+}
+
+#[test]
+fn as_slice_interior() {
+	let mut data = [0x12u16, 0x34, 0x56];
+	{
+		let bits = BitSlice::<LittleEndian, _>::from_slice(&data);
+		assert_eq!(bits.as_slice(), &[0x12, 0x34, 0x56]);
+		assert_eq!(bits[16 ..].as_slice(), &[0x34, 0x56]);
+		assert_eq!(bits[.. 40].as_slice(), &[0x12, 0x34]);
+		assert_eq!(bits[3 .. 45].as_slice(), &[0x34]);
+		assert!(bits[3 .. 13].as_slice().is_empty());
+		assert!(bits[3 .. 20].as_slice().is_empty());
+	}
+
+	let bits = BitSlice::<LittleEndian, _>::from_slice_mut(&mut data);
+	bits[8 .. 40].as_mut_slice()[0] = 0xFF;
+	assert!(bits[.. 0].as_mut_slice().is_empty());
+	assert_eq!(bits.as_slice(), &[0x12, 0xFF, 0x56]);
+}
+
+#[test]
+fn try_into_int() {
+	use core::convert::TryFrom;
+
+	let data = [0xABu8, 0xCD, 0xEF];
+	let bits = BitSlice::<BigEndian, _>::from_slice(&data);
+
+	assert_eq!(u16::try_from(&bits[.. 12]), Ok(0xABC));
+	assert_eq!(u16::try_from(&bits[4 .. 16]), Ok(0xBCD));
+	assert!(u16::try_from(&bits[.. 20]).is_err());
+
+	assert_eq!(u8::try_from(&bits[8 .. 16]), Ok(0xCD));
+	assert!(u8::try_from(&bits[.. 9]).is_err());
+	assert_eq!(u32::try_from(bits), Ok(0x00AB_CDEF));
+	assert_eq!(u64::try_from(&bits[12 ..]), Ok(0xDEF));
+	assert_eq!(u8::try_from(BitSlice::<BigEndian, u8>::empty()), Ok(0));
+
+	let bits = BitSlice::<LittleEndian, _>::from_slice(&data);
+	assert_eq!(u8::try_from(&bits[.. 4]), Ok(0b1101));
+}
+
+#[test]
+fn runs() {
+	let zero = [0u16; 3];
+	let bits = BitSlice::<LittleEndian, _>::from_slice(&zero);
+	assert!(bits.runs().eq(Some((false, 48))));
+	assert!(bits[5 .. 40].runs().eq(Some((false, 35))));
+	assert!(bits[.. 0].runs().next().is_none());
+
+	let alt = [0x55u8; 2];
+	let bits = BitSlice::<BigEndian, _>::from_slice(&alt);
+	assert_eq!(bits.runs().count(), 16);
+	assert!(bits.runs().all(|(_, len)| len == 1));
+	assert!(
+		bits.runs()
+			.map(|(bit, _)| bit)
+			.eq((0 .. 16).map(|n| n % 2 == 1))
+	);
+
+	//  Runs that cross element boundaries are yielded whole, from both ends.
+	let data = [0x0Fu8, 0xFF, 0xF0, 0x01];
+	let bits = BitSlice::<BigEndian, _>::from_slice(&data);
+	let expected = [(false, 4), (true, 16), (false, 11), (true, 1)];
+	assert!(bits.runs().eq(expected.iter().cloned()));
+	assert!(bits.runs().rev().eq(expected.iter().rev().cloned()));
+	assert!(bits[2 .. 30].runs().eq(
+		[(false, 2), (true, 16), (false, 10)].iter().cloned()
+	));
+
+	let mut runs = bits.runs();
+	assert_eq!(runs.next(), Some((false, 4)));
+	assert_eq!(runs.next_back(), Some((true, 1)));
+	assert_eq!(runs.next_back(), Some((false, 11)));
+	assert_eq!(runs.next(), Some((true, 16)));
+	assert!(runs.next().is_none());
+	assert!(runs.next_back().is_none());
+	assert_eq!(bits.runs().last(), Some((true, 1)));
+	assert_eq!(
+		bits.runs().map(|(_, len)| len).sum::<usize>(),
+		bits.len(),
+	);
+}
+
+#[test]
+fn copy_within() {
+	//  Disjoint ranges, in both directions and across elements.
+	let mut data = [0xF0u8, 0x00];
+	{
+		let bits = BitSlice::<BigEndian, _>::from_slice_mut(&mut data);
+		bits.copy_within(.. 4, 10);
+	}
+	assert_eq!(data, [0xF0, 0b0011_1100]);
+	{
+		let bits = BitSlice::<BigEndian, _>::from_slice_mut(&mut data);
+		bits.copy_within(8 .. 16, 0);
+	}
+	assert_eq!(data, [0b0011_1100, 0b0011_1100]);
+
+	//  Overlapping ranges toward the back and toward the front.
+	let mut data = [0b1101_0000u8, 0];
+	{
+		let bits = BitSlice::<BigEndian, _>::from_slice_mut(&mut data);
+		bits.copy_within(0 ..= 3, 2);
+		assert_eq!(bits.load_bits(0, 8), 0xF4);
+		bits.copy_within(2 .. 6, 1);
+	}
+	assert_eq!(data, [0b1110_1100, 0]);
+
+	//  Element-aligned copies move whole elements.
+	let mut data = [1u16, 2, 3, 4];
+	{
+		let bits = BitSlice::<LittleEndian, _>::from_slice_mut(&mut data);
+		bits.copy_within(16 .. 48, 32);
+		bits.copy_within(48 .., 0);
+		bits.copy_within(5 .. 5, 64);
+	}
+	assert_eq!(data, [3, 2, 2, 3]);
+}
+
+#[test]
+#[should_panic]
+fn copy_within_past_end() {
+	let mut data = 0u8;
+	BitSlice::<BigEndian, _>::from_element_mut(&mut data).copy_within(.. 4, 5);
+}
+
+#[test]
+fn unchecked_access() {
+	let data = [0xA5u8, 0x3C, 0x0F];
+	let bits = BitSlice::<BigEndian, _>::from_slice(&data);
+	for from in 0 .. 12 {
+		let part = &bits[from .. from + 9];
+		for idx in 0 .. part.len() {
+			assert_eq!(Some(unsafe { part.get_unchecked(idx) }), part.get(idx));
+		}
+	}
+
+	let mut checked = [0u16; 2];
+	let mut unchecked = [0u16; 2];
+	let pattern = [0x1234u16, 0xFEDC];
+	let src = BitSlice::<LittleEndian, _>::from_slice(&pattern);
+	{
+		let a = BitSlice::<LittleEndian, _>::from_slice_mut(&mut checked);
+		let b = BitSlice::<LittleEndian, _>::from_slice_mut(&mut unchecked);
+		let (a, b) = (&mut a[3 .. 29], &mut b[3 .. 29]);
+		for (idx, bit) in src[3 .. 29].iter().enumerate() {
+			a.set(idx, bit);
+			unsafe { b.set_unchecked(idx, bit); }
+		}
+		assert_eq!(a, b);
+		assert_eq!(a, &src[3 .. 29]);
+	}
+	assert_eq!(checked, unchecked);
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn split() {
+	let bv = bitvec![BigEndian, u8; 0, 0, 1, 0, 1, 0, 0];
+	let mut split = bv.split(|bit| bit);
+	assert_eq!(split.next().unwrap(), &bitvec![0, 0]);
+	assert_eq!(split.next().unwrap(), &bitvec![0]);
+	assert_eq!(split.next().unwrap(), &bitvec![0, 0]);
+	assert!(split.next().is_none());
+	assert!(split.next().is_none());
+
+	//  Separators at the edges and next to each other produce empty slices.
+	let bv = bitvec![BigEndian, u8; 1, 0, 1, 1];
+	let lens = bv.split(|bit| bit).map(|s| s.len()).collect::<Vec<_>>();
+	assert_eq!(lens, [0, 1, 0, 0]);
+	let lens = bv.split(|bit| bit).rev().map(|s| s.len()).collect::<Vec<_>>();
+	assert_eq!(lens, [0, 0, 1, 0]);
+
+	//  Splitting from both ends meets in the middle.
+	let bv = bitvec![BigEndian, u8; 0, 1, 0, 0, 1, 0, 0, 0];
+	let mut split = bv.split(|bit| bit);
+	assert_eq!(split.next_back().map(BitSlice::len), Some(3));
+	assert_eq!(split.next().map(BitSlice::len), Some(1));
+	assert_eq!(split.next_back().map(BitSlice::len), Some(2));
+	assert!(split.next().is_none());
+	assert!(split.next_back().is_none());
+
+	//  A slice with no separators, and the empty slice, are one subslice.
+	assert_eq!(bv.split(|_| false).count(), 1);
+	assert_eq!(bv[.. 0].split(|bit| bit).count(), 1);
+	assert!(bv.split(|bit| !bit).all(|s| s.all()));
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn split_variants() {
+	let bv = bitvec![BigEndian, u8; 0, 1, 0, 1, 0];
+	let mut splitn = bv.splitn(2, |bit| bit);
+	assert_eq!(splitn.next().unwrap(), &bitvec![0]);
+	assert_eq!(splitn.next().unwrap(), &bitvec![0, 1, 0]);
+	assert!(splitn.next().is_none());
+
+	let mut rsplitn = bv.rsplitn(2, |bit| bit);
+	assert_eq!(rsplitn.next().unwrap(), &bitvec![0]);
+	assert_eq!(rsplitn.next().unwrap(), &bitvec![0, 1, 0]);
+	assert!(rsplitn.next().is_none());
+
+	//  Limits above the number of subslices, of one, and of zero.
+	assert_eq!(bv.splitn(10, |bit| bit).count(), 3);
+	assert_eq!(bv.splitn(1, |bit| bit).next().unwrap(), &bv);
+	assert!(bv.splitn(0, |bit| bit).next().is_none());
+	assert_eq!(bv.rsplitn(1, |bit| bit).next().unwrap(), &bv);
+	assert!(bv.rsplitn(0, |bit| bit).next().is_none());
+
+	let bv = bitvec![BigEndian, u8; 1, 0, 0, 1, 0, 1];
+	let fwd = bv.split(|bit| bit).map(BitSlice::len).collect::<Vec<_>>();
+	let mut rev = bv.rsplit(|bit| bit).map(BitSlice::len).collect::<Vec<_>>();
+	assert_eq!(fwd, [0, 2, 1, 0]);
+	rev.reverse();
+	assert_eq!(fwd, rev);
+	assert!(bv.rsplit(|bit| bit).rev().map(BitSlice::len).eq(fwd));
+
+	let lens = bv.rsplitn(3, |bit| bit).map(BitSlice::len).collect::<Vec<_>>();
+	assert_eq!(lens, [0, 1, 3]);
 }
@@ -15,7 +15,10 @@ use crate::{
 };
 
 use core::{
-	cmp::Eq,
+	cmp::{
+		self,
+		Eq,
+	},
 	fmt::{
 		Binary,
 		Debug,
@@ -51,6 +54,14 @@ This trait must only be implemented on unsigned integer primitives with full
 alignment. It cannot be implemented on `u128` on any architecture, or on `u64`
 on 32-bit systems.
 
+Full alignment is required because `BitPtr` stores the high bits of its head
+index in the low bits of the element address, which are always zero when the
+type is aligned to its own size. A `u128` needs seven bits of head index, and
+so would need sixteen-byte alignment, but Rust only guarantees eight-byte
+alignment for `u128` on common 64-bit targets. There is also no stable
+`AtomicU128` to serve as its `Nucleus`, and `u128` cannot satisfy the
+`Into<u64>` bound that the bit-counting functions use.
+
 The `Sealed` supertrait ensures that this can only be implemented locally, and
 will never be implemented by downstream crates on new types.
 **/
@@ -240,6 +251,123 @@ pub trait BitStore:
 		u64::count_ones((!*self).into()) as usize
 	}
 
+	/// Counts how many `0` bits are above the most significant `1` bit in
+	/// `self`.
+	///
+	/// This zero-extends `self` to `u64`, uses the [`u64::leading_zeros`]
+	/// inherent method, and discards the zeros introduced by the extension.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	///
+	/// # Returns
+	///
+	/// The number of high-order zero bits in `self`. This is `Self::BITS` when
+	/// `self` is zero.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::BitStore;
+	/// assert_eq!(BitStore::leading_zeros(&0u8), 8);
+	/// assert_eq!(BitStore::leading_zeros(&1u8), 7);
+	/// assert_eq!(BitStore::leading_zeros(&0x80u8), 0);
+	/// assert_eq!(BitStore::leading_zeros(&0x0100u16), 7);
+	/// ```
+	///
+	/// [`u64::leading_zeros`]: https://doc.rust-lang.org/stable/std/primitive.u64.html#method.leading_zeros
+	#[inline(always)]
+	fn leading_zeros(&self) -> usize {
+		u64::leading_zeros((*self).into()) as usize - (64 - Self::BITS as usize)
+	}
+
+	/// Counts how many `0` bits are below the least significant `1` bit in
+	/// `self`.
+	///
+	/// This zero-extends `self` to `u64`, and uses the [`u64::trailing_zeros`]
+	/// inherent method, clamped to the width of `Self`.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	///
+	/// # Returns
+	///
+	/// The number of low-order zero bits in `self`. This is `Self::BITS` when
+	/// `self` is zero.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::BitStore;
+	/// assert_eq!(BitStore::trailing_zeros(&0u8), 8);
+	/// assert_eq!(BitStore::trailing_zeros(&1u8), 0);
+	/// assert_eq!(BitStore::trailing_zeros(&0x80u8), 7);
+	/// assert_eq!(BitStore::trailing_zeros(&0x0100u16), 8);
+	/// ```
+	///
+	/// [`u64::trailing_zeros`]: https://doc.rust-lang.org/stable/std/primitive.u64.html#method.trailing_zeros
+	#[inline(always)]
+	fn trailing_zeros(&self) -> usize {
+		cmp::min(
+			u64::trailing_zeros((*self).into()) as usize,
+			Self::BITS as usize,
+		)
+	}
+
+	/// Reverses the order of bits in `self`.
+	///
+	/// The least significant bit becomes the most significant bit, the second
+	/// least significant bit becomes the second most significant bit, and so
+	/// on.
+	///
+	/// The inherent `reverse_bits` methods on the fundamentals are not
+	/// available in the minimum supported Rust version, so this swaps the
+	/// bytes of `self` and then reverses each byte with a masking network.
+	///
+	/// # Parameters
+	///
+	/// - `self`
+	///
+	/// # Returns
+	///
+	/// `self`, with its bits in the opposite order.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::BitStore;
+	/// assert_eq!(BitStore::reverse_bits(0b1000_0000u8), 0b0000_0001);
+	/// assert_eq!(BitStore::reverse_bits(0b1100_1010u8), 0b0101_0011);
+	/// assert_eq!(BitStore::reverse_bits(0x8001_0000u32), 0x0000_8001);
+	/// assert_eq!(BitStore::reverse_bits(0x1234u16), 0x2C48);
+	/// ```
+	fn reverse_bits(self) -> Self;
+
+	/// Truncates a `u64` to the width of `Self`.
+	///
+	/// This is the inverse of the `Into<u64>` conversion that every `BitStore`
+	/// type provides, and discards any bits of `value` that `Self` is too
+	/// narrow to hold.
+	///
+	/// # Parameters
+	///
+	/// - `value`: The integer to truncate.
+	///
+	/// # Returns
+	///
+	/// The low `Self::BITS` bits of `value`.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::BitStore;
+	/// assert_eq!(<u8 as BitStore>::from_u64(0x1234), 0x34u8);
+	/// assert_eq!(<u32 as BitStore>::from_u64(0x1234), 0x1234u32);
+	/// ```
+	fn from_u64(value: u64) -> Self;
+
 	/// Extends a single bit to fill the entire element.
 	///
 	/// # Parameters
@@ -279,6 +407,23 @@ macro_rules! store {
 			type Nucleus = $a;
 			#[cfg(not(feature = "atomic"))]
 			type Nucleus = Cell<Self>;
+
+			#[inline]
+			fn reverse_bits(self) -> Self {
+				//  Masks of alternating single bits, bit pairs, and nibbles.
+				let m1 = <$t>::max_value() / 3;
+				let m2 = <$t>::max_value() / 5;
+				let m4 = <$t>::max_value() / 17;
+				let out = self.swap_bytes();
+				let out = (out >> 1) & m1 | (out & m1) << 1;
+				let out = (out >> 2) & m2 | (out & m2) << 2;
+				(out >> 4) & m4 | (out & m4) << 4
+			}
+
+			#[inline]
+			fn from_u64(value: u64) -> Self {
+				value as Self
+			}
 		}
 	)* };
 }
@@ -357,6 +502,33 @@ where T: BitStore {
 	fn invert_bit<C>(&self, place: BitIdx<T>)
 	where C: Cursor;
 
+	/// Sets all bits selected by a mask low.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	/// - `mask`: An element value whose high bits select the bits of `self`
+	///   to clear. Bits of `self` under low bits of `mask` are not modified.
+	fn clear_bits(&self, mask: T);
+
+	/// Sets all bits selected by a mask high.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	/// - `mask`: An element value whose high bits select the bits of `self`
+	///   to set. Bits of `self` under low bits of `mask` are not modified.
+	fn set_bits(&self, mask: T);
+
+	/// Inverts all bits selected by a mask.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	/// - `mask`: An element value whose high bits select the bits of `self`
+	///   to invert. Bits of `self` under low bits of `mask` are not modified.
+	fn invert_bits(&self, mask: T);
+
 	/// Gets a specific bit in an element.
 	///
 	/// # Parameters
@@ -463,6 +635,21 @@ where T: BitStore, R: RadiumBits<T> {
 	where C: Cursor {
 		self.fetch_xor(*C::mask(bit), Relaxed);
 	}
+
+	#[inline(always)]
+	fn clear_bits(&self, mask: T) {
+		self.fetch_and(!mask, Relaxed);
+	}
+
+	#[inline(always)]
+	fn set_bits(&self, mask: T) {
+		self.fetch_or(mask, Relaxed);
+	}
+
+	#[inline(always)]
+	fn invert_bits(&self, mask: T) {
+		self.fetch_xor(mask, Relaxed);
+	}
 }
 */
 
@@ -487,6 +674,21 @@ impl BitAccess<u8> for atomic::AtomicU8 {
 		self.fetch_xor(*C::mask(bit), Relaxed);
 	}
 
+	#[inline(always)]
+	fn clear_bits(&self, mask: u8) {
+		self.fetch_and(!mask, Relaxed);
+	}
+
+	#[inline(always)]
+	fn set_bits(&self, mask: u8) {
+		self.fetch_or(mask, Relaxed);
+	}
+
+	#[inline(always)]
+	fn invert_bits(&self, mask: u8) {
+		self.fetch_xor(mask, Relaxed);
+	}
+
 	#[inline(always)]
 	fn load(&self) -> u8 {
 		self.load(Relaxed)
@@ -512,6 +714,21 @@ impl BitAccess<u16> for atomic::AtomicU16 {
 		self.fetch_xor(*C::mask(bit), Relaxed);
 	}
 
+	#[inline(always)]
+	fn clear_bits(&self, mask: u16) {
+		self.fetch_and(!mask, Relaxed);
+	}
+
+	#[inline(always)]
+	fn set_bits(&self, mask: u16) {
+		self.fetch_or(mask, Relaxed);
+	}
+
+	#[inline(always)]
+	fn invert_bits(&self, mask: u16) {
+		self.fetch_xor(mask, Relaxed);
+	}
+
 	#[inline(always)]
 	fn load(&self) -> u16 {
 		self.load(Relaxed)
@@ -537,6 +754,21 @@ impl BitAccess<u32> for atomic::AtomicU32 {
 		self.fetch_xor(*C::mask(bit), Relaxed);
 	}
 
+	#[inline(always)]
+	fn clear_bits(&self, mask: u32) {
+		self.fetch_and(!mask, Relaxed);
+	}
+
+	#[inline(always)]
+	fn set_bits(&self, mask: u32) {
+		self.fetch_or(mask, Relaxed);
+	}
+
+	#[inline(always)]
+	fn invert_bits(&self, mask: u32) {
+		self.fetch_xor(mask, Relaxed);
+	}
+
 	#[inline(always)]
 	fn load(&self) -> u32 {
 		self.load(Relaxed)
@@ -563,6 +795,21 @@ impl BitAccess<u64> for atomic::AtomicU64 {
 		self.fetch_xor(*C::mask(bit), Relaxed);
 	}
 
+	#[inline(always)]
+	fn clear_bits(&self, mask: u64) {
+		self.fetch_and(!mask, Relaxed);
+	}
+
+	#[inline(always)]
+	fn set_bits(&self, mask: u64) {
+		self.fetch_or(mask, Relaxed);
+	}
+
+	#[inline(always)]
+	fn invert_bits(&self, mask: u64) {
+		self.fetch_xor(mask, Relaxed);
+	}
+
 	#[inline(always)]
 	fn load(&self) -> u64 {
 		self.load(Relaxed)
@@ -592,6 +839,21 @@ impl BitAccess<u8> for Cell<u8> {
 		self.set(self.get() ^ *C::mask(bit));
 	}
 
+	#[inline(always)]
+	fn clear_bits(&self, mask: u8) {
+		self.set(self.get() & !mask);
+	}
+
+	#[inline(always)]
+	fn set_bits(&self, mask: u8) {
+		self.set(self.get() | mask);
+	}
+
+	#[inline(always)]
+	fn invert_bits(&self, mask: u8) {
+		self.set(self.get() ^ mask);
+	}
+
 	#[inline(always)]
 	fn load(&self) -> u8 {
 		self.get()
@@ -617,6 +879,21 @@ impl BitAccess<u16> for Cell<u16> {
 		self.set(self.get() ^ *C::mask(bit));
 	}
 
+	#[inline(always)]
+	fn clear_bits(&self, mask: u16) {
+		self.set(self.get() & !mask);
+	}
+
+	#[inline(always)]
+	fn set_bits(&self, mask: u16) {
+		self.set(self.get() | mask);
+	}
+
+	#[inline(always)]
+	fn invert_bits(&self, mask: u16) {
+		self.set(self.get() ^ mask);
+	}
+
 	#[inline(always)]
 	fn load(&self) -> u16 {
 		self.get()
@@ -642,6 +919,21 @@ impl BitAccess<u32> for Cell<u32> {
 		self.set(self.get() ^ *C::mask(bit));
 	}
 
+	#[inline(always)]
+	fn clear_bits(&self, mask: u32) {
+		self.set(self.get() & !mask);
+	}
+
+	#[inline(always)]
+	fn set_bits(&self, mask: u32) {
+		self.set(self.get() | mask);
+	}
+
+	#[inline(always)]
+	fn invert_bits(&self, mask: u32) {
+		self.set(self.get() ^ mask);
+	}
+
 	#[inline(always)]
 	fn load(&self) -> u32 {
 		self.get()
@@ -668,6 +960,21 @@ impl BitAccess<u64> for Cell<u64> {
 		self.set(self.get() ^ *C::mask(bit));
 	}
 
+	#[inline(always)]
+	fn clear_bits(&self, mask: u64) {
+		self.set(self.get() & !mask);
+	}
+
+	#[inline(always)]
+	fn set_bits(&self, mask: u64) {
+		self.set(self.get() | mask);
+	}
+
+	#[inline(always)]
+	fn invert_bits(&self, mask: u64) {
+		self.set(self.get() ^ mask);
+	}
+
 	#[inline(always)]
 	fn load(&self) -> u64 {
 		self.get()
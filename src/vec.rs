@@ -17,7 +17,11 @@ use crate::{
 	},
 	indices::IntoBitIdx,
 	pointer::BitPtr,
-	slice::BitSlice,
+	slice::{
+		BitSlice,
+		live_mask,
+		resolve_range,
+	},
 	store::{
 		BitStore,
 		Word,
@@ -35,9 +39,13 @@ use core::{
 	cmp,
 	fmt::{
 		self,
+		Binary,
 		Debug,
 		Display,
 		Formatter,
+		LowerHex,
+		Octal,
+		UpperHex,
 	},
 	hash::{
 		Hash,
@@ -47,9 +55,19 @@ use core::{
 		PhantomData,
 	},
 	mem,
-	ops::RangeBounds,
-	ptr::NonNull,
+	ops::{
+		Range,
+		RangeBounds,
+	},
+	ptr::{
+		self,
+		NonNull,
+	},
 	slice,
+	sync::atomic::{
+		compiler_fence,
+		Ordering,
+	},
 };
 
 #[cfg(not(feature = "std"))]
@@ -250,6 +268,10 @@ effect. There is one case that will never break, however: using `unsafe` to
 construct a `[T]` slice over the `BitVec`’s capacity, and writing to the excess
 space, then increasing the length to match, is always valid.
 
+To erase a vector’s memory, use [`clear_zeroed`], which writes zeros that the
+optimizer may not remove, or wrap the vector in a [`SecretBitVec`], which does
+so when it is dropped.
+
 # Type Parameters
 
 - `C`: An implementor of the `Cursor` trait. This type is used to convert
@@ -271,8 +293,10 @@ is ***extremely binary incompatible*** with them. Attempting to treat
 [`Index`]: https://doc.rust-lang.org/stable/std/ops/trait.Index.html
 [`String`]: https://doc.rust-lang.org/stable/std/string/struct.String.html
 [`Vec`]: https://doc.rust-lang.org/stable/std/vec/struct.Vec.html
+[`SecretBitVec`]: struct.SecretBitVec.html
 [`bitvec!`]: ../macro.bitvec.html
 [`clear_on_drop`]: https://docs.rs/clear_on_drop
+[`clear_zeroed`]: #method.clear_zeroed
 [`len`]: #method.len
 [`shrink_to_fit`]: #method.shrink_to_fit
 [`with_capacity`]: #method.with_capacity
@@ -310,6 +334,9 @@ where C: Cursor, T: BitStore {
 	/// assert_eq!(bv.capacity(), 0);
 	/// ```
 	pub fn new() -> Self {
+		if cfg!(debug_assertions) {
+			C::verify::<T>();
+		}
 		Self {
 			_cursor: PhantomData,
 			pointer: BitPtr::empty(),
@@ -344,9 +371,41 @@ where C: Cursor, T: BitStore {
 		//  Find the number of elements needed to store the requested capacity
 		//  of bits.
 		let (cap, _) = 0.idx::<T>().span(capacity);
+		Self::with_element_capacity(cap)
+	}
+
+	/// Constructs a new, empty, `BitVec<C, T>` with room for at least the
+	/// specified number of storage elements.
+	///
+	/// This is useful when the vector’s memory needs to match the size of some
+	/// external buffer, which is measured in elements rather than bits.
+	///
+	/// # Parameters
+	///
+	/// - `elements`: The minimum number of `T` elements that the new vector
+	///   will need to be able to hold.
+	///
+	/// # Returns
+	///
+	/// An empty vector with room for at least `elements * T::BITS` bits.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let bv = BitVec::<BigEndian, u16>::with_element_capacity(3);
+	/// assert!(bv.is_empty());
+	/// assert!(bv.element_capacity() >= 3);
+	/// assert!(bv.capacity() >= 48);
+	/// ```
+	pub fn with_element_capacity(elements: usize) -> Self {
+		if cfg!(debug_assertions) {
+			C::verify::<T>();
+		}
 		//  Acquire a region of memory large enough for that element number.
 		let (ptr, cap) = {
-			let v = Vec::with_capacity(cap);
+			let v = Vec::with_capacity(elements);
 			let (ptr, cap) = (v.as_ptr(), v.capacity());
 			mem::forget(v);
 			(ptr, cap)
@@ -535,45 +594,259 @@ where C: Cursor, T: BitStore {
 	pub fn from_boxed_bitslice(slice: BitBox<C, T>) -> Self {
 		let bitptr = slice.bitptr();
 		mem::forget(slice);
-		unsafe { Self::from_raw_parts(bitptr, bitptr.elements()) }
+		unsafe { Self::from_bitptr(bitptr, bitptr.elements()) }
+	}
+
+	/// Concatenates a sequence of bit slices into a single `BitVec`.
+	///
+	/// This is an associated function, rather than a method on `[S]`, because
+	/// the standard library already claims `.concat()` for slices of slices.
+	///
+	/// # Parameters
+	///
+	/// - `parts`: The bit collections to concatenate, in order.
+	///
+	/// # Returns
+	///
+	/// A `BitVec` containing every bit of each member of `parts`, in order. The
+	/// total length is computed first, so the vector allocates only once.
+	///
+	/// # Type Parameters
+	///
+	/// - `S: Borrow<BitSlice<C, T>>`: Any bit collection with the same cursor
+	///   and storage type, such as `&BitSlice`, `BitBox`, or `BitVec`.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let codes = [bitvec![1, 0], bitvec![1], bitvec![0, 0]];
+	/// assert_eq!(BitVec::concat(&codes), bitvec![1, 0, 1, 0, 0]);
+	/// ```
+	pub fn concat<S>(parts: &[S]) -> Self
+	where S: Borrow<BitSlice<C, T>> {
+		let len = parts.iter().map(|p| p.borrow().len()).sum();
+		let mut out = Self::with_capacity(len);
+		for part in parts {
+			out.extend(part.borrow().iter());
+		}
+		out
+	}
+
+	/// Concatenates a sequence of bit slices into a single `BitVec`, placing a
+	/// separator between each of them.
+	///
+	/// # Parameters
+	///
+	/// - `parts`: The bit collections to join, in order.
+	/// - `sep`: A bit pattern inserted between each adjacent pair of `parts`.
+	///
+	/// # Returns
+	///
+	/// A `BitVec` containing every bit of each member of `parts`, with `sep`
+	/// between them. The total length is computed first, so the vector
+	/// allocates only once.
+	///
+	/// # Type Parameters
+	///
+	/// - `S: Borrow<BitSlice<C, T>>`: Any bit collection with the same cursor
+	///   and storage type, such as `&BitSlice`, `BitBox`, or `BitVec`.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let codes = [bitvec![1, 0], bitvec![1], bitvec![0, 0]];
+	/// let sep = bitvec![1, 1];
+	/// assert_eq!(
+	///   BitVec::join(&codes, &sep),
+	///   bitvec![1, 0, 1, 1, 1, 1, 1, 0, 0],
+	/// );
+	/// ```
+	pub fn join<S>(parts: &[S], sep: &BitSlice<C, T>) -> Self
+	where S: Borrow<BitSlice<C, T>> {
+		let seps = parts.len().saturating_sub(1);
+		let len = parts.iter().map(|p| p.borrow().len()).sum::<usize>()
+			+ seps * sep.len();
+		let mut out = Self::with_capacity(len);
+		for (idx, part) in parts.iter().enumerate() {
+			if idx > 0 {
+				out.extend(sep.iter());
+			}
+			out.extend(part.borrow().iter());
+		}
+		out
+	}
+
+	/// Builds a `BitVec` from a run-length encoding of its bits.
+	///
+	/// This is the inverse of [`BitSlice::runs`]. Each run is appended by a
+	/// bulk fill of its elements, rather than by pushing each bit.
+	///
+	/// # Parameters
+	///
+	/// - `runs`: A sequence of `(bit, count)` pairs. Each pair appends `count`
+	///   copies of `bit` to the vector. Adjacent pairs may have the same bit,
+	///   and a `count` may be zero.
+	///
+	/// # Returns
+	///
+	/// A `BitVec` containing each run of bits, in order.
+	///
+	/// # Panics
+	///
+	/// This panics if the total length of the runs overflows the vector.
+	///
+	/// # Type Parameters
+	///
+	/// - `I: IntoIterator<Item = (bool, usize)>`: The source of runs.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let runs = vec![(true, 3), (false, 2), (true, 1)];
+	/// let bv: BitVec = BitVec::from_runs(runs);
+	/// assert_eq!(bv, bitvec![1, 1, 1, 0, 0, 1]);
+	/// ```
+	///
+	/// [`BitSlice::runs`]: ../slice/struct.BitSlice.html#method.runs
+	pub fn from_runs<I>(runs: I) -> Self
+	where I: IntoIterator<Item = (bool, usize)> {
+		let mut out = Self::new();
+		for (bit, count) in runs {
+			let len = out.len();
+			let total = len.saturating_add(count);
+			assert!(
+				total <= BitPtr::<T>::MAX_INDX,
+				"Capacity overflow: {} exceeds {}",
+				total,
+				BitPtr::<T>::MAX_INDX,
+			);
+			out.resize(total, bit);
+		}
+		out
+	}
+
+	/// Decomposes a `BitVec<C, T>` into its raw components.
+	///
+	/// The returned pointer addresses the first element of the allocation, and
+	/// the bit length is counted from the first bit of that element. If the
+	/// vector does not begin at the front of its first element, its contents
+	/// are first copied into a fresh allocation that does.
+	///
+	/// After calling this function, the caller is responsible for the memory
+	/// previously managed by the `BitVec`. The only way to do this correctly is
+	/// to convert the parts back into a `BitVec` with
+	/// [`from_raw_parts`](#method.from_raw_parts), allowing the destructor to
+	/// perform the cleanup.
+	///
+	/// # Parameters
+	///
+	/// - `self`
+	///
+	/// # Returns
+	///
+	/// - `.0`: A pointer to the first element of the underlying buffer.
+	/// - `.1`: The number of live bits in the buffer.
+	/// - `.2`: The number of `T` elements allocated in the buffer.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let bv = bitvec![BigEndian, u8; 0, 1, 1, 0, 1];
+	/// let (ptr, len, cap) = bv.into_raw_parts();
+	/// assert_eq!(len, 5);
+	///
+	/// let rebuilt = unsafe { BitVec::<BigEndian, u8>::from_raw_parts(
+	///   ptr,
+	///   len,
+	///   cap,
+	/// ) };
+	/// assert_eq!(rebuilt, bitvec![0, 1, 1, 0, 1]);
+	/// ```
+	pub fn into_raw_parts(self) -> (*mut T, usize, usize) {
+		if *self.pointer.head() != 0 {
+			let mut out = Self::with_capacity(self.len());
+			out.extend_from_bitslice(&self);
+			return out.into_raw_parts();
+		}
+		let parts = (self.pointer.pointer().w(), self.len(), self.capacity);
+		mem::forget(self);
+		parts
 	}
 
-	/// Creates a new `BitVec<C, T>` directly from the raw parts of another.
+	/// Creates a `BitVec<C, T>` directly from the raw components of another.
 	///
 	/// # Parameters
 	///
-	/// - `pointer`: The `BitPtr<T>` to use.
-	/// - `capacity`: The number of `T` elements *allocated* in that slab.
+	/// - `ptr`: A pointer to the first element of the buffer.
+	/// - `bit_len`: The number of live bits in the buffer, counted from the
+	///   first bit of `*ptr` in the `C` order.
+	/// - `capacity`: The number of `T` elements allocated in the buffer.
 	///
 	/// # Returns
 	///
-	/// A `BitVec` over the given slab of memory.
+	/// A `BitVec` which owns the buffer, and whose first `bit_len` bits are
+	/// the bits of the buffer.
 	///
 	/// # Safety
 	///
 	/// This is ***highly*** unsafe, due to the number of invariants that aren’t
 	/// checked:
 	///
-	/// - `pointer` needs to have been previously allocated by some allocating
-	///   type.
-	/// - `pointer`’s `T` needs to have the same size ***and alignment*** as it
-	///   was initially allocated.
-	/// - `pointer`’s element count needs to be less than or equal to the
-	///   original allocation capacity.
-	/// - `capacity` needs to be the original allocation capacity for the
-	///   vector. This is *not* the value produced by `.capacity()`.
+	/// - `ptr` needs to have been previously allocated by `Vec<T>` (or
+	///   `BitVec<_, T>`), with the same `T`, and must not have been freed.
+	/// - `T` needs to have the same size ***and alignment*** as it was
+	///   initially allocated. It is ***absolutely*** not safe to construct a
+	///   `BitVec` whose `T` differs from the type used for the initial
+	///   allocation.
+	/// - `capacity` needs to be the capacity, in elements, with which the
+	///   buffer was allocated. This is *not* the value produced by
+	///   `.capacity()`, which counts bits.
+	/// - `bit_len` needs to be no greater than `capacity * T::BITS`, and the
+	///   elements it touches must be initialized.
 	///
 	/// Violating these ***will*** cause problems, like corrupting the handle’s
 	/// concept of memory, the allocator’s internal data structures, and the
-	/// sanity of your program. It is ***absolutely*** not safe to construct a
-	/// `BitVec` whose `T` differs from the type used for the initial
-	/// allocation.
+	/// sanity of your program.
 	///
-	/// The ownership of `pointer` is effectively transferred to the
+	/// The triple produced by [`into_raw_parts`](#method.into_raw_parts)
+	/// always satisfies these requirements for the same `C` and `T`.
+	///
+	/// The ownership of `ptr` is effectively transferred to the
 	/// `BitVec<C, T>` which may then deallocate, reallocate, or modify the
-	/// contents of the referent slice at will. Ensure that nothing else uses
+	/// contents of the referent buffer at will. Ensure that nothing else uses
 	/// the pointer after calling this function.
-	pub unsafe fn from_raw_parts(pointer: BitPtr<T>, capacity: usize) -> Self {
+	///
+	/// # Panics
+	///
+	/// This panics if `bit_len` exceeds the maximum length of a `BitVec`.
+	pub unsafe fn from_raw_parts(
+		ptr: *mut T,
+		bit_len: usize,
+		capacity: usize,
+	) -> Self {
+		let pointer = BitPtr::new(ptr as *const T, 0.idx(), bit_len);
+		Self::from_bitptr(pointer, capacity)
+	}
+
+	/// Creates a new `BitVec<C, T>` directly from a `BitPtr` and the capacity
+	/// of its allocation.
+	///
+	/// # Safety
+	///
+	/// `pointer` must describe a region of an allocation made by `Vec<T>`, and
+	/// `capacity` must be the element capacity of that allocation.
+	pub(crate) unsafe fn from_bitptr(
+		pointer: BitPtr<T>,
+		capacity: usize,
+	) -> Self {
 		Self {
 			_cursor: PhantomData,
 			pointer,
@@ -621,6 +894,24 @@ where C: Cursor, T: BitStore {
 		self.capacity
 	}
 
+	/// Returns the number of bytes of memory the vector holds for storage.
+	///
+	/// This is the element capacity multiplied by the size of `T`, and does
+	/// not include the vector handle itself.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let bv = BitVec::<BigEndian, u32>::with_element_capacity(4);
+	/// assert_eq!(bv.byte_capacity(), bv.element_capacity() * 4);
+	/// assert!(bv.byte_capacity() >= 16);
+	/// ```
+	pub fn byte_capacity(&self) -> usize {
+		self.capacity * mem::size_of::<T>()
+	}
+
 	/// Reserves capacity for at least `additional` more bits to be inserted.
 	///
 	/// The collection may reserve more space to avoid frequent reallocations.
@@ -655,15 +946,10 @@ where C: Cursor, T: BitStore {
 			newlen,
 			BitPtr::<T>::MAX_INDX,
 		);
-		let tail = self.pointer.tail();
-		//  If the additional bits would not depart the last element, do nothing
-		if *tail as usize + additional <= T::BITS as usize {
-			return;
-		}
-		//  Compute the number of additional elements needed to store the
-		//  requested number of additional bits.
-		let (e, _) = tail.span(additional);
-		self.do_unto_vec(|v| v.reserve(e));
+		//  Compute the number of elements needed to store the new length, and
+		//  reserve the difference between that and the elements in use.
+		let (elts, _) = self.pointer.head().span(newlen);
+		self.do_unto_vec(|v| v.reserve(elts - v.len()));
 	}
 
 	/// Reserves the minimum capacity for at least `additional` more bits.
@@ -672,6 +958,10 @@ where C: Cursor, T: BitStore {
 	/// equal to `self.len() + additional`. Does nothing if the capacity is
 	/// already sufficient.
 	///
+	/// Unlike `reserve`, this requests exactly as many elements as are needed
+	/// to hold `self.len() + additional` bits, and does not over-allocate to
+	/// amortize future growth.
+	///
 	/// Note that the allocator may give the collection more space than it
 	/// requests. Therefore, the capacity cannot be relied upon to be precisely
 	/// minimal. Prefer `reserve` if future insertions are expected.
@@ -694,27 +984,29 @@ where C: Cursor, T: BitStore {
 	/// assert!(bv.capacity() >= 5);
 	/// bv.reserve_exact(10);
 	/// assert!(bv.capacity() >= 15);
+	///
+	/// let mut bv: BitVec<BigEndian, u16> = BitVec::new();
+	/// bv.reserve_exact(35);
+	/// assert!(bv.element_capacity() >= 3);
 	/// ```
 	pub fn reserve_exact(&mut self, additional: usize) {
-		let newlen = self.len() + additional;
+		let newlen = self.len().saturating_add(additional);
 		assert!(
 			newlen <= BitPtr::<T>::MAX_INDX,
 			"Capacity overflow: {} exceeds {}",
 			newlen,
 			BitPtr::<T>::MAX_INDX,
 		);
-		let tail = self.pointer.tail();
-		if *tail as usize + additional <= T::BITS as usize {
-			return;
-		}
-		let (e, _) = tail.span(additional);
-		self.do_unto_vec(|v| v.reserve_exact(e));
+		let (elts, _) = self.pointer.head().span(newlen);
+		self.do_unto_vec(|v| v.reserve_exact(elts - v.len()));
 	}
 
 	/// Shrinks the capacity of the vector as much as possible.
 	///
 	/// It will drop down as close as possible to the length, but the allocator
-	/// may still inform the vector that there is space for bits.
+	/// may still inform the vector that there is space for bits. Only the
+	/// elements which hold live bits are kept; the live bits are moved with
+	/// them if the allocation relocates.
 	///
 	/// This does not modify the contents of the memory store! It will not zero
 	/// any memory that had been used and then removed from the vector’s live
@@ -729,11 +1021,12 @@ where C: Cursor, T: BitStore {
 	/// ```rust
 	/// use bitvec::prelude::*;
 	///
-	/// let mut bv = bitvec![1; 100];
+	/// let mut bv = bitvec![BigEndian, u8; 1; 100];
 	/// let cap = bv.capacity();
 	/// bv.truncate(10);
 	/// bv.shrink_to_fit();
-	/// assert!(bv.capacity() <= cap);
+	/// assert!(bv.capacity() < cap);
+	/// assert_eq!(bv, bitvec![1; 10]);
 	/// ```
 	pub fn shrink_to_fit(&mut self) {
 		self.do_unto_vec(Vec::shrink_to_fit);
@@ -767,6 +1060,114 @@ where C: Cursor, T: BitStore {
 		}
 	}
 
+	/// Shortens the vector, and writes zero over the bits it drops.
+	///
+	/// Unlike [`truncate`], which only forgets the dropped bits, this clears
+	/// them in memory, along with any dead bits after them in the last element
+	/// the vector occupied. Use it when the dropped bits held sensitive data.
+	/// As with [`clear_zeroed`], the writes are volatile, so that the compiler
+	/// does not remove them as dead stores.
+	///
+	/// If `len` is greater than the vector’s current length, this has no
+	/// effect.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	/// - `len`: The new length of the vector.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let mut bv = bitvec![BigEndian, u8; 1; 12];
+	/// bv.truncate_zeroing(3);
+	/// assert_eq!(bv.len(), 3);
+	/// assert_eq!(bv.as_slice(), &[0b1110_0000]);
+	/// ```
+	///
+	/// [`clear_zeroed`]: #method.clear_zeroed
+	/// [`truncate`]: #method.truncate
+	pub fn truncate_zeroing(&mut self, len: usize) {
+		if len >= self.len() {
+			return;
+		}
+		let tail = self.tail_mut(len).bitptr();
+		let (base, edge) = (tail.pointer().w(), *tail.head());
+		//  The element holding the new end keeps the bits in front of it. All
+		//  elements after it are cleared entirely.
+		for n in 0 .. tail.elements() {
+			let keep = if n == 0 {
+				live_mask::<C, T>(0, edge)
+			}
+			else {
+				T::from(0)
+			};
+			unsafe {
+				let elt = base.add(n);
+				ptr::write_volatile(elt, ptr::read_volatile(elt) & keep);
+			}
+		}
+		compiler_fence(Ordering::SeqCst);
+		unsafe { self.pointer.set_len(len); }
+	}
+
+	/// Sets the dead bits after the end of the vector in its last element.
+	///
+	/// The bits after the vector’s length in its last element are not part of
+	/// the vector, and no `BitVec` or `BitSlice` operation reads them as data:
+	/// comparison, hashing, counting, searching, and formatting of the bits all
+	/// mask them out. They are only observable through views of the raw
+	/// storage elements:
+	///
+	/// - [`as_slice`], [`as_mut_slice`], and `AsRef<[T]>`
+	/// - [`into_vec`], [`into_boxed_slice`], and [`into_raw_parts`]
+	/// - the `store` field of the `Debug` output
+	/// - serialization through `serde`, which sends the raw elements
+	///
+	/// The `bitvec!` macro, [`resize`], [`truncate_zeroing`], and
+	/// [`clear_zeroed`] leave these bits zeroed, but other operations may leave
+	/// them with any value. Code which reads the raw elements and requires a
+	/// particular value in the dead bits should set it with this method first.
+	///
+	/// Elements past the last live element are not modified.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	/// - `value`: The value to write into each dead bit after the end of the
+	///   vector in its last element.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let mut bv = bitvec![BigEndian, u8; 1, 0, 1];
+	/// bv.set_uninitialized(true);
+	/// assert_eq!(bv, bitvec![1, 0, 1]);
+	/// assert_eq!(bv.as_slice(), &[0b1011_1111]);
+	/// bv.set_uninitialized(false);
+	/// assert_eq!(bv.as_slice(), &[0b1010_0000]);
+	/// ```
+	///
+	/// [`as_mut_slice`]: #method.as_mut_slice
+	/// [`as_slice`]: #method.as_slice
+	/// [`clear_zeroed`]: #method.clear_zeroed
+	/// [`into_boxed_slice`]: #method.into_boxed_slice
+	/// [`into_raw_parts`]: #method.into_raw_parts
+	/// [`into_vec`]: #method.into_vec
+	/// [`resize`]: #method.resize
+	/// [`truncate_zeroing`]: #method.truncate_zeroing
+	pub fn set_uninitialized(&mut self, value: bool) {
+		let len = self.len();
+		if len == 0 {
+			return;
+		}
+		self.tail_mut(len).set_all(value);
+	}
+
 	/// Produces a `BitSlice` containing the entire vector.
 	///
 	/// Equivalent to `&s[..]`.
@@ -932,8 +1333,11 @@ where C: Cursor, T: BitStore {
 	pub fn insert(&mut self, index: usize, value: bool) {
 		let len = self.len();
 		assert!(index <= len, "Index {} is out of bounds: {}", index, len);
-		self.push(value);
-		self[index ..].rotate_right(1);
+		self.push(false);
+		//  Move the back segment up by one, opening a slot at `index`.
+		let back = &mut self[index ..];
+		*back >>= 1;
+		unsafe { back.set_unchecked(0, value); }
 	}
 
 	/// Removes and returns the bit at position `index`, shifting all bits after
@@ -965,16 +1369,19 @@ where C: Cursor, T: BitStore {
 	pub fn remove(&mut self, index: usize) -> bool {
 		let len = self.len();
 		assert!(index < len, "Index {} is out of bounds: {}", index, len);
-		self[index ..].rotate_left(1);
-		self.pop()
-			.expect("BitVec::remove cannot fail after index validation")
+		let out = unsafe { self.get_unchecked(index) };
+		//  Move the back segment down by one, covering the slot at `index`.
+		self[index ..] <<= 1;
+		unsafe { self.pointer.set_len(len - 1); }
+		out
 	}
 
 	/// Retains only the bits that pass the predicate.
 	///
 	/// This removes all bits `b` where `f(e)` returns `false`. This method
-	/// operates in place and preserves the order of the retained bits. Because
-	/// it is in-place, it operates in `O(n²)` time.
+	/// operates in place and preserves the order of the retained bits. It
+	/// makes a single pass over the vector, moving each retained bit to the
+	/// front, and so operates in `O(n)` time.
 	///
 	/// # Parameters
 	///
@@ -1001,11 +1408,113 @@ where C: Cursor, T: BitStore {
 	/// [`BitSlice::for_each`]: ../slice/struct.BitSlice.html#method.for_each
 	pub fn retain<F>(&mut self, mut pred: F)
 	where F: FnMut(usize, bool) -> bool {
-		for n in (0 .. self.len()).rev() {
-			if !pred(n, unsafe { self.get_unchecked(n) }) {
-				self.remove(n);
+		let len = self.len();
+		//  Retained bits are written at `to`, which never passes the read
+		//  cursor `from`.
+		let mut to = 0;
+		for from in 0 .. len {
+			let bit = unsafe { self.get_unchecked(from) };
+			if pred(from, bit) {
+				unsafe { self.set_unchecked(to, bit); }
+				to += 1;
+			}
+		}
+		self.truncate(to);
+	}
+
+	/// Removes consecutive repeated bits from the vector.
+	///
+	/// Because a bit has only two values, this collapses each run of equal
+	/// bits into a single bit, leaving a vector of alternating bits.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let mut bv = bitvec![0, 0, 1, 1, 1, 0, 1, 1];
+	/// bv.dedup();
+	/// assert_eq!(bv, bitvec![0, 1, 0, 1]);
+	/// ```
+	pub fn dedup(&mut self) {
+		self.dedup_by(|a, b| a == b);
+	}
+
+	/// Removes consecutive bits in the vector that resolve to the same key.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	/// - `key`: A function which maps each bit to a key.
+	///
+	/// # Type Parameters
+	///
+	/// - `F`: A function that maps a bit to its key.
+	/// - `K`: The key type, which must be comparable for equality.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let mut bv = bitvec![0, 0, 1, 1, 0];
+	/// bv.dedup_by_key(|_| ());
+	/// assert_eq!(bv, bitvec![0]);
+	/// ```
+	pub fn dedup_by_key<F, K>(&mut self, mut key: F)
+	where F: FnMut(bool) -> K, K: PartialEq {
+		self.dedup_by(|a, b| key(a) == key(b));
+	}
+
+	/// Removes all but the first of consecutive bits in the vector satisfying
+	/// a given equality relation.
+	///
+	/// The `same` function is passed each bit in turn, and the last bit that
+	/// was kept before it. If `same(bit, kept)` returns `true`, `bit` is
+	/// removed. This operates in place, in `O(n)` time.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	/// - `same`: The equality relation between a bit and its kept predecessor.
+	///
+	/// # Type Parameters
+	///
+	/// - `F`: A function that receives the current bit and the previous kept
+	///   bit, in that order, and returns whether they are the same.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let mut bv = bitvec![1, 0, 0, 1, 0];
+	/// //  Remove zeros that follow a kept one.
+	/// bv.dedup_by(|bit, kept| kept && !bit);
+	/// assert_eq!(bv, bitvec![1, 1]);
+	/// ```
+	pub fn dedup_by<F>(&mut self, mut same: F)
+	where F: FnMut(bool, bool) -> bool {
+		let len = self.len();
+		if len < 2 {
+			return;
+		}
+		//  The last kept bit is at `to - 1`, which never passes the read
+		//  cursor `from`.
+		let mut to = 1;
+		for from in 1 .. len {
+			let bit = unsafe { self.get_unchecked(from) };
+			let kept = unsafe { self.get_unchecked(to - 1) };
+			if !same(bit, kept) {
+				unsafe { self.set_unchecked(to, bit); }
+				to += 1;
 			}
 		}
+		self.truncate(to);
 	}
 
 	/// Appends a bit to the back of the vector.
@@ -1092,36 +1601,313 @@ where C: Cursor, T: BitStore {
 		}
 	}
 
-	/// Moves all the elements of `other` into `self`, leaving `other` empty.
+	/// Prepends a bit to the front of the vector.
+	///
+	/// All existing bits move up by one index. This is equivalent to
+	/// `.insert(0, value)`, and takes `O(n)` time.
 	///
 	/// # Parameters
 	///
 	/// - `&mut self`
-	/// - `other`: A `BitVec` of any order and storage type. Its bits are
-	///   appended to `self`.
+	/// - `value`: The bit value to prepend.
 	///
 	/// # Panics
 	///
-	/// Panics if the joined vector is too large.
+	/// This will panic if the push will cause the vector to allocate above
+	/// `BitPtr<T>` or machine capacity.
 	///
 	/// # Examples
 	///
 	/// ```rust
 	/// use bitvec::prelude::*;
 	///
-	/// let mut bv1 = bitvec![0; 10];
-	/// let mut bv2 = bitvec![1; 10];
-	/// bv1.append(&mut bv2);
-	/// assert_eq!(bv1.len(), 20);
-	/// assert!(bv1[10]);
-	/// assert!(bv2.is_empty());
+	/// let mut bv = bitvec![0, 0];
+	/// bv.push_front(true);
+	/// assert_eq!(bv, bitvec![1, 0, 0]);
 	/// ```
+	pub fn push_front(&mut self, value: bool) {
+		self.insert(0, value);
+	}
+
+	/// Removes the first bit from the collection, if present.
+	///
+	/// All remaining bits move down by one index. This is equivalent to
+	/// `.remove(0)` on a non-empty vector, and takes `O(n)` time.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	///
+	/// # Returns
+	///
+	/// If the vector is not empty, this returns the first bit; if it is empty,
+	/// this returns `None`.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let mut bv = bitvec![1, 0];
+	/// assert_eq!(bv.pop_front(), Some(true));
+	/// assert_eq!(bv.pop_front(), Some(false));
+	/// assert!(bv.pop_front().is_none());
+	/// ```
+	pub fn pop_front(&mut self) -> Option<bool> {
+		if self.is_empty() {
+			None
+		}
+		else {
+			Some(self.remove(0))
+		}
+	}
+
+	/// Moves all the elements of `other` into `self`, leaving `other` empty.
+	///
+	/// The memory of `other` is retained, so it can be refilled without
+	/// reallocating. When `other` stores its bits the same way as `self`, it is
+	/// appended through [`extend_from_bitslice`], which copies whole elements
+	/// wherever the two vectors are aligned.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	/// - `other`: A `BitVec` of any order and storage type. Its bits are
+	///   appended to `self`.
+	///
+	/// # Panics
+	///
+	/// Panics if the joined vector is too large.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let mut bv1 = bitvec![0; 10];
+	/// let mut bv2 = bitvec![1; 10];
+	/// bv1.append(&mut bv2);
+	/// assert_eq!(bv1.len(), 20);
+	/// assert!(bv1[10]);
+	/// assert!(bv2.is_empty());
+	/// ```
+	///
+	/// [`extend_from_bitslice`]: #method.extend_from_bitslice
 	pub fn append<D, U>(&mut self, other: &mut BitVec<D, U>)
 	where D: Cursor, U: BitStore {
-		self.extend(other.iter());
+		//  The storage types are sealed, so equal names are equal types, and
+		//  cursors are pure, so equal positions for every index are equal
+		//  layouts. Under both, `other` is a valid `BitSlice<C, T>`.
+		let same_layout = T::TYPENAME == U::TYPENAME && (0 .. T::BITS)
+			.map(IntoBitIdx::idx::<T>)
+			.all(|idx| *C::at::<T>(idx) == *D::at::<T>(idx));
+		if same_layout {
+			let bits = &**other as *const BitSlice<D, U>
+				as *const BitSlice<C, T>;
+			self.extend_from_bitslice(unsafe { &*bits });
+		}
+		else {
+			let len = self.len();
+			//  Grow with cleared bits in bulk, then only visit the set bits of
+			//  `other`, which skips over its clear elements entirely.
+			self.resize(len + other.len(), false);
+			for idx in other.iter_ones() {
+				unsafe { self.set_unchecked(len + idx, true); }
+			}
+		}
 		other.clear();
 	}
 
+	/// Appends all bits of a `BitSlice` to the end of the vector.
+	///
+	/// This behaves like `self.extend(other.iter())`, but copies whole
+	/// elements at once wherever the end of `self` and the bits of `other`
+	/// occupy the same positions within their elements. Bits are pushed one at
+	/// a time only until the end of `self` reaches an element boundary; if
+	/// `other` then also begins on an element boundary, the rest of it is
+	/// copied by element.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	/// - `other`: A `BitSlice` with the same cursor and storage type as
+	///   `self`, whose bits are appended to `self`.
+	///
+	/// # Panics
+	///
+	/// Panics if the joined vector is too large.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let mut bv = bitvec![BigEndian, u8; 0, 1];
+	/// let src = [0x0Fu8, 0xF0];
+	/// bv.extend_from_bitslice(&src.bits::<BigEndian>()[2 ..]);
+	/// assert_eq!(bv.len(), 16);
+	/// assert_eq!(bv.as_slice(), &[0b0100_1111, 0b1111_0000]);
+	/// ```
+	pub fn extend_from_bitslice(&mut self, other: &BitSlice<C, T>) {
+		let len = self.len();
+		let total = len + other.len();
+		assert!(
+			total <= BitPtr::<T>::MAX_INDX,
+			"Capacity overflow: {} > {}",
+			total,
+			BitPtr::<T>::MAX_INDX,
+		);
+		self.reserve(other.len());
+		//  Push bits until the end of `self` is on an element boundary.
+		let next = if self.is_empty() {
+			*self.pointer.head()
+		}
+		else {
+			*self.pointer.tail()
+		};
+		let lead = cmp::min(
+			(T::BITS - next % T::BITS) as usize % T::BITS as usize,
+			other.len(),
+		);
+		let (lead, rest) = other.split_at(lead);
+		for bit in lead {
+			self.push(bit);
+		}
+		if rest.is_empty() {
+			return;
+		}
+		if *rest.bitptr().head() != 0 {
+			for bit in rest {
+				self.push(bit);
+			}
+			return;
+		}
+		//  Both the end of `self` and the front of `rest` are on element
+		//  boundaries, so the elements of `rest` can be copied directly.
+		self.do_unto_vec(|v| v.extend_from_slice(rest.bitptr().as_slice()));
+		unsafe { self.pointer.set_len(total); }
+	}
+
+	/// Appends a copy of a range of the vector’s own bits to its end.
+	///
+	/// The vector is grown to its new length before any bits are copied, so
+	/// the source range is always read from the vector’s current allocation.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	/// - `src`: The range of bits to copy onto the end of the vector.
+	///
+	/// # Panics
+	///
+	/// This panics if `src` is not a valid range within the vector, or if the
+	/// new length of the vector overflows.
+	///
+	/// # Type Parameters
+	///
+	/// - `R: RangeBounds<usize>`: Any range over bit indices.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let mut bv = bitvec![1, 0, 1];
+	/// bv.extend_from_within(.. 2);
+	/// assert_eq!(bv, bitvec![1, 0, 1, 1, 0]);
+	/// ```
+	pub fn extend_from_within<R>(&mut self, src: R)
+	where R: RangeBounds<usize> {
+		let len = self.len();
+		let Range { start, end } = resolve_range(src, len);
+		self.resize(len + (end - start), false);
+		self.copy_within(start .. end, len);
+	}
+
+	/// Appends an integer field to the end of the vector.
+	///
+	/// The low `width` bits of `value` are appended with the same significance
+	/// as [`BitSlice::set_bits`]: the first appended bit is the most
+	/// significant bit of the field, and the last is its least significant.
+	/// The cursor `C` determines where in memory each of those bits live, but
+	/// does not change this order; use [`push_bits_lsb0`] to append the least
+	/// significant bit first. The appended field can be read back with
+	/// [`BitSlice::load_bits`].
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	/// - `value`: The value to append. Bits above `width` are ignored.
+	/// - `width`: The number of bits to append. This may be at most 64.
+	///
+	/// # Panics
+	///
+	/// This panics if `width` is greater than 64, or if the new length of the
+	/// vector overflows.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let mut bv = BitVec::<BigEndian, u8>::new();
+	/// bv.push_bits(0b101, 3);
+	/// bv.push_bits(0xF3, 4);
+	/// assert_eq!(bv, bitvec![1, 0, 1, 0, 0, 1, 1]);
+	/// assert_eq!(bv.load_bits(3, 4), 0b0011);
+	/// ```
+	///
+	/// [`BitSlice::load_bits`]: ../slice/struct.BitSlice.html#method.load_bits
+	/// [`BitSlice::set_bits`]: ../slice/struct.BitSlice.html#method.set_bits
+	/// [`push_bits_lsb0`]: #method.push_bits_lsb0
+	pub fn push_bits(&mut self, value: u64, width: usize) {
+		assert!(width <= 64, "Fields cannot be wider than 64 bits: {}", width);
+		let len = self.len();
+		self.resize(len + width, false);
+		self.set_bits(len, width, value);
+	}
+
+	/// Appends an integer field to the end of the vector, least significant
+	/// bit first.
+	///
+	/// This is the counterpart to [`push_bits`] for formats, such as DEFLATE,
+	/// which emit fields starting from their least significant bit. The first
+	/// appended bit is bit 0 of `value`, and the last is bit `width - 1`. As
+	/// with `push_bits`, the cursor `C` only determines where in memory each
+	/// appended bit lives.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	/// - `value`: The value to append. Bits above `width` are ignored.
+	/// - `width`: The number of bits to append. This may be at most 64.
+	///
+	/// # Panics
+	///
+	/// This panics if `width` is greater than 64, or if the new length of the
+	/// vector overflows.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let mut bv = BitVec::<LittleEndian, u8>::new();
+	/// bv.push_bits_lsb0(0b110, 3);
+	/// bv.push_bits_lsb0(0x13, 5);
+	/// assert_eq!(bv, bitvec![0, 1, 1, 1, 1, 0, 0, 1]);
+	/// assert_eq!(bv.as_slice(), &[0b110 | 0x13 << 3]);
+	/// ```
+	///
+	/// [`push_bits`]: #method.push_bits
+	pub fn push_bits_lsb0(&mut self, value: u64, width: usize) {
+		assert!(width <= 64, "Fields cannot be wider than 64 bits: {}", width);
+		//  Reversing the field turns its least significant bit into the most
+		//  significant, which `push_bits` writes first.
+		let rev = (0 .. width).fold(0, |rev, n| rev << 1 | (value >> n) & 1);
+		self.push_bits(rev, width);
+	}
+
 	/// Creates a draining iterator that removes the specified range from the
 	/// vector and yields the removed bits.
 	///
@@ -1226,12 +2012,50 @@ where C: Cursor, T: BitStore {
 		unsafe { self.set_len(0) }
 	}
 
+	/// Clears the vector, and writes zero to every element of its allocation.
+	///
+	/// Unlike [`clear`], which only forgets the live bits, this scrubs the
+	/// entire buffer, including the dead bits past the end of the vector, so
+	/// that no previous contents remain in memory. The writes are volatile, so
+	/// that the compiler does not remove them as dead stores. The capacity is
+	/// unchanged.
+	///
+	/// Neither `clear` nor the destructor zero the buffer. Call this before
+	/// dropping a vector which held sensitive data.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let mut bv = bitvec![1; 30];
+	/// bv.clear_zeroed();
+	/// assert!(bv.is_empty());
+	/// assert!(bv.capacity() >= 30);
+	/// ```
+	///
+	/// [`clear`]: #method.clear
+	pub fn clear_zeroed(&mut self) {
+		let base = self.pointer.pointer().w();
+		for n in 0 .. self.capacity {
+			unsafe { ptr::write_volatile(base.add(n), T::from(0)); }
+		}
+		compiler_fence(Ordering::SeqCst);
+		self.clear();
+	}
+
 	/// Splits the collection into two at the given index.
 	///
 	/// Returns a newly allocated `Self`. `self` contains elements `[0, at)`,
 	/// and the returned `Self` contains elements `[at, self.len())`.
 	///
-	/// Note that the capacity of `self` does not change.
+	/// Note that the capacity of `self` does not change, even when `at` is
+	/// `0`: the returned vector takes over the old buffer, and `self` receives
+	/// a new one of the same size.
 	///
 	/// # Parameters
 	///
@@ -1262,7 +2086,10 @@ where C: Cursor, T: BitStore {
 		let len = self.len();
 		assert!(at <= len, "Index out of bounds: {} is beyond {}", at, len);
 		match at {
-			0 => mem::replace(self, Self::new()),
+			0 => {
+				let cap = self.element_capacity();
+				mem::replace(self, Self::with_element_capacity(cap))
+			},
 			n if n == len => Self::new(),
 			_ => {
 				let out = self.as_bits()[at ..].to_owned();
@@ -1301,7 +2128,55 @@ where C: Cursor, T: BitStore {
 			self.truncate(new_len);
 		}
 		else if new_len > len {
-			self.extend(core::iter::repeat(value).take(new_len - len));
+			//  Initialize every element the new length touches, then fill the
+			//  exposed region in bulk.
+			let (elts, _) = self.pointer.head().span(new_len);
+			self.do_unto_vec(|v| v.resize(elts, T::from(0)));
+			unsafe { self.set_len(new_len); }
+			self[len ..].set_all(value);
+		}
+	}
+
+	/// Resizes the `BitVec` in place so that `len` is equal to `new_len`.
+	///
+	/// If `new_len` is greater than `len`, then the vector is extended by the
+	/// difference, with each new bit produced by calling `func`. If `new_len`
+	/// is less than `len`, then the vector is just truncated.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	/// - `new_len`: The new length of the vector.
+	/// - `func`: A generator of fill values, called once for each new bit, in
+	///   order.
+	///
+	/// # Type Parameters
+	///
+	/// - `F`: A function which produces bits.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let mut bv = bitvec![1; 2];
+	/// let mut next = false;
+	/// bv.resize_with(6, || { next = !next; next });
+	/// assert_eq!(bv, bitvec![1, 1, 1, 0, 1, 0]);
+	/// bv.resize_with(3, || unreachable!());
+	/// assert_eq!(bv, bitvec![1, 1, 1]);
+	/// ```
+	pub fn resize_with<F>(&mut self, new_len: usize, mut func: F)
+	where F: FnMut() -> bool {
+		let len = self.len();
+		if new_len < len {
+			self.truncate(new_len);
+		}
+		else if new_len > len {
+			self.reserve(new_len - len);
+			for _ in len .. new_len {
+				self.push(func());
+			}
 		}
 	}
 
@@ -1558,11 +2433,15 @@ where C: Cursor, T: BitStore {
 	where D: Cursor {
 		let (bp, cap) = (self.pointer, self.capacity);
 		mem::forget(self);
-		unsafe { BitVec::from_raw_parts(bp, cap) }
+		unsafe { BitVec::from_bitptr(bp, cap) }
 	}
 
 	/// Degrades a `BitVec` to a `BitBox`, freezing its size.
 	///
+	/// Like `Vec::into_boxed_slice`, this discards any excess capacity, so
+	/// the box owns only the elements needed to hold the live bits. This may
+	/// reallocate.
+	///
 	/// # Parameters
 	///
 	/// - `self`
@@ -1570,10 +2449,25 @@ where C: Cursor, T: BitStore {
 	/// # Returns
 	///
 	/// Itself, with its size frozen and ungrowable.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let mut bv = bitvec![BigEndian, u8; 1; 12];
+	/// bv.reserve(800);
+	/// let bb = bv.into_boxed_bitslice();
+	/// assert_eq!(bb.len(), 12);
+	/// assert_eq!(bb.into_boxed_slice().len(), 2);
+	/// ```
 	pub fn into_boxed_bitslice(self) -> BitBox<C, T> {
-		let pointer = self.pointer;
-		//  Convert the Vec allocation into a Box<[T]> allocation
-		mem::forget(self.into_boxed_slice());
+		let len = self.len();
+		//  Convert the Vec allocation into a Box<[T]> allocation, which moves
+		//  the live bits to the front and drops the excess capacity.
+		let boxed = self.into_boxed_slice();
+		let pointer = BitPtr::new(boxed.as_ptr(), 0.idx(), len);
+		mem::forget(boxed);
 		unsafe { BitBox::from_raw(pointer) }
 	}
 
@@ -1592,6 +2486,14 @@ where C: Cursor, T: BitStore {
 
 	/// Degrades a `BitVec` to a standard `Vec`.
 	///
+	/// This does not copy or reallocate. The live bits are first moved to the
+	/// front of the allocation, with [`force_align`], so that the produced
+	/// vector has exactly as many elements as are needed to hold them. Its
+	/// capacity is that of the `BitVec`.
+	///
+	/// Dead bits in the last element are not cleared, and their values are
+	/// unspecified.
+	///
 	/// # Parameters
 	///
 	/// - `self`
@@ -1599,6 +2501,22 @@ where C: Cursor, T: BitStore {
 	/// # Returns
 	///
 	/// The plain vector underlying the `BitVec`.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let bv = bitvec![BigEndian, u8; 0, 1, 0, 1, 1, 0, 1, 0, 1];
+	/// let v = bv.into_vec();
+	/// assert_eq!(v.len(), 2);
+	/// assert_eq!(v[0], 0x5A);
+	///
+	/// let bv = BitVec::<BigEndian, u8>::from_vec(v);
+	/// assert_eq!(bv.len(), 16);
+	/// ```
+	///
+	/// [`force_align`]: #method.force_align
 	pub fn into_vec(mut self) -> Vec<T> {
 		self.force_align();
 		let slice = self.as_mut_slice();
@@ -1609,6 +2527,28 @@ where C: Cursor, T: BitStore {
 		out
 	}
 
+	/// Views the bits from an index through the end of the last element that
+	/// the vector occupies.
+	///
+	/// This includes the dead bits after the end of the vector, which are not
+	/// otherwise addressable.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	/// - `from`: The index of the first bit in the view. This must not be
+	///   greater than `self.len()`.
+	///
+	/// # Returns
+	///
+	/// A `BitSlice` which begins at `from`, and ends on an element boundary.
+	fn tail_mut(&mut self, from: usize) -> &mut BitSlice<C, T> {
+		let mut bp = self.pointer;
+		let full = bp.elements() * T::BITS as usize - *bp.head() as usize;
+		unsafe { bp.set_len(full); }
+		&mut bp.into_bitslice_mut()[from ..]
+	}
+
 	/// Permits a function to modify the `Vec<T>` underneath a `BitVec<_, T>`.
 	///
 	/// This produces a `Vec<T>` structure referring to the same data region as
@@ -1680,6 +2620,99 @@ where C: Cursor, T: BitStore {
 	}
 }
 
+/** Byte-oriented constructors.
+
+These are only available on vectors which use `u8` as their storage element, so
+that the bit sequence of the vector is exactly the bit sequence of the source
+bytes, interpreted under the vector’s `Cursor`.
+**/
+impl<C> BitVec<C, u8>
+where C: Cursor {
+	/// Copies a byte slice into a new `BitVec`.
+	///
+	/// The produced vector has exactly `bytes.len() * 8` bits, and each byte
+	/// contributes its bits in the order defined by the `C` cursor. If you do
+	/// not need ownership of the bits, use [`BitSlice::from_slice`], which
+	/// views the bytes in place without copying.
+	///
+	/// # Parameters
+	///
+	/// - `bytes`: The source bytes to copy into the new `BitVec`.
+	///
+	/// # Returns
+	///
+	/// A `BitVec` whose bits are the bits of `bytes`.
+	///
+	/// # Panics
+	///
+	/// Panics if the source slice exceeds the maximum number of elements that a
+	/// `BitVec` can contain.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let be = BitVec::<BigEndian, u8>::from_bytes(&[0b0110_1001]);
+	/// assert_eq!(be, bitvec![0, 1, 1, 0, 1, 0, 0, 1]);
+	///
+	/// let le = BitVec::<LittleEndian, u8>::from_bytes(&[0b0110_1001]);
+	/// assert_eq!(le, bitvec![1, 0, 0, 1, 0, 1, 1, 0]);
+	/// ```
+	///
+	/// [`BitSlice::from_slice`]: ../slice/struct.BitSlice.html#method.from_slice
+	pub fn from_bytes(bytes: &[u8]) -> Self {
+		Self::from_slice(bytes)
+	}
+
+	/// Copies the first `bit_len` bits of a byte slice into a new `BitVec`.
+	///
+	/// This is [`from_bytes`] for buffers whose final byte is only partially
+	/// used. Only the bytes which hold live bits are copied, and the vector’s
+	/// length is set directly rather than by truncation.
+	///
+	/// # Parameters
+	///
+	/// - `bytes`: The source bytes to copy into the new `BitVec`.
+	/// - `bit_len`: The number of bits, counted from the front of `bytes`, to
+	///   place in the new vector.
+	///
+	/// # Returns
+	///
+	/// A `BitVec` of exactly `bit_len` bits, whose bits are the first
+	/// `bit_len` bits of `bytes`.
+	///
+	/// # Panics
+	///
+	/// Panics if `bit_len` is greater than `bytes.len() * 8`.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let bv = BitVec::<BigEndian, u8>::from_bytes_len(&[0xA5, 0xF0, 0xFF], 12);
+	/// assert_eq!(bv.len(), 12);
+	/// assert_eq!(bv.as_slice().len(), 2);
+	/// assert_eq!(bv, bitvec![1, 0, 1, 0, 0, 1, 0, 1, 1, 1, 1, 1]);
+	/// ```
+	///
+	/// [`from_bytes`]: #method.from_bytes
+	pub fn from_bytes_len(bytes: &[u8], bit_len: usize) -> Self {
+		assert!(
+			bit_len <= bytes.len().saturating_mul(8),
+			"Bit length {} exceeds the {} bits in {} bytes",
+			bit_len,
+			bytes.len().saturating_mul(8),
+			bytes.len(),
+		);
+		let (elts, _) = 0.idx::<u8>().span(bit_len);
+		let mut out = Self::from_slice(&bytes[.. elts]);
+		unsafe { out.set_len(bit_len); }
+		out
+	}
+}
+
 /// Signifies that `BitSlice` is the borrowed form of `BitVec`.
 impl<C, T> Borrow<BitSlice<C, T>> for BitVec<C, T>
 where C: Cursor, T: BitStore {
@@ -1956,6 +2989,49 @@ where C: Cursor, T: BitStore {
 	}
 }
 
+/** Builds a one-bit `BitVec` out of a `bool`.
+
+# Examples
+
+```rust
+use bitvec::prelude::*;
+
+let bv: BitVec = BitVec::from(true);
+assert_eq!(bv, bitvec![1]);
+```
+**/
+impl<C, T> From<bool> for BitVec<C, T>
+where C: Cursor, T: BitStore {
+	fn from(src: bool) -> Self {
+		let mut out = Self::with_capacity(1);
+		out.push(src);
+		out
+	}
+}
+
+macro_rules! impl_from_bool_array {
+	( $( $n:expr )* ) => { $(
+/** Builds a `BitVec` out of an array of `bool`.
+
+Until type-level integers stabilize, this is only implemented on arrays up to
+length 32.
+**/
+impl<C, T> From<[bool; $n]> for BitVec<C, T>
+where C: Cursor, T: BitStore {
+	fn from(src: [bool; $n]) -> Self {
+		Self::from(&src[..])
+	}
+}
+	)* };
+}
+
+impl_from_bool_array! {
+	 0  1  2  3  4  5  6  7  8  9
+	10 11 12 13 14 15 16 17 18 19
+	20 21 22 23 24 25 26 27 28 29
+	30 31 32
+}
+
 impl<C, T> From<BitBox<C, T>> for BitVec<C, T>
 where C: Cursor, T: BitStore {
 	fn from(src: BitBox<C, T>) -> Self {
@@ -2013,14 +3089,14 @@ where C: Cursor, T: BitStore {
 
 /** Prints the `BitVec` for debugging.
 
-The output is of the form `BitVec<C, T> [ELT, *]`, where `<C, T>` is the cursor
-and element type, with square brackets on each end of the bits and all the live
-elements in the vector printed in binary. The printout is always in semantic
-order, and may not reflect the underlying store. To see the underlying store,
-use `format!("{:?}", self.as_slice());` instead.
+The output is of the form
+`BitVec<C, T> { len: LEN, bits: [ELT, *], store: [RAW, *] }`, where `<C, T>` is
+the cursor and element type. `bits` holds the live bits of each element,
+printed in binary in semantic order, and `store` holds the full value of each
+element in memory, printed in hexadecimal, including any dead bits.
 
-The alternate character `{:#?}` prints each element on its own line, rather than
-separated by a space.
+The alternate character `{:#?}` prints each field, and each element, on its own
+line.
 **/
 impl<C, T> Debug for BitVec<C, T>
 where C: Cursor, T: BitStore {
@@ -2031,21 +3107,15 @@ where C: Cursor, T: BitStore {
 	/// ```rust
 	/// use bitvec::prelude::*;
 	///
-	/// let bv = bitvec![LittleEndian, u16;
-	///   0, 1, 0, 1, 0, 0, 0, 0, 1, 1, 1, 1, 0, 1, 0, 1
-	/// ];
+	/// let bv = bitvec![BigEndian, u8; 0, 1, 1, 0, 1, 0, 0, 1, 1, 0];
 	/// assert_eq!(
-	///   "BitVec<LittleEndian, u16> [0101000011110101]",
-	///   &format!("{:?}", bv)
+	///   &format!("{:?}", bv),
+	///   "BitVec<BigEndian, u8> { len: 10, bits: [01101001, 10], \
+	///    store: [0x69, 0x80] }",
 	/// );
 	/// ```
 	fn fmt(&self, f: &mut Formatter) -> fmt::Result {
-		f.write_str("BitVec<")?;
-		f.write_str(C::TYPENAME)?;
-		f.write_str(", ")?;
-		f.write_str(T::TYPENAME)?;
-		f.write_str("> ")?;
-		Display::fmt(self.as_bits(), f)
+		self.as_bits().fmt_debug("BitVec", f)
 	}
 }
 
@@ -2077,10 +3147,84 @@ where C: Cursor, T: BitStore {
 	}
 }
 
+/// Prints the `BitVec` as binary digits. See the `BitSlice` implementation.
+impl<C, T> Binary for BitVec<C, T>
+where C: Cursor, T: BitStore {
+	/// Renders the `BitVec` contents as binary digits.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// assert_eq!(format!("{:b}", bitvec![1, 0, 1, 1]), "1011");
+	/// ```
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+		Binary::fmt(self.as_bits(), f)
+	}
+}
+
+/// Prints the `BitVec` as octal digits. See the `BitSlice` implementation.
+impl<C, T> Octal for BitVec<C, T>
+where C: Cursor, T: BitStore {
+	/// Renders the `BitVec` contents as octal digits.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let bv = bitvec![1, 0, 1, 1, 1, 0, 0];
+	/// assert_eq!(format!("{:o}", bv), "560");
+	/// ```
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+		Octal::fmt(self.as_bits(), f)
+	}
+}
+
+/// Prints the `BitVec` as hexadecimal digits. See the `BitSlice`
+/// implementation.
+impl<C, T> LowerHex for BitVec<C, T>
+where C: Cursor, T: BitStore {
+	/// Renders the `BitVec` contents as lowercase hexadecimal digits.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let bv = bitvec![1, 0, 1, 1, 0, 0, 0, 1];
+	/// assert_eq!(format!("{:x}", bv), "b1");
+	/// ```
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+		LowerHex::fmt(self.as_bits(), f)
+	}
+}
+
+/// Prints the `BitVec` as hexadecimal digits. See the `BitSlice`
+/// implementation.
+impl<C, T> UpperHex for BitVec<C, T>
+where C: Cursor, T: BitStore {
+	/// Renders the `BitVec` contents as uppercase hexadecimal digits.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let bv = bitvec![1, 0, 1, 1, 0, 0, 0, 1, 1];
+	/// assert_eq!(format!("{:#X}", bv), "0xB18");
+	/// ```
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+		UpperHex::fmt(self.as_bits(), f)
+	}
+}
+
 /// Writes the contents of the `BitVec`, in semantic bit order, into a hasher.
 impl<C, T> Hash for BitVec<C, T>
 where C: Cursor, T: BitStore {
-	/// Writes each bit of the `BitVec`, as a full `bool`, into the hasher.
+	/// Writes the length of the `BitVec`, then each bit, as a full `bool`, into
+	/// the hasher.
 	///
 	/// # Parameters
 	///
@@ -2094,12 +3238,17 @@ where C: Cursor, T: BitStore {
 #[cfg(feature = "std")]
 impl<C, T> Write for BitVec<C, T>
 where C: Cursor, T: BitStore {
+	/// Appends the bits of each byte in `buf` to the vector.
+	///
+	/// Each byte is read as a `BitSlice<C, u8>`, so the cursor of the vector
+	/// also governs the order in which bits are drawn from the bytes. Only as
+	/// many bytes as the vector has room to index are written.
 	fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
 		let amt = cmp::min(
 			buf.len(),
-			BitPtr::<T>::MAX_INDX - self.as_slice().len(),
+			(BitPtr::<T>::MAX_INDX - self.len()) / 8,
 		);
-		let bits = BitSlice::<C, u8>::from_slice(buf);
+		let bits = BitSlice::<C, u8>::from_slice(&buf[.. amt]);
 		self.reserve(bits.len());
 		self.extend(bits);
 		Ok(amt)
@@ -2116,6 +3265,165 @@ where C: Cursor, T: BitStore {}
 unsafe impl<C, T> Sync for BitVec<C, T>
 where C: Cursor, T: BitStore {}
 
+/** A `BitVec` which scrubs its allocation when it is dropped.
+
+When it is dropped, this wrapper calls [`BitVec::clear_zeroed`] on its vector
+before the allocation is released, so that the contents of the buffer do not
+outlive it.
+
+The wrapper derefs to its `BitVec` only immutably. A mutable `BitVec` could be
+grown, shrunk, split, or swapped out, any of which releases or moves the buffer
+without scrubbing it. Instead, the wrapper provides only the mutators which
+keep the contents in the original allocation: [`as_mut_bitslice`] for writing
+bits in place, [`push`] and `Extend` within the existing capacity, and
+[`truncate`] and [`clear`], which zero what they remove. Allocate all the
+capacity that secret data will need before wrapping the vector.
+
+[`into_inner`] hands the vector back unprotected; it is the only way to move
+the buffer out of the wrapper.
+
+# Type Parameters
+
+- `C`: The `Cursor` type of the wrapped vector.
+- `T`: The storage type of the wrapped vector.
+
+# Examples
+
+```rust
+use bitvec::prelude::*;
+use bitvec::vec::SecretBitVec;
+
+let mut key = SecretBitVec::new(BitVec::<BigEndian, u8>::with_capacity(128));
+key.extend(bitvec![1, 0, 1, 1]);
+assert_eq!(key.len(), 4);
+//  The allocation is zeroed here.
+drop(key);
+```
+
+[`BitVec::clear_zeroed`]: struct.BitVec.html#method.clear_zeroed
+[`as_mut_bitslice`]: #method.as_mut_bitslice
+[`clear`]: #method.clear
+[`into_inner`]: #method.into_inner
+[`push`]: #method.push
+[`truncate`]: #method.truncate
+**/
+pub struct SecretBitVec<C = Local, T = Word>
+where C: Cursor, T: BitStore {
+	/// The vector to scrub on drop.
+	inner: BitVec<C, T>,
+}
+
+impl<C, T> SecretBitVec<C, T>
+where C: Cursor, T: BitStore {
+	/// Wraps a vector, so that its allocation is scrubbed when dropped.
+	///
+	/// # Parameters
+	///
+	/// - `inner`: The vector to protect.
+	///
+	/// # Returns
+	///
+	/// A `SecretBitVec` which owns `inner`.
+	pub fn new(inner: BitVec<C, T>) -> Self {
+		Self { inner }
+	}
+
+	/// Accesses the live bits of the vector for writing in place.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	///
+	/// # Returns
+	///
+	/// A mutable slice over the bits of the wrapped vector.
+	pub fn as_mut_bitslice(&mut self) -> &mut BitSlice<C, T> {
+		&mut self.inner
+	}
+
+	/// Appends a bit to the back of the vector.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	/// - `value`: The bit value to append.
+	///
+	/// # Panics
+	///
+	/// This panics if the vector is at its capacity, rather than moving its
+	/// contents into a new allocation.
+	pub fn push(&mut self, value: bool) {
+		let (len, cap) = (self.inner.len(), self.inner.capacity());
+		assert!(
+			*self.inner.pointer.head() as usize + len < cap,
+			"SecretBitVec cannot grow past its capacity of {} bits",
+			cap,
+		);
+		self.inner.push(value);
+	}
+
+	/// Shortens the vector, and zeroes the bits it drops.
+	///
+	/// This is [`BitVec::truncate_zeroing`].
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	/// - `len`: The new length of the vector.
+	///
+	/// [`BitVec::truncate_zeroing`]: struct.BitVec.html#method.truncate_zeroing
+	pub fn truncate(&mut self, len: usize) {
+		self.inner.truncate_zeroing(len);
+	}
+
+	/// Empties the vector, and zeroes its entire buffer.
+	///
+	/// This is [`BitVec::clear_zeroed`].
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	///
+	/// [`BitVec::clear_zeroed`]: struct.BitVec.html#method.clear_zeroed
+	pub fn clear(&mut self) {
+		self.inner.clear_zeroed();
+	}
+
+	/// Unwraps the vector, without scrubbing it.
+	///
+	/// The returned vector is no longer scrubbed when it is dropped.
+	///
+	/// # Parameters
+	///
+	/// - `self`
+	///
+	/// # Returns
+	///
+	/// The wrapped vector, with its contents intact.
+	pub fn into_inner(mut self) -> BitVec<C, T> {
+		let inner = mem::replace(&mut self.inner, BitVec::new());
+		mem::forget(self);
+		inner
+	}
+}
+
+/// Prints only the length of the wrapped vector, never its contents.
+impl<C, T> Debug for SecretBitVec<C, T>
+where C: Cursor, T: BitStore {
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+		f.debug_struct("SecretBitVec")
+			.field("len", &self.inner.len())
+			.finish()
+	}
+}
+
+impl<C, T> From<BitVec<C, T>> for SecretBitVec<C, T>
+where C: Cursor, T: BitStore {
+	fn from(inner: BitVec<C, T>) -> Self {
+		Self::new(inner)
+	}
+}
+
 mod r#override;
 mod iter;
 mod ops;
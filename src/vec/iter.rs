@@ -6,7 +6,10 @@ which rely on its memory ownership: `Drain`, which removes a section from a
 a different bit-stream into the removed section.
 !*/
 
-use super::BitVec;
+use super::{
+	BitVec,
+	SecretBitVec,
+};
 
 use crate::{
 	cursor::Cursor,
@@ -23,6 +26,21 @@ use core::{
 	ptr::NonNull,
 };
 
+/** Chooses how many bits to allocate for an iterator of bits.
+
+As with `Vec`, the upper bound of the iterator’s size hint is only used when it
+equals the lower bound, so that collecting an iterator of known length
+allocates exactly once. Otherwise, the lower bound is reserved: an upper bound
+is only a limit, and adapters such as `filter` and `take_while` over long
+ranges report bounds far beyond what they will produce.
+**/
+fn capacity_hint(hint: (usize, Option<usize>)) -> usize {
+	match hint {
+		(lo, Some(hi)) if hi == lo => hi,
+		(lo, _) => lo,
+	}
+}
+
 /** Extends a `BitVec` with the contents of another bitstream.
 
 At present, this just calls `.push()` in a loop. When specialization becomes
@@ -54,14 +72,111 @@ where C: Cursor, T: BitStore {
 	/// ```
 	fn extend<I: IntoIterator<Item=bool>>(&mut self, src: I) {
 		let iter = src.into_iter();
-		match iter.size_hint() {
-			(_, Some(hi)) => self.reserve(hi),
-			(lo, None) => self.reserve(lo),
-		}
+		self.reserve(capacity_hint(iter.size_hint()));
 		iter.for_each(|b| self.push(b));
 	}
 }
 
+/// Appends bits within the capacity of the wrapped vector.
+impl<C, T> Extend<bool> for SecretBitVec<C, T>
+where C: Cursor, T: BitStore {
+	/// Extends the wrapped vector from another bitstream.
+	///
+	/// # Panics
+	///
+	/// This panics if the vector reaches its capacity, as with `push`.
+	fn extend<I: IntoIterator<Item=bool>>(&mut self, src: I) {
+		src.into_iter().for_each(|b| self.push(b));
+	}
+}
+
+/** Extends a byte-backed `BitVec` with the bits of a byte stream.
+
+Each byte contributes its eight bits, in the order defined by the cursor `C`.
+When the end of the vector falls on a byte boundary, the bytes are appended to
+the underlying storage directly rather than being pushed bit by bit.
+**/
+impl<C> Extend<u8> for BitVec<C, u8>
+where C: Cursor {
+	/// Extends a `BitVec` with the bits of each byte in a byte stream.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	/// - `src`: A source byte stream.
+	///
+	/// # Type Parameters
+	///
+	/// - `I: IntoIterator<Item=u8>`: The source byte stream with which to
+	///   extend `self`.
+	///
+	/// # Panics
+	///
+	/// Panics if the extended vector would exceed its maximum length.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let mut bv = bitvec![BigEndian, u8; 1, 0];
+	/// bv.extend(vec![0x0Fu8, 0xF0]);
+	/// assert_eq!(bv.len(), 18);
+	/// assert_eq!(bv[2 .. 10], bitvec![0, 0, 0, 0, 1, 1, 1, 1]);
+	/// ```
+	fn extend<I: IntoIterator<Item=u8>>(&mut self, src: I) {
+		let iter = src.into_iter();
+		let aligned = if self.is_empty() {
+			*self.pointer.head() == 0
+		}
+		else {
+			*self.pointer.tail() == <u8 as BitStore>::BITS
+		};
+		if !aligned {
+			self.reserve(iter.size_hint().0 * 8);
+			for byte in iter {
+				self.extend(BitSlice::<C, u8>::from_element(&byte));
+			}
+			return;
+		}
+		let len = self.len();
+		let added = self.do_unto_vec(|v| {
+			let before = v.len();
+			v.extend(iter);
+			v.len() - before
+		});
+		let new_len = len + added * 8;
+		assert!(
+			new_len <= BitPtr::<u8>::MAX_INDX,
+			"Capacity overflow: {} > {}",
+			new_len,
+			BitPtr::<u8>::MAX_INDX,
+		);
+		unsafe { self.pointer.set_len(new_len); }
+	}
+}
+
+/// Extends a byte-backed `BitVec` with the bits of borrowed bytes.
+impl<'a, C> Extend<&'a u8> for BitVec<C, u8>
+where C: Cursor {
+	/// Extends a `BitVec` with the bits of each byte in a byte stream.
+	///
+	/// See the `Extend<u8>` implementation.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let mut bv = BitVec::<LittleEndian, u8>::new();
+	/// bv.extend(&[0x0Fu8]);
+	/// assert_eq!(bv, bitvec![1, 1, 1, 1, 0, 0, 0, 0]);
+	/// ```
+	fn extend<I: IntoIterator<Item=&'a u8>>(&mut self, src: I) {
+		self.extend(src.into_iter().cloned());
+	}
+}
+
 /// Permits the construction of a `BitVec` by using `.collect()` on an iterator
 /// of `bool`.
 impl<C, T> FromIterator<bool> for BitVec<C, T>
@@ -82,11 +197,7 @@ where C: Cursor, T: BitStore {
 	/// ```
 	fn from_iter<I: IntoIterator<Item=bool>>(src: I) -> Self {
 		let iter = src.into_iter();
-		let mut bv = match iter.size_hint() {
-			| (_, Some(len))
-			| (len, _)
-			=> Self::with_capacity(len),
-		};
+		let mut bv = Self::with_capacity(capacity_hint(iter.size_hint()));
 		for bit in iter {
 			bv.push(bit);
 		}
@@ -94,6 +205,34 @@ where C: Cursor, T: BitStore {
 	}
 }
 
+/// Permits the construction of a byte-backed `BitVec` by using `.collect()` on
+/// an iterator of `u8`.
+impl<C> FromIterator<u8> for BitVec<C, u8>
+where C: Cursor {
+	/// Collects an iterator of bytes into a vector of their bits.
+	///
+	/// Each byte contributes its eight bits, in the order defined by the cursor
+	/// `C`, and the bytes become the storage elements of the vector.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let bv: BitVec<LittleEndian, u8> = vec![0x0Fu8, 0x01]
+	///   .into_iter()
+	///   .collect();
+	/// assert_eq!(bv.len(), 16);
+	/// assert_eq!(bv[.. 8], bitvec![1, 1, 1, 1, 0, 0, 0, 0]);
+	/// assert_eq!(bv.as_slice(), &[0x0F, 0x01]);
+	/// ```
+	fn from_iter<I: IntoIterator<Item=u8>>(src: I) -> Self {
+		let mut bv = Self::new();
+		bv.extend(src);
+		bv
+	}
+}
+
 /** Produces an iterator over all the bits in the vector.
 
 This iterator follows the ordering in the vector type, and implements
@@ -277,9 +416,10 @@ where C: Cursor, T: 'a + BitStore {
 		let end_len = start + tail_len;
 		//  Inflate the vector to include the remnant span,
 		bv.set_len(full_len);
-		//  Swap the remnant span down into the drained span,
+		//  Copy the remnant span down into the drained span. The drained bits
+		//  are dead, so they need not be preserved behind the remnant.
 		for (from, to) in (tail .. full_len).zip(start .. end_len) {
-			bv.swap(from, to);
+			bv.copy(from, to);
 		}
 		//  And deflate the vector to fit.
 		bv.set_len(end_len);
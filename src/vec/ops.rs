@@ -4,7 +4,10 @@ Operator trait implementations are moved here, in order to reduce the total size
 of the `src/vec.rs`. file.
 !*/
 
-use super::BitVec;
+use super::{
+	BitVec,
+	SecretBitVec,
+};
 
 use crate::{
 	cursor::Cursor,
@@ -32,6 +35,8 @@ use core::{
 		DerefMut,
 		Index,
 		IndexMut,
+		Mul,
+		MulAssign,
 		Neg,
 		Not,
 		Range,
@@ -386,8 +391,12 @@ where C: Cursor, T: BitStore {
 /** Readies the underlying storage for Drop.
 
 Note that this implementation does *not* attempt to zero the allocation block.
-If your vector contained secret data, you must use a crate which specializes in
-erasing secrets to destroy the contents of the allocation before it is released.
+If your vector contained secret data, call [`BitVec::clear_zeroed`] to erase the
+contents of the allocation before it is released, or keep it in a
+[`SecretBitVec`], which does so automatically.
+
+[`BitVec::clear_zeroed`]: struct.BitVec.html#method.clear_zeroed
+[`SecretBitVec`]: struct.SecretBitVec.html
 **/
 impl<C, T> Drop for BitVec<C, T>
 where C: Cursor, T: BitStore {
@@ -403,6 +412,23 @@ where C: Cursor, T: BitStore {
 	}
 }
 
+impl<C, T> Deref for SecretBitVec<C, T>
+where C: Cursor, T: BitStore {
+	type Target = BitVec<C, T>;
+
+	fn deref(&self) -> &Self::Target {
+		&self.inner
+	}
+}
+
+/// Scrubs the allocation before the wrapped vector releases it.
+impl<C, T> Drop for SecretBitVec<C, T>
+where C: Cursor, T: BitStore {
+	fn drop(&mut self) {
+		self.inner.clear_zeroed();
+	}
+}
+
 /// Gets the bit at a specific index. The index must be less than the length of
 /// the `BitVec`.
 impl<C, T> Index<usize> for BitVec<C, T>
@@ -536,6 +562,104 @@ where C: Cursor, T: BitStore {
 	}
 }
 
+/** Multiplies two `BitVec`s together.
+
+`BitVec` multiplication works just like multiplying numbers longhand on paper.
+The first bits in the `BitVec` are the highest, and each set bit of the
+multiplier adds a correspondingly shifted copy of the multiplicand into the
+product.
+
+The output `BitVec` is exactly as long as both inputs together, which is wide
+enough to hold any product without overflow. It is zero-extended on the left
+when the product is narrower than that.
+
+Numeric arithmetic is provided on `BitVec` as a convenience. Serious numeric
+computation on variable-length integers should use the `num_bigint` crate
+instead, which is written specifically for that use case. `BitVec`s are not
+intended for arithmetic, and `bitvec` makes no guarantees about sustained
+correctness in arithmetic at this time.
+**/
+impl<C, T> Mul for BitVec<C, T>
+where C: Cursor, T: BitStore {
+	type Output = Self;
+
+	/// Multiplies two `BitVec`s.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let a = bitvec![1, 0];
+	/// let b = bitvec![1, 1];
+	/// let p = a * b;
+	/// assert_eq!(bitvec![0, 1, 1, 0], p);
+	/// ```
+	fn mul(mut self, multiplier: Self) -> Self::Output {
+		self *= multiplier;
+		self
+	}
+}
+
+/** Multiplies another `BitVec` into `self`.
+
+`BitVec` multiplication works just like multiplying numbers longhand on paper.
+The first bits in the `BitVec` are the highest, and each set bit of the
+multiplier adds a correspondingly shifted copy of the multiplicand into the
+product.
+
+The output `BitVec` is exactly as long as both inputs together, which is wide
+enough to hold any product without overflow. It is zero-extended on the left
+when the product is narrower than that.
+
+Numeric arithmetic is provided on `BitVec` as a convenience. Serious numeric
+computation on variable-length integers should use the `num_bigint` crate
+instead, which is written specifically for that use case. `BitVec`s are not
+intended for arithmetic, and `bitvec` makes no guarantees about sustained
+correctness in arithmetic at this time.
+**/
+impl<C, T> MulAssign for BitVec<C, T>
+where C: Cursor, T: BitStore {
+	/// Multiplies another `BitVec` into `self`.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let mut a = bitvec![1, 1, 1];
+	/// let b = bitvec![1, 1];
+	/// a *= b;
+	/// assert_eq!(a, bitvec![1, 0, 1, 0, 1]);
+	/// ```
+	fn mul_assign(&mut self, multiplier: Self) {
+		let len = self.len() + multiplier.len();
+		let mut product = BitVec::<C, T>::with_capacity(len);
+		product.resize(len, false);
+		//  Each set bit in the multiplier, counted from the right, adds the
+		//  multiplicand into the product with its last bit `shamt` bits from
+		//  the right edge.
+		let ones = multiplier.iter().rev().enumerate().filter(|&(_, b)| b);
+		for (shamt, _) in ones {
+			let mut c = false;
+			let mut addend = self.iter().rev();
+			for idx in (0 .. len - shamt).rev() {
+				let b = match addend.next() {
+					Some(b) => b,
+					//  Once the multiplicand expires, only a carry can change
+					//  the product.
+					None if c => false,
+					None => break,
+				};
+				let (y, z) = crate::rca1(product[idx], b, c);
+				product.set(idx, y);
+				c = z;
+			}
+		}
+		*self = product;
+	}
+}
+
 /** 2’s-complement negation of a `BitVec`.
 
 In 2’s-complement, negation is defined as bit-inversion followed by adding one.
@@ -83,21 +83,42 @@ where C: Cursor, T: BitStore {
 	///
 	/// This function works best when `self.capacity() - self.len()` is at least
 	/// the rotation distance.
-	pub fn rotate_left(&mut self, by: usize) {
+	///
+	/// # Returns
+	///
+	/// The rotated vector, so that further operations can be chained. The
+	/// length of the vector does not change.
+	///
+	/// # Examples
+	///
+	/// A ring buffer whose read head is always at the front:
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let mut ring = bitvec![1, 0, 0, 1, 1];
+	/// let mut read = Vec::new();
+	/// for _ in 0 .. ring.len() {
+	///   read.push(ring[0]);
+	///   ring.rotate_left(1);
+	/// }
+	/// assert_eq!(read, [true, false, false, true, true]);
+	/// assert_eq!(ring, bitvec![1, 0, 0, 1, 1]);
+	///
+	/// assert_eq!(ring.rotate_left(2).rotate_left(1), &bitvec![1, 1, 1, 0, 0]);
+	/// ```
+	pub fn rotate_left(&mut self, by: usize) -> &mut Self {
 		let len = self.len();
-		let by = by % len;
-
 		//  Exit immediately for noöp rotations.
-		if by == 0 {
-			return;
+		if len == 0 || by % len == 0 {
+			return self;
 		}
+		let by = by % len;
 
 		//  If the rotation distance exceeds `isize::max_value()`, shift twice.
 		let isz_max = isize::max_value() as usize;
 		if by > isz_max {
-			self.rotate_left(isz_max);
-			self.rotate_left(by - isz_max);
-			return;
+			return self.rotate_left(isz_max).rotate_left(by - isz_max);
 		}
 
 		//  Compute the size of the empty region in the buffer after the live
@@ -129,7 +150,7 @@ where C: Cursor, T: BitStore {
 					self.pointer.set_pointer(base_ptr);
 				}
 			}
-			return;
+			return self;
 		}
 
 		//  Ensure that the live span is aligned at the bottom edge.
@@ -174,6 +195,7 @@ where C: Cursor, T: BitStore {
 		for (b, i) in tmp.bits::<C>().iter().take(by).zip(mid ..) {
 			unsafe { self.set_unchecked(i, b); }
 		}
+		self
 	}
 
 	/// Rotates the contents of the vector right, in-place.
@@ -197,19 +219,32 @@ where C: Cursor, T: BitStore {
 	///
 	/// Right rotation will always require more effort than left rotation when
 	/// the fastest path is not available. Prefer `rotate_left` where possible.
-	pub fn rotate_right(&mut self, by: usize) {
+	///
+	/// # Returns
+	///
+	/// The rotated vector, so that further operations can be chained. The
+	/// length of the vector does not change.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let mut bv = bitvec![1, 1, 0, 0, 0];
+	/// assert_eq!(bv.rotate_right(1), &bitvec![0, 1, 1, 0, 0]);
+	/// assert_eq!(bv.rotate_right(7).len(), 5);
+	/// assert_eq!(bv, bitvec![0, 0, 0, 1, 1]);
+	/// ```
+	pub fn rotate_right(&mut self, by: usize) -> &mut Self {
 		let len = self.len();
-		let by = by % len;
-
-		if by == 0 {
-			return;
+		if len == 0 || by % len == 0 {
+			return self;
 		}
+		let by = by % len;
 
 		let isz_max = isize::max_value() as usize;
 		if by > isz_max {
-			self.rotate_right(isz_max);
-			self.rotate_right(by - isz_max);
-			return;
+			return self.rotate_right(isz_max).rotate_right(by - isz_max);
 		}
 
 		let orig_head = self.pointer.head();
@@ -224,7 +259,7 @@ where C: Cursor, T: BitStore {
 					self.copy(len, 0);
 				}
 			}
-			return;
+			return self;
 		}
 
 		let base_ptr = self.pointer.pointer();
@@ -283,5 +318,6 @@ where C: Cursor, T: BitStore {
 				self.pointer.set_pointer(base_ptr);
 			}
 		}
+		self
 	}
 }
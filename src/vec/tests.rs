@@ -3,7 +3,15 @@
 
 #![cfg(all(test, feature = "std"))]
 
-use crate::cursor::BigEndian;
+use crate::{
+	cursor::{
+		BigEndian,
+		LittleEndian,
+	},
+	pointer::BitPtr,
+	slice::BitSlice,
+	vec::BitVec,
+};
 
 //  Tests that the `rotate_left` function behaves as expected in all edge cases
 #[test]
@@ -52,3 +60,1149 @@ fn rotate_left_major() {
 		0, 0, 0, 0, 0, 0, 0,
 	]);
 }
+
+#[test]
+fn insert() {
+	let base = [true, false, false, true, true, false, true, false, false, true];
+	for index in 0 ..= base.len() {
+		for &value in &[false, true] {
+			let mut bv = base.iter().cloned().collect::<BitVec<BigEndian, u8>>();
+			let mut v = base.to_vec();
+			bv.insert(index, value);
+			v.insert(index, value);
+			assert_eq!(bv, v.iter().cloned().collect::<BitVec<BigEndian, u8>>());
+		}
+	}
+}
+
+#[test]
+fn remove() {
+	let mut bv = bitvec![1, 0, 1, 1, 0];
+	assert!(bv.remove(2));
+	assert_eq!(bv, bitvec![1, 0, 1, 0]);
+
+	let base = [
+		true, false, false, true, true, false, true, false,
+		false, true, true, false,
+	];
+	for index in 0 .. base.len() {
+		let mut bv = base.iter().cloned().collect::<BitVec<BigEndian, u8>>();
+		let mut v = base.to_vec();
+		let capacity = bv.capacity();
+		assert_eq!(bv.remove(index), v.remove(index));
+		assert_eq!(bv, v.iter().cloned().collect::<BitVec<BigEndian, u8>>());
+		assert_eq!(bv.capacity(), capacity);
+	}
+}
+
+#[test]
+fn front() {
+	//  Bits pushed onto the front leave from the back in FIFO order.
+	let mut bv = BitVec::<BigEndian, u8>::new();
+	bv.push_front(true);
+	bv.push_front(false);
+	bv.push_front(true);
+	assert_eq!(bv, bitvec![1, 0, 1]);
+	bv.push_front(true);
+	assert_eq!(bv.pop(), Some(true));
+	assert_eq!(bv.pop(), Some(false));
+	assert_eq!(bv.pop(), Some(true));
+
+	//  Bits pushed onto the back leave from the front in FIFO order.
+	let mut bv = bitvec![BigEndian, u16; 0; 15];
+	for &bit in &[true, false, true] {
+		bv.push(bit);
+	}
+	for _ in 0 .. 15 {
+		assert_eq!(bv.pop_front(), Some(false));
+	}
+	assert_eq!(bv.pop_front(), Some(true));
+	assert_eq!(bv.pop_front(), Some(false));
+	assert_eq!(bv.pop_front(), Some(true));
+	assert!(bv.pop_front().is_none());
+}
+
+#[test]
+fn rotate() {
+	let base = bitvec![BigEndian, u8; 1, 0, 1, 1, 0, 0, 1, 0, 1, 1, 1];
+	for by in 0 ..= base.len() {
+		let mut bv = base.clone();
+		let mut bits = base.clone();
+		bv.rotate_left(by);
+		bits.as_bits_mut().rotate_left(by);
+		assert_eq!(bv, bits);
+		assert_eq!(bv.len(), base.len());
+
+		bv.rotate_right(by);
+		bits.as_bits_mut().rotate_right(by);
+		assert_eq!(bv, bits);
+		assert_eq!(bv, base);
+	}
+	let mut bv = base.clone();
+	assert_eq!(bv.rotate_left(3).rotate_right(3), &base);
+
+	//  Distances wrap around the length, and empty vectors do not rotate.
+	let mut bits = base.clone();
+	bits.as_bits_mut().rotate_left(2);
+	assert_eq!(bv.rotate_left(base.len() * 3 + 2), &bits);
+	let mut bv = BitVec::<BigEndian, u8>::new();
+	assert!(bv.rotate_left(5).rotate_right(3).is_empty());
+}
+
+#[test]
+fn retain() {
+	let mut bv = bitvec![1, 1, 0, 0, 1, 1];
+	bv.retain(|idx, _| idx % 2 == 0);
+	assert_eq!(bv, bitvec![1, 0, 1]);
+
+	let mut bv = bitvec![BigEndian, u8; 0, 1, 1, 0, 1, 0, 0, 1, 1, 1, 0, 1];
+	let mut seen = Vec::new();
+	bv.retain(|idx, bit| {
+		seen.push(idx);
+		bit
+	});
+	assert_eq!(seen, (0 .. 12).collect::<Vec<_>>());
+	assert_eq!(bv, bitvec![1; 7]);
+}
+
+#[test]
+fn dedup() {
+	let mut bv = bitvec![BigEndian, u16; 1, 1, 0, 1, 0, 0, 0, 1, 1];
+	bv.dedup();
+	assert_eq!(bv, bitvec![1, 0, 1, 0, 1]);
+
+	let mut bv = bitvec![0, 1, 1, 0, 1];
+	bv.dedup_by(|_, _| true);
+	assert_eq!(bv, bitvec![0]);
+
+	let mut bv = bitvec![0, 1, 1, 0, 1];
+	bv.dedup_by_key(|bit| !bit);
+	assert_eq!(bv, bitvec![0, 1, 0, 1]);
+
+	let mut bv = bitvec![1];
+	bv.dedup_by(|_, _| true);
+	assert_eq!(bv, bitvec![1]);
+	let mut bv = BitVec::<BigEndian, u8>::new();
+	bv.dedup();
+	assert!(bv.is_empty());
+}
+
+#[test]
+fn drain() {
+	let mut bv = bitvec![BigEndian, u8; 0, 0, 1, 1, 0, 1, 0, 0, 1, 1, 1, 0];
+	let drained = bv.drain(2 .. 10).collect::<Vec<_>>();
+	assert_eq!(
+		drained,
+		&[true, true, false, true, false, false, true, true],
+	);
+	assert_eq!(bv, bitvec![0, 0, 1, 0]);
+
+	//  A partially consumed drain still removes its entire range.
+	let mut bv = bitvec![BigEndian, u8; 0, 0, 1, 1, 0, 1, 0, 0, 1, 1, 1, 0];
+	{
+		let mut drain = bv.drain(2 .. 10);
+		assert_eq!(drain.next(), Some(true));
+		assert_eq!(drain.next_back(), Some(true));
+	}
+	assert_eq!(bv, bitvec![0, 0, 1, 0]);
+
+	//  A forgotten drain leaves the vector truncated to the drain start.
+	let mut bv = bitvec![BigEndian, u8; 0, 0, 1, 1, 0, 1, 0, 0, 1, 1, 1, 0];
+	core::mem::forget(bv.drain(5 ..= 7));
+	assert_eq!(bv, bitvec![0, 0, 1, 1, 0]);
+}
+
+#[test]
+fn resize() {
+	let mut bv = bitvec![BigEndian, u8; 0, 1, 0];
+	bv.resize(10, true);
+	assert_eq!(bv, bitvec![0, 1, 0, 1, 1, 1, 1, 1, 1, 1]);
+	bv.resize(4, false);
+	assert_eq!(bv, bitvec![0, 1, 0, 1]);
+
+	//  Regrowing over dead bits must overwrite them.
+	bv.resize(12, false);
+	assert_eq!(bv, bitvec![0, 1, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0]);
+
+	let mut bv: BitVec<BigEndian, u16> = BitVec::new();
+	bv.resize(100, true);
+	assert_eq!(bv.len(), 100);
+	assert!(bv.all());
+	assert!(bv.capacity() >= 100);
+
+	let mut bv = bitvec![BigEndian, u8; 1; 3];
+	bv.resize_with(10, || false);
+	assert_eq!(bv, bitvec![1, 1, 1, 0, 0, 0, 0, 0, 0, 0]);
+}
+
+#[test]
+fn swap_remove() {
+	let mut bv = bitvec![1, 0, 1, 1];
+	assert!(!bv.swap_remove(1));
+	assert_eq!(bv, bitvec![1, 1, 1]);
+
+	let mut bv = bitvec![1, 0, 1, 0];
+	assert!(!bv.swap_remove(3));
+	assert_eq!(bv, bitvec![1, 0, 1]);
+
+	assert!(std::panic::catch_unwind(|| {
+		let mut bv = bitvec![1, 0, 1];
+		bv.swap_remove(3);
+	}).is_err());
+}
+
+#[test]
+fn ordering() {
+	use std::collections::BTreeSet;
+
+	assert!(bitvec![1, 0] > bitvec![0, 1, 1]);
+	assert!(bitvec![1, 0] < bitvec![1, 0, 0]);
+	assert!(bitvec![BigEndian, u8; 0, 1] < bitvec![LittleEndian, u32; 1]);
+
+	let mut set = BTreeSet::new();
+	set.insert(bitvec![1, 0, 0]);
+	set.insert(bitvec![0, 1, 1]);
+	set.insert(bitvec![1, 0]);
+	set.insert(bitvec![]);
+	set.insert(bitvec![1, 0]);
+	assert_eq!(set.len(), 4);
+	assert_eq!(
+		set.into_iter().collect::<Vec<_>>(),
+		vec![bitvec![], bitvec![0, 1, 1], bitvec![1, 0], bitvec![1, 0, 0]],
+	);
+}
+
+#[test]
+fn hash() {
+	use std::{
+		collections::{
+			hash_map::DefaultHasher,
+			HashMap,
+		},
+		hash::{
+			Hash,
+			Hasher,
+		},
+	};
+
+	fn hash_of<H: Hash + ?Sized>(item: &H) -> u64 {
+		let mut hasher = DefaultHasher::new();
+		item.hash(&mut hasher);
+		hasher.finish()
+	}
+
+	let mut map = HashMap::new();
+	map.insert(bitvec![1, 0, 1], "five");
+	map.insert(bitvec![1, 0, 1, 0], "ten");
+	assert_eq!(map.get(&bitvec![1, 0, 1]), Some(&"five"));
+	assert_eq!(map.get(&bitvec![1, 0, 1, 0]), Some(&"ten"));
+	assert!(map.get(&bitvec![1, 0]).is_none());
+
+	//  Dead bits in the edge elements are not hashed.
+	let a = [0xA5u8, 0xAF];
+	let b = [0x5A00_0000u32];
+	let c = [0xFEB5u16];
+	let a = &BitSlice::<BigEndian, _>::from_slice(&a)[4 .. 12];
+	let b = &BitSlice::<BigEndian, _>::from_slice(&b)[.. 8];
+	let c = &BitSlice::<LittleEndian, _>::from_slice(&c)[1 .. 9];
+	assert_eq!(a, b);
+	assert_eq!(a, c);
+	assert_eq!(hash_of(a), hash_of(b));
+	assert_eq!(hash_of(a), hash_of(c));
+	assert_eq!(hash_of(a), hash_of(&a.to_owned()));
+
+	assert_ne!(
+		hash_of(&(bitvec![1], bitvec![0, 1])),
+		hash_of(&(bitvec![1, 0], bitvec![1])),
+	);
+}
+
+#[test]
+fn split_off() {
+	let mut bv = bitvec![1, 0, 1, 1, 0];
+	let tail = bv.split_off(2);
+	assert_eq!(bv, bitvec![1, 0]);
+	assert_eq!(tail, bitvec![1, 1, 0]);
+	assert_ne!(bv.as_ptr(), tail.as_ptr());
+
+	let mut bv = bitvec![BigEndian, u8; 0, 1, 1];
+	let cap = bv.capacity();
+	let all = bv.split_off(0);
+	assert!(bv.is_empty());
+	assert_eq!(bv.capacity(), cap);
+	assert_eq!(all, bitvec![0, 1, 1]);
+
+	let mut bv = bitvec![BigEndian, u8; 0, 1, 1];
+	assert!(bv.split_off(3).is_empty());
+	assert_eq!(bv, bitvec![0, 1, 1]);
+}
+
+#[test]
+#[should_panic]
+fn split_off_beyond() {
+	bitvec![0, 1].split_off(3);
+}
+
+#[test]
+fn extend_from_bitslice() {
+	use crate::{
+		bits::Bits,
+		cursor::LittleEndian,
+	};
+
+	let src = [0xA5u16, 0x0FF0, 0x1234];
+	let bits = src.bits::<LittleEndian>();
+
+	//  Every combination of vector length and slice start, which covers the
+	//  aligned, offset-aligned, and misaligned paths.
+	for len in 0 .. 20 {
+		for start in [0, 3, 16, 19].iter().cloned() {
+			let mut bv = bitvec![LittleEndian, u16; 1; len];
+			let mut expected = bv.clone();
+			expected.extend(bits[start ..].iter());
+			bv.extend_from_bitslice(&bits[start ..]);
+			assert_eq!(bv, expected, "len {}, start {}", len, start);
+		}
+	}
+
+	let mut bv = bitvec![BigEndian, u8; 1; 8];
+	bv.extend_from_bitslice(&[0x3Cu8, 0xC3].bits::<BigEndian>()[.. 12]);
+	assert_eq!(bv.len(), 20);
+	assert_eq!(&bv.as_slice()[.. 2], &[0xFF, 0x3C]);
+	assert_eq!(bv[16 ..], bitvec![1, 1, 0, 0]);
+
+	let mut bv = bitvec![BigEndian, u8; 1, 0, 1];
+	bv.extend_from_bitslice(BitSlice::empty());
+	assert_eq!(bv, bitvec![1, 0, 1]);
+	assert_eq!(bv.as_slice(), &[0b1010_0000]);
+}
+
+#[test]
+fn push_bits() {
+	let mut bv = BitVec::<BigEndian, u8>::new();
+	bv.push_bits(0b101, 3);
+	assert_eq!(bv, bitvec![1, 0, 1]);
+	assert_eq!(bv.as_slice(), &[0b1010_0000]);
+
+	//  Fields are written most significant bit first, and cross elements.
+	bv.push_bits(0x1F0, 9);
+	assert_eq!(bv.len(), 12);
+	assert_eq!(bv.load_bits(3, 9), 0x1F0);
+	assert_eq!(bv.as_slice(), &[0b1011_1111, 0b0000_0000]);
+
+	bv.push_bits(!0, 0);
+	assert_eq!(bv.len(), 12);
+
+	let mut bv = BitVec::<BigEndian, u32>::with_capacity(64);
+	bv.push_bits(0x0123_4567_89AB_CDEF, 64);
+	assert_eq!(bv.len(), 64);
+	assert_eq!(bv.as_slice(), &[0x0123_4567, 0x89AB_CDEF]);
+}
+
+#[test]
+#[should_panic]
+fn push_bits_too_wide() {
+	BitVec::<BigEndian, u8>::new().push_bits(0, 65);
+}
+
+#[test]
+fn push_bits_lsb0() {
+	//  Least significant bit first, under `LittleEndian`, packs fields into
+	//  elements in ascending order of significance.
+	let mut bv = BitVec::<LittleEndian, u8>::new();
+	bv.push_bits_lsb0(0b101, 3);
+	bv.push_bits_lsb0(0b0_1100, 5);
+	assert_eq!(bv, bitvec![1, 0, 1, 0, 0, 1, 1, 0]);
+	assert_eq!(bv.as_slice(), &[0b0110_0101]);
+
+	bv.push_bits_lsb0(0x1F0, 9);
+	assert_eq!(bv.len(), 17);
+	assert_eq!(bv[8 ..], bitvec![0, 0, 0, 0, 1, 1, 1, 1, 1]);
+	assert_eq!(bv.as_slice(), &[0x65, 0xF0, 0x01]);
+
+	//  The most-significant-first order is unaffected by the cursor.
+	let mut bv = BitVec::<LittleEndian, u8>::new();
+	bv.push_bits(0b110, 3);
+	assert_eq!(bv, bitvec![1, 1, 0]);
+
+	let mut bv = BitVec::<BigEndian, u16>::with_capacity(64);
+	bv.push_bits_lsb0(0x0123_4567_89AB_CDEF, 64);
+	assert_eq!(bv.len(), 64);
+	assert_eq!(bv.as_slice(), &[0xF7B3, 0xD591, 0xE6A2, 0xC480]);
+}
+
+#[test]
+#[should_panic]
+fn push_bits_lsb0_too_wide() {
+	BitVec::<LittleEndian, u8>::new().push_bits_lsb0(0, 65);
+}
+
+#[test]
+fn from_bools() {
+	let bv = BitVec::<BigEndian, u8>::from([true, false, true, true]);
+	assert_eq!(bv.len(), 4);
+	assert_eq!(bv, bitvec![1, 0, 1, 1]);
+	assert_eq!(bv.as_slice(), &[0b1011_0000]);
+
+	let bv = BitVec::<BigEndian, u8>::from(false);
+	assert_eq!(bv, bitvec![0]);
+	assert_eq!(BitVec::<BigEndian, u8>::from(true), bitvec![1]);
+
+	assert!(BitVec::<BigEndian, u8>::from([]).is_empty());
+	assert_eq!(BitVec::<BigEndian, u16>::from([true; 32]).count_ones(), 32);
+}
+
+#[test]
+fn collect_reserves() {
+	//  Known-length sources allocate once, without growth headroom.
+	let src = (0 .. 100_000).map(|i| i % 2 == 0).collect::<Vec<bool>>();
+	let bv = src.iter().cloned().collect::<BitVec<BigEndian, u8>>();
+	assert_eq!(bv.len(), 100_000);
+	assert_eq!(bv.capacity(), 100_000);
+	assert_eq!(bv.count_ones(), 50_000);
+
+	let mut bv = BitVec::<BigEndian, u8>::new();
+	bv.extend(src.iter().cloned());
+	assert_eq!(bv.capacity(), 100_000);
+
+	//  Inexact upper bounds are not used as reservations, even when a vector
+	//  could hold them.
+	let max = BitPtr::<u8>::MAX_INDX - 1;
+	let bv = (0 .. max)
+		.map(|_| false)
+		.take_while(|b| *b)
+		.collect::<BitVec<BigEndian, u8>>();
+	assert!(bv.is_empty());
+	assert_eq!(bv.capacity(), 0);
+	let bv = (0 .. usize::max_value())
+		.map(|n| n < 10)
+		.take_while(|b| *b)
+		.collect::<BitVec<BigEndian, u8>>();
+	assert_eq!(bv, bitvec![1; 10]);
+	assert!(bv.capacity() < 1 << 10);
+
+	let mut bv = bitvec![BigEndian, u8; 1; 3];
+	bv.extend((0 .. max).map(|n| n % 2 == 0).filter(|b| *b).take(2));
+	assert_eq!(bv, bitvec![1; 5]);
+	assert!(bv.capacity() < 1 << 10);
+	bv.extend((0 .. max - 5).map(|_| true).take_while(|b| !*b));
+	assert_eq!(bv.len(), 5);
+}
+
+#[test]
+fn from_runs() {
+	let runs = [(false, 4), (true, 16), (false, 11), (true, 1)];
+	let bv = BitVec::<BigEndian, u8>::from_runs(runs.iter().cloned());
+	assert_eq!(bv.len(), 32);
+	assert_eq!(bv.as_slice(), &[0x0F, 0xFF, 0xF0, 0x01]);
+
+	//  Round trip through the run-length encoding, across element edges.
+	let src = [0x0FF0_00A5u32, 0xFFFF_FFFF, 0, 0x8000_0001];
+	let bits = BitSlice::<LittleEndian, _>::from_slice(&src);
+	for (from, upto) in [(0, 128), (3, 97), (40, 41), (5, 5)].iter().cloned() {
+		let bits = &bits[from .. upto];
+		let bv = BitVec::<LittleEndian, u32>::from_runs(bits.runs());
+		assert_eq!(bv, bits);
+	}
+
+	//  Empty and repeated runs are accepted.
+	let runs = [(true, 0), (true, 2), (true, 3), (false, 0)];
+	let bv = BitVec::<BigEndian, u8>::from_runs(runs.iter().cloned());
+	assert_eq!(bv, bitvec![1; 5]);
+	assert!(BitVec::<BigEndian, u8>::from_runs(None).is_empty());
+}
+
+#[test]
+fn set_uninitialized() {
+	let mut bv = bitvec![LittleEndian, u16; 1, 0, 1, 1];
+	bv.extend(bitvec![LittleEndian, u16; 0; 16]);
+	let orig = bv.clone();
+	assert_eq!(bv.as_slice(), &[0b1101, 0]);
+
+	bv.set_uninitialized(true);
+	assert_eq!(bv, orig);
+	assert_eq!(bv.count_ones(), 3);
+	assert_eq!(bv.as_slice(), &[0b1101, 0xFFF0]);
+
+	bv.set_uninitialized(false);
+	assert_eq!(bv, orig);
+	assert_eq!(bv.as_slice(), orig.as_slice());
+
+	//  Full last elements and empty vectors have no dead bits to set.
+	let mut bv = bitvec![BigEndian, u8; 0; 16];
+	bv.set_uninitialized(true);
+	assert_eq!(bv.as_slice(), &[0, 0]);
+	let mut bv = BitVec::<BigEndian, u8>::new();
+	bv.set_uninitialized(true);
+	assert!(bv.is_empty());
+
+	//  Only the dead bits after the live bits are set.
+	let mut bv = bitvec![BigEndian, u8; 0, 1, 1, 0];
+	bv.pop_front();
+	bv.set_uninitialized(true);
+	assert_eq!(bv, bitvec![1, 1, 0]);
+	assert_eq!(bv.as_slice()[0] & 0b1110_0000, 0b1100_0000);
+	assert_eq!(bv.as_slice()[0] & 0b0001_1111, 0b0001_1111);
+}
+
+#[test]
+fn extend_from_within() {
+	let mut bv = bitvec![BigEndian, u8; 1, 0, 1];
+	bv.extend_from_within(0 .. 2);
+	assert_eq!(bv, bitvec![1, 0, 1, 1, 0]);
+
+	bv.extend_from_within(..);
+	assert_eq!(bv, bitvec![1, 0, 1, 1, 0, 1, 0, 1, 1, 0]);
+	bv.extend_from_within(4 ..= 4);
+	bv.extend_from_within(3 .. 3);
+	assert_eq!(bv.len(), 11);
+	assert!(!bv[10]);
+
+	//  Growth past the original allocation copies from the live buffer.
+	let mut bv = BitVec::<BigEndian, u8>::with_capacity(8);
+	bv.extend([0xA5u8, 0x3C].iter());
+	bv.shrink_to_fit();
+	for _ in 0 .. 4 {
+		bv.extend_from_within(3 .. 16);
+	}
+	assert_eq!(bv.len(), 16 + 13 * 4);
+	assert!(bv[16 ..].chunks(13).all(|c| c == &bv[3 .. 16]));
+}
+
+#[test]
+#[should_panic]
+fn extend_from_within_out_of_range() {
+	bitvec![BigEndian, u8; 1, 0].extend_from_within(1 .. 3);
+}
+
+#[test]
+fn repeat() {
+	let bv = bitvec![1, 0];
+	assert_eq!(bv.repeat(3), bitvec![1, 0, 1, 0, 1, 0]);
+	assert!(bv.repeat(0).is_empty());
+	assert!(BitSlice::<BigEndian, u8>::empty().repeat(5).is_empty());
+
+	//  Repetitions that cross element boundaries at varying offsets.
+	let bv = bitvec![BigEndian, u8; 1, 1, 0, 1, 0];
+	let rep = bv.repeat(7);
+	assert_eq!(rep.len(), 35);
+	for (idx, chunk) in rep.chunks(5).enumerate() {
+		assert_eq!(chunk, &bv, "repetition {}", idx);
+	}
+}
+
+#[test]
+fn set_operations() {
+	let long = bitvec![BigEndian, u8; 0, 1, 0, 1, 1, 0, 1, 1, 0, 1];
+	let short = bitvec![BigEndian, u16; 1, 1, 0, 0];
+
+	//  Where both operands have bits, the results match the operators.
+	let mut or = long.clone();
+	or[.. 4] |= short.iter();
+	assert_eq!(long.union(&short), or);
+	assert_eq!(short.union(&long), or);
+
+	let mut and = long.clone();
+	and[..] &= short.iter();
+	assert_eq!(long.intersection(&short), and);
+	assert_eq!(short.intersection(&long), and);
+
+	let mut xor = long.clone();
+	xor[.. 4] ^= short.iter();
+	assert_eq!(long.symmetric_difference(&short), xor);
+	assert_eq!(short.symmetric_difference(&long), xor);
+
+	//  The operators truncate to the shorter operand; the methods do not.
+	let mut truncated = short.clone();
+	truncated |= long.iter();
+	assert_eq!(truncated.len(), 4);
+	assert_eq!(short.union(&long).len(), 10);
+
+	let mut diff = long.clone();
+	diff[.. 4] &= short.iter().map(|b| !b);
+	assert_eq!(long.difference(&short), diff);
+	assert_eq!(
+		short.difference(&long),
+		bitvec![1, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+	);
+
+	let empty = BitVec::<BigEndian, u8>::new();
+	assert!(empty.union(&empty).is_empty());
+	assert_eq!(empty.intersection(&long), bitvec![0; 10]);
+}
+
+#[test]
+fn from_bytes() {
+	let bytes = [0b0110_1001u8];
+
+	let be = BitSlice::<BigEndian, u8>::from_slice(&bytes);
+	assert_eq!(be.len(), 8);
+	assert_eq!(be, &bitvec![0, 1, 1, 0, 1, 0, 0, 1]);
+	let le = BitSlice::<LittleEndian, u8>::from_slice(&bytes);
+	assert_eq!(le.len(), 8);
+	assert_eq!(le, &bitvec![1, 0, 0, 1, 0, 1, 1, 0]);
+
+	let bv = BitVec::<BigEndian, u8>::from_bytes(&bytes);
+	assert_eq!(bv, be);
+	assert_eq!(bv.as_slice(), &bytes);
+	let bv = BitVec::<LittleEndian, u8>::from_bytes(&bytes);
+	assert_eq!(bv, le);
+
+	let bv = BitVec::<BigEndian, u8>::from_bytes(&[0xA5, 0x0F, 0xFF]);
+	assert_eq!(bv.len(), 24);
+	assert_eq!(bv.count_ones(), 16);
+	assert!(BitVec::<BigEndian, u8>::from_bytes(&[]).is_empty());
+}
+
+#[test]
+fn from_bytes_len() {
+	let bytes = [0b1011_0011u8, 0b0110_1111];
+	let bv = BitVec::<BigEndian, u8>::from_bytes_len(&bytes, 13);
+	assert_eq!(bv.len(), 13);
+	assert_eq!(bv, bitvec![1, 0, 1, 1, 0, 0, 1, 1, 0, 1, 1, 0, 1]);
+
+	let bv = BitVec::<LittleEndian, u8>::from_bytes_len(&bytes, 13);
+	assert_eq!(bv, bitvec![1, 1, 0, 0, 1, 1, 0, 1, 1, 1, 1, 1, 0]);
+
+	let bv = BitVec::<BigEndian, u8>::from_bytes_len(&bytes, 8);
+	assert_eq!(bv.as_slice(), &[0b1011_0011]);
+	assert!(BitVec::<BigEndian, u8>::from_bytes_len(&bytes, 0).is_empty());
+	assert!(BitVec::<BigEndian, u8>::from_bytes_len(&[], 0).is_empty());
+}
+
+#[test]
+#[should_panic]
+fn from_bytes_len_overlong() {
+	BitVec::<BigEndian, u8>::from_bytes_len(&[0, 0], 17);
+}
+
+#[test]
+fn extend_bytes() {
+	let mut bv = BitVec::<BigEndian, u8>::new();
+	bv.extend(vec![0x0Fu8]);
+	assert_eq!(bv, bitvec![0, 0, 0, 0, 1, 1, 1, 1]);
+	assert_eq!(bv.as_slice(), &[0x0F]);
+
+	bv.extend(&[0xA5u8, 0x3C]);
+	assert_eq!(bv.len(), 24);
+	assert_eq!(bv.as_slice(), &[0x0F, 0xA5, 0x3C]);
+
+	//  Unaligned vectors append bit by bit.
+	let mut bv = bitvec![BigEndian, u8; 1, 1, 1];
+	bv.extend(vec![0x0Fu8, 0x80]);
+	assert_eq!(bv.len(), 19);
+	assert_eq!(bv[.. 3], bitvec![1, 1, 1]);
+	assert_eq!(bv[3 .. 11], bitvec![0, 0, 0, 0, 1, 1, 1, 1]);
+	assert_eq!(bv[11 ..], bitvec![1, 0, 0, 0, 0, 0, 0, 0]);
+
+	//  A vector whose live region starts inside its first element is
+	//  unaligned even when its length is a multiple of eight.
+	let src = [0xFFu8, 0xFF];
+	let mut bv = BitSlice::<BigEndian, u8>::from_slice(&src)[4 .. 12]
+		.to_owned();
+	bv.extend(vec![0u8]);
+	assert_eq!(bv.len(), 16);
+	assert_eq!(bv.count_ones(), 8);
+	assert!(bv[8 ..].not_any());
+}
+
+#[test]
+fn collect_bytes() {
+	let bv: BitVec<LittleEndian, u8> = vec![0xFFu8, 0x00].into_iter().collect();
+	assert_eq!(bv.len(), 16);
+	assert!(bv[.. 8].all());
+	assert!(bv[8 ..].not_any());
+
+	let bv: BitVec<BigEndian, u8> = [0x80u8, 0x01].iter().cloned().collect();
+	assert_eq!(bv, bitvec![1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1]);
+
+	let bools: BitVec<BigEndian, u8> = vec![true, false].into_iter().collect();
+	assert_eq!(bools, bitvec![1, 0]);
+}
+
+#[test]
+fn append() {
+	let mut bv = bitvec![0, 0, 0];
+	let mut other = bitvec![1, 1];
+	bv.append(&mut other);
+	assert_eq!(bv, bitvec![0, 0, 0, 1, 1]);
+	assert!(other.is_empty());
+
+	let mut bv = bitvec![BigEndian, u8; 1; 7];
+	let mut other = bitvec![LittleEndian, u32; 0; 40];
+	other.set(0, true);
+	other.set(39, true);
+	let cap = other.capacity();
+	bv.append(&mut other);
+	assert_eq!(bv.len(), 47);
+	assert_eq!(bv.count_ones(), 9);
+	assert!(bv[7] && bv[46]);
+	assert!(bv[8 .. 46].not_any());
+	assert!(other.is_empty());
+	assert_eq!(other.capacity(), cap);
+
+	//  Matching layouts append by element once `self` reaches a boundary.
+	let mut bv = bitvec![LittleEndian, u16; 1; 3];
+	let mut other = BitVec::<LittleEndian, u16>::from_slice(&[0x1234, 0xABCD]);
+	other.truncate(29);
+	bv.append(&mut other);
+	assert_eq!(bv.len(), 32);
+	assert_eq!(bv.as_slice(), &[0x91A7, 0x5E68]);
+
+	let mut bv = bitvec![BigEndian, u8; 0; 8];
+	let mut other = BitVec::<BigEndian, u8>::from_slice(&[0xC3, 0x5A]);
+	bv.append(&mut other);
+	assert_eq!(bv.as_slice(), &[0, 0xC3, 0x5A]);
+	assert!(other.is_empty());
+}
+
+#[test]
+fn concat_join() {
+	let codes = [bitvec![1, 0], bitvec![1], bitvec![0, 0]];
+	let bv = BitVec::concat(&codes);
+	assert_eq!(bv, bitvec![1, 0, 1, 0, 0]);
+	assert!(bv.capacity() >= 5);
+
+	let sep = bitvec![0, 1];
+	assert_eq!(
+		BitVec::join(&codes, &sep),
+		bitvec![1, 0, 0, 1, 1, 0, 1, 0, 0],
+	);
+
+	let slices: [&BitSlice; 2] = [&codes[0][..], &codes[1][..]];
+	assert_eq!(BitVec::concat(&slices[..]), bitvec![1, 0, 1]);
+	assert_eq!(BitVec::join(&slices[.. 1], &sep), bitvec![1, 0]);
+
+	let empty: [BitVec; 0] = [];
+	assert!(BitVec::concat(&empty).is_empty());
+	assert!(BitVec::join(&empty, &sep).is_empty());
+}
+
+#[test]
+fn mul() {
+	//  2 × 3 = 6
+	assert_eq!(bitvec![1, 0] * bitvec![1, 1], bitvec![0, 1, 1, 0]);
+
+	//  13 × 11 = 143, which carries through the whole product.
+	let p = bitvec![BigEndian, u8; 1, 1, 0, 1]
+		* bitvec![BigEndian, u8; 1, 0, 1, 1];
+	assert_eq!(p, bitvec![1, 0, 0, 0, 1, 1, 1, 1]);
+
+	//  255 × 255 = 65025 fills the full width.
+	let mut a = bitvec![BigEndian, u16; 1; 8];
+	a *= bitvec![BigEndian, u16; 1; 8];
+	assert_eq!(a, bitvec![
+		1, 1, 1, 1, 1, 1, 1, 0,
+		0, 0, 0, 0, 0, 0, 0, 1,
+	]);
+
+	assert_eq!(bitvec![1, 0, 1] * bitvec![0, 0], bitvec![0; 5]);
+	assert_eq!(bitvec![1, 0, 1] * bitvec![], bitvec![0; 3]);
+}
+
+#[test]
+fn shrink_to_fit() {
+	let mut bv = BitVec::<BigEndian, u8>::new();
+	for n in 0 .. 1000 {
+		bv.push(n % 3 == 0);
+	}
+	let cap = bv.capacity();
+	assert!(cap >= 1000);
+
+	bv.truncate(10);
+	assert_eq!(bv.capacity(), cap);
+	bv.shrink_to_fit();
+	assert!(bv.capacity() < cap);
+	assert!(bv.capacity() >= 10);
+	assert_eq!(bv, bitvec![1, 0, 0, 1, 0, 0, 1, 0, 0, 1]);
+
+	//  The vector is still usable after reallocation.
+	bv.push(true);
+	assert_eq!(bv.len(), 11);
+	assert!(bv[10]);
+
+	let mut bv = BitVec::<BigEndian, u32>::with_capacity(512);
+	bv.shrink_to_fit();
+	assert_eq!(bv.capacity(), 0);
+	assert!(bv.is_empty());
+}
+
+#[test]
+fn into_boxed_bitslice() {
+	let mut bv = BitVec::<BigEndian, u16>::with_capacity(4096);
+	for n in 0 .. 40 {
+		bv.push(n % 5 == 0);
+	}
+	let expected = bv.clone();
+	let bb = bv.into_boxed_bitslice();
+	assert_eq!(bb.len(), 40);
+	assert_eq!(bb.bitptr().elements(), 3);
+	assert_eq!(bb.as_bits(), &expected[..]);
+
+	//  Spans that do not begin at the front of the allocation are moved down.
+	let mut bv = BitVec::<BigEndian, u8>::with_capacity(256);
+	bv.extend(expected.iter().take(13));
+	bv.rotate_left(3);
+	assert_ne!(*bv.bitptr().head(), 0);
+	let mut rotated = expected[.. 13].to_owned();
+	rotated.as_bits_mut().rotate_left(3);
+	let bb = bv.into_boxed_bitslice();
+	assert_eq!(*bb.bitptr().head(), 0);
+	assert_eq!(bb.bitptr().elements(), 2);
+	assert_eq!(bb.as_bits(), &rotated[..]);
+	assert_eq!(BitVec::from_boxed_bitslice(bb).capacity(), 16);
+
+	let bb = BitVec::<BigEndian, u8>::with_capacity(64).into_boxed_bitslice();
+	assert!(bb.is_empty());
+	assert_eq!(bb.bitptr().elements(), 0);
+}
+
+#[test]
+fn reserve_exact() {
+	let mut bv = bitvec![BigEndian, u16; 1; 5];
+	bv.shrink_to_fit();
+	assert_eq!(bv.element_capacity(), 1);
+
+	//  35 bits need exactly three `u16` elements.
+	bv.reserve_exact(30);
+	assert_eq!(bv.element_capacity(), 3);
+	assert_eq!(bv.capacity(), 48);
+
+	//  Requests which already fit do not reallocate.
+	bv.reserve_exact(43);
+	assert_eq!(bv.element_capacity(), 3);
+	bv.reserve(10);
+	assert_eq!(bv.element_capacity(), 3);
+	assert_eq!(bv, bitvec![1; 5]);
+
+	let mut bv = BitVec::<BigEndian, u32>::new();
+	bv.reserve_exact(1);
+	assert_eq!(bv.element_capacity(), 1);
+	bv.reserve_exact(0);
+	assert_eq!(bv.element_capacity(), 1);
+}
+
+#[test]
+fn clear_zeroed() {
+	let mut bv = BitVec::<BigEndian, u16>::with_capacity(100);
+	bv.extend(core::iter::repeat(true).take(40));
+	bv.truncate(20);
+	bv.rotate_left(3);
+	let (base, elts) = (bv.pointer.pointer().r(), bv.capacity);
+	assert!(elts >= 3);
+
+	bv.clear_zeroed();
+	assert!(bv.is_empty());
+	assert_eq!(bv.capacity, elts);
+	assert_eq!(bv.pointer.pointer().r(), base);
+	let raw = unsafe { core::slice::from_raw_parts(base, elts) };
+	assert!(raw.iter().all(|&elt| elt == 0));
+
+	bv.push(true);
+	assert_eq!(bv, bitvec![1]);
+}
+
+#[test]
+fn secret_bitvec() {
+	use crate::vec::SecretBitVec;
+
+	let bv = BitVec::<BigEndian, u16>::with_capacity(64);
+	let mut secret = SecretBitVec::new(bv);
+	let cap = secret.capacity();
+	secret.extend(core::iter::repeat(true).take(40));
+	secret.truncate(20);
+	assert_eq!(secret.count_ones(), 20);
+	assert_eq!(secret.capacity(), cap);
+	//  The contents never appear in debug output.
+	assert_eq!(format!("{:?}", secret), "SecretBitVec { len: 20 }");
+
+	//  Unwrapping hands back the vector without scrubbing it.
+	let bv = secret.into_inner();
+	assert_eq!(bv.len(), 20);
+	assert!(bv.all());
+
+	//  Dropping scrubs through `clear_zeroed`, which is checked above.
+	let mut secret = SecretBitVec::from(bv);
+	assert_eq!(secret.len(), 20);
+	secret.as_mut_bitslice().set(3, false);
+	assert_eq!(secret.count_ones(), 19);
+	secret.clear();
+	assert!(secret.is_empty());
+	assert_eq!(secret.capacity(), cap);
+	drop(secret);
+}
+
+#[test]
+#[should_panic]
+fn secret_bitvec_full() {
+	use crate::vec::SecretBitVec;
+
+	let mut secret = SecretBitVec::new(bitvec![BigEndian, u8; 1; 8]);
+	secret.truncate(6);
+	secret.extend(vec![false; 2]);
+	assert_eq!(secret.len(), 8);
+	//  The buffer is full, and growing it would release the old allocation.
+	secret.push(true);
+}
+
+#[test]
+fn truncate_zeroing() {
+	let mut bv = bitvec![BigEndian, u8; 1; 30];
+	bv.truncate(28);
+	bv.truncate_zeroing(9);
+	assert_eq!(bv, bitvec![1; 9]);
+	let base = bv.pointer.pointer().r();
+	let raw = unsafe { core::slice::from_raw_parts(base, 4) };
+	assert_eq!(raw, &[0xFF, 0x80, 0, 0]);
+
+	//  Truncation within an element, after the head has moved.
+	let mut bv = bitvec![LittleEndian, u16; 1; 14];
+	bv.rotate_left(1);
+	bv.truncate_zeroing(4);
+	assert_eq!(bv, bitvec![1; 4]);
+	//  Everything after the live bits is cleared.
+	assert_eq!(bv.as_slice()[0] >> (*bv.pointer.head() + 4), 0);
+
+	bv.truncate_zeroing(10);
+	assert_eq!(bv.len(), 4);
+	bv.truncate_zeroing(0);
+	assert!(bv.is_empty());
+
+	//  Truncation onto an element boundary clears every later element.
+	let mut bv = bitvec![BigEndian, u8; 1; 24];
+	bv.truncate_zeroing(16);
+	assert_eq!(bv.as_slice(), &[0xFF, 0xFF]);
+	let base = bv.pointer.pointer().r();
+	let raw = unsafe { core::slice::from_raw_parts(base, 3) };
+	assert_eq!(raw, &[0xFF, 0xFF, 0]);
+}
+
+#[test]
+fn capacities() {
+	let mut bv = BitVec::<BigEndian, u16>::with_element_capacity(5);
+	bv.shrink_to_fit();
+	assert_eq!(bv.element_capacity(), 0);
+	bv.reserve_exact(33);
+	assert_eq!(bv.element_capacity(), 3);
+	assert_eq!(bv.byte_capacity(), 6);
+	assert_eq!(bv.capacity(), 48);
+
+	let mut bv = BitVec::<BigEndian, u32>::with_capacity(33);
+	bv.shrink_to_fit();
+	bv.reserve_exact(33);
+	assert_eq!(bv.element_capacity(), 2);
+	assert_eq!(bv.byte_capacity(), 8);
+	assert_eq!(bv.capacity(), 64);
+
+	let bv = BitVec::<BigEndian, u32>::with_element_capacity(3);
+	assert!(bv.element_capacity() >= 3);
+	assert!(bv.byte_capacity() >= 12);
+	assert_eq!(bv.byte_capacity(), bv.element_capacity() * 4);
+	assert!(bv.is_empty());
+}
+
+#[test]
+fn into_from_vec() {
+	let bv = BitVec::<BigEndian, u16>::from_vec(vec![0x1234, 0x5678, 0x9ABC]);
+	assert_eq!(bv.len(), 48);
+	let ptr = bv.as_slice().as_ptr();
+	let v = bv.into_vec();
+	assert_eq!(v, [0x1234, 0x5678, 0x9ABC]);
+	assert_eq!(v.as_ptr(), ptr);
+
+	let bv = BitVec::<BigEndian, u16>::from_vec(v.clone());
+	assert_eq!(bv.as_slice(), &v[..]);
+	assert_eq!(BitVec::<BigEndian, u16>::from_vec(bv.into_vec()).as_slice(), &v[..]);
+
+	//  Partial tails keep their element, and misaligned heads are moved down.
+	let mut bv = bitvec![BigEndian, u8; 1; 12];
+	bv.truncate(9);
+	assert_eq!(bv.clone().into_vec().len(), 2);
+	let src = [0x0Fu8, 0xF0];
+	let bv = BitSlice::<BigEndian, u8>::from_slice(&src)[4 .. 12].to_owned();
+	assert_eq!(bv.into_vec(), [0xFF]);
+
+	assert!(BitVec::<BigEndian, u8>::new().into_vec().is_empty());
+	assert!(BitVec::<BigEndian, u8>::from_vec(Vec::new()).is_empty());
+}
+
+#[test]
+fn raw_parts() {
+	let mut bv = BitVec::<BigEndian, u16>::with_capacity(40);
+	bv.extend([true, false, true, true, false].iter().cloned());
+	let cap = bv.capacity();
+	let ptr = bv.as_slice().as_ptr();
+
+	let (raw, len, elts) = bv.into_raw_parts();
+	assert_eq!(raw as *const u16, ptr);
+	assert_eq!(len, 5);
+	assert_eq!(elts * 16, cap);
+
+	let mut bv = unsafe { BitVec::<BigEndian, u16>::from_raw_parts(
+		raw,
+		len,
+		elts,
+	) };
+	assert_eq!(bv, bitvec![1, 0, 1, 1, 0]);
+	assert_eq!(bv.capacity(), cap);
+	assert_eq!(bv.as_slice().as_ptr(), ptr);
+	bv.extend(core::iter::repeat(true).take(40));
+	assert_eq!(bv.len(), 45);
+
+	//  Misaligned heads are moved to the front of a new allocation.
+	let src = [0x0Fu8, 0xF0];
+	let bv = BitSlice::<BigEndian, u8>::from_slice(&src)[4 .. 13].to_owned();
+	let (raw, len, elts) = bv.into_raw_parts();
+	assert_eq!(len, 9);
+	let bv = unsafe { BitVec::<BigEndian, u8>::from_raw_parts(raw, len, elts) };
+	assert_eq!(bv.as_slice(), &[0xFF, 0x00]);
+
+	let (raw, len, elts) = BitVec::<BigEndian, u8>::new().into_raw_parts();
+	assert_eq!((len, elts), (0, 0));
+	let bv = unsafe { BitVec::<BigEndian, u8>::from_raw_parts(raw, len, elts) };
+	assert!(bv.is_empty());
+}
+
+#[test]
+fn significance_cursors() {
+	use crate::cursor::{
+		Lsb0,
+		Msb0,
+	};
+
+	let mut bv = BitVec::<Msb0, u16>::with_capacity(16);
+	bv.push(true);
+	bv.extend(core::iter::repeat(false).take(14));
+	bv.push(true);
+	assert_eq!(bv.as_slice(), &[0x8001]);
+	bv.set(1, true);
+	assert_eq!(bv.as_slice(), &[0xC001]);
+
+	let mut bv = BitVec::<Lsb0, u16>::with_capacity(3);
+	bv.push(true);
+	bv.push(false);
+	bv.push(true);
+	assert_eq!(bv.as_slice(), &[0b101]);
+
+	//  The names are aliases, so the types are interchangeable.
+	let bv: BitVec<BigEndian, u16> = BitVec::<Msb0, u16>::from_element(0x1234);
+	assert_eq!(bv.as_slice(), &[0x1234]);
+}
+
+#[test]
+fn for_loops() {
+	let bv = bitvec![1, 0, 1];
+	let mut bits = Vec::new();
+	for b in &bv {
+		bits.push(b);
+	}
+	assert_eq!(bits, [true, false, true]);
+
+	let mut bits = Vec::new();
+	for b in &bv[1 ..] {
+		bits.push(b);
+	}
+	assert_eq!(bits, [false, true]);
+	assert!(bv[1 ..].iter().eq(&bv[1 ..]));
+
+	let bb = bv.clone().into_boxed_bitslice();
+	assert_eq!((&bb).into_iter().filter(|&b| b).count(), 2);
+	assert!(bb.iter().eq(&bv));
+}
+
+#[test]
+fn radix_fmt() {
+	assert_eq!(format!("{:b}", bitvec![1, 0, 1, 1]), "1011");
+	assert_eq!(format!("{:#b}", bitvec![1, 0, 1, 1]), "0b1011");
+	assert_eq!(format!("{:x}", bitvec![1, 0, 1, 1, 0, 0, 0, 1]), "b1");
+	assert_eq!(format!("{:#x}", bitvec![1, 0, 1, 1, 0, 0, 0, 1]), "0xb1");
+	assert_eq!(format!("{:X}", bitvec![1, 0, 1, 1, 0, 0, 0, 1, 1]), "B18");
+	assert_eq!(format!("{:x}", BitVec::<BigEndian, u8>::new()), "");
+
+	//  Digits follow the semantic order, not the memory order.
+	let bv = BitVec::<LittleEndian, u16>::from_element(0x1234);
+	assert_eq!(format!("{:x}", bv), "2c48");
+	assert_eq!(format!("{:x}", &bv[4 .. 12]), "c4");
+	assert_eq!(format!("{:x}", bv.clone().into_boxed_bitslice()), "2c48");
+
+	//  Long slices are written in several pieces.
+	let bv = bitvec![1; 301];
+	assert_eq!(format!("{:b}", bv), "1".repeat(301));
+	assert_eq!(format!("{:X}", bv), format!("{}8", "F".repeat(75)));
+	assert_eq!(format!("{:>302b}", bv), format!(" {}", "1".repeat(301)));
+
+	//  Width, fill, and alignment apply to the whole output, as for integers.
+	let bv = bitvec![BigEndian, u8; 1, 0, 1, 1];
+	assert_eq!(format!("{:>8b}", bv), format!("{:>8b}", 0b1011));
+	assert_eq!(format!("{:08b}", bv), format!("{:08b}", 0b1011));
+	assert_eq!(format!("{:#08b}", bv), "0b001011");
+	assert_eq!(format!("{:<7b}|", bv), "1011   |");
+	assert_eq!(format!("{:*^9b}", bv), "**1011***");
+	assert_eq!(format!("{:#06x}", bv), "0x000b");
+	assert_eq!(format!("{:2b}", bv), "1011");
+}
+
+#[test]
+fn octal_grouped_fmt() {
+	assert_eq!(format!("{:o}", bitvec![1, 0, 1, 1, 1, 0, 0, 1]), "562");
+	assert_eq!(format!("{:#o}", bitvec![1, 1, 1, 0, 0, 1]), "0o71");
+	assert_eq!(format!("{:o}", bitvec![1]), "4");
+
+	let bv = bitvec![1, 0, 1, 1, 0, 0, 1, 0];
+	assert_eq!(format!("{}", bv.display_grouped(4, '_')), "1011_0010");
+	assert_eq!(format!("{}", bv[.. 6].display_grouped(4, '_')), "1011_00");
+	assert_eq!(format!("{}", bv.display_grouped(3, '·')), "101·100·10");
+	assert_eq!(format!("{}", bv.display_grouped(8, ' ')), "10110010");
+	assert_eq!(format!("{}", bv[.. 0].display_grouped(4, '_')), "");
+	//  The alternate flag does not prefix each group.
+	assert_eq!(format!("{:#}", bv.display_grouped(4, '_')), "1011_0010");
+}
+
+#[test]
+#[should_panic(expected = "Group size cannot be zero")]
+fn grouped_zero() {
+	bitvec![1, 0].display_grouped(0, '_');
+}
+
+#[test]
+fn debug_fmt() {
+	let bv = bitvec![BigEndian, u8; 0, 1, 1, 0, 1, 0, 0, 1, 1, 0];
+	let text = format!("{:?}", bv);
+	assert!(text.starts_with("BitVec<BigEndian, u8> { len: 10, "));
+	assert!(text.contains("bits: [01101001, 10]"));
+	assert!(text.contains("store: [0x69, 0x80]"));
+
+	//  Slices show the whole of each element they touch.
+	let text = format!("{:?}", &bv[2 .. 9]);
+	assert!(text.starts_with("BitSlice<BigEndian, u8> { len: 7, "));
+	assert!(text.contains("bits: [101001, 1]"));
+	assert!(text.contains("store: [0x69, 0x80]"));
+
+	let bb = bitvec![LittleEndian, u16; 1, 1].into_boxed_bitslice();
+	assert_eq!(
+		format!("{:?}", bb),
+		"BitBox<LittleEndian, u16> { len: 2, bits: [11], store: [0x0003] }",
+	);
+	assert_eq!(
+		format!("{:?}", BitVec::<BigEndian, u32>::new()),
+		"BitVec<BigEndian, u32> { len: 0, bits: [], store: [] }",
+	);
+	assert!(format!("{:#?}", bv).contains("\n    store: [\n        0x69,\n"));
+}
+
+#[test]
+fn push_onto_new() {
+	//  An unallocated vector must begin at the front of its first element.
+	let mut bv = BitVec::<BigEndian, u16>::new();
+	bv.push(true);
+	assert_eq!(bv.as_slice(), &[0x8000]);
+
+	let mut bv = BitVec::<BigEndian, u64>::new();
+	bv.push_bits(0xAB, 8);
+	assert_eq!(bv.as_slice(), &[0xAB00_0000_0000_0000]);
+
+	let mut bv = BitVec::<BigEndian, u32>::new();
+	bv.extend_from_bitslice(&BitVec::<BigEndian, u32>::new());
+	bv.push(true);
+	assert_eq!(bv.as_slice(), &[0x8000_0000]);
+}
@@ -16,10 +16,28 @@ use bitvec::prelude::*;
 fn serdes() {
 	let bv = bitvec![BigEndian, u8; 1, 0, 1, 1, 0, 0, 1, 0];
 	let json = serde_json::to_string(&bv).expect("cannot fail to serialize");
-	assert_eq!(json.trim(),r#"{"head":0,"bits":8,"data":[178]}"#);
+	assert_eq!(json.trim(), r#""10110010""#);
 
 	let bb: BitBox::<BigEndian, u8> = serde_json::from_str(&json)
 		.expect("cannot fail to deserialize");
 	assert!(bb[0]);
 	assert_eq!(bb.as_slice()[0], 178);
 }
+
+#[cfg(all(feature = "std", feature = "serde"))]
+#[test]
+fn bit_string() {
+	let bv = bitvec![1, 0, 1];
+	let json = serde_json::to_string(&bv).expect("cannot fail to serialize");
+	assert_eq!(json, r#""101""#);
+
+	let back: BitVec = serde_json::from_str(&json)
+		.expect("cannot fail to deserialize");
+	assert_eq!(back, bv);
+
+	//  The structured form from earlier versions is still accepted.
+	let old: BitVec<BigEndian, u8> =
+		serde_json::from_str(r#"{"head":0,"bits":3,"data":[160]}"#)
+			.expect("cannot fail to deserialize");
+	assert_eq!(old, bitvec![BigEndian, u8; 1, 0, 1]);
+}